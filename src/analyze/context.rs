@@ -1,26 +1,34 @@
 use crate::scan::RepoModel;
+use crate::types::config::{HarnessConfig, DEFAULT_FRESH_DAYS};
+use crate::types::scoring::{signal, ScoreBreakdown};
 
-pub fn context_score(model: &RepoModel) -> f32 {
-    let mut score: f32 = 0.0;
-    if model.docs.has_agents_md && model.docs.agents_has_section_header {
-        score += 0.35;
-    }
-    if model.docs.has_context_index {
-        score += 0.20;
-    }
-    if model.docs.has_architecture_doc {
-        score += 0.15;
-    }
-    if model.docs.readme_links_architecture {
-        score += 0.10;
-    }
-    if model
-        .docs
+pub fn context_score(model: &RepoModel, config: Option<&HarnessConfig>) -> f32 {
+    context_breakdown(model, config).total
+}
+
+pub fn context_breakdown(model: &RepoModel, config: Option<&HarnessConfig>) -> ScoreBreakdown {
+    let fresh_days = config.map_or(DEFAULT_FRESH_DAYS, HarnessConfig::fresh_days);
+    let docs = &model.docs;
+    let fresh = docs
         .docs_age_days
-        .map(|days| days < 90)
-        .unwrap_or(false)
-    {
-        score += 0.20;
-    }
-    score.clamp(0.0, 1.0)
+        .map(|days| days < i64::from(fresh_days))
+        .unwrap_or(false);
+    ScoreBreakdown::new(
+        0.0,
+        vec![
+            signal(
+                "agents_md+header",
+                docs.has_agents_md && docs.agents_has_section_header,
+                0.35,
+            ),
+            signal("context_index", docs.has_context_index, 0.20),
+            signal("architecture_doc", docs.has_architecture_doc, 0.15),
+            signal(
+                "readme_links_architecture",
+                docs.readme_links_architecture,
+                0.10,
+            ),
+            signal("fresh_docs", fresh, 0.20),
+        ],
+    )
 }
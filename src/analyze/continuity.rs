@@ -1,18 +1,35 @@
 use crate::scan::RepoModel;
+use crate::types::config::HarnessConfig;
+use crate::types::scoring::{signal, ScoreBreakdown};
 
-pub fn continuity_score(model: &RepoModel) -> f32 {
-    let mut score: f32 = 0.0;
-    if model.continuity.has_initializer_prompt && model.continuity.has_coding_prompt {
-        score += 0.40;
-    }
-    if model.continuity.has_progress_file {
-        score += 0.25;
-    }
-    if model.continuity.has_feature_state_file {
-        score += 0.20;
-    }
-    if model.continuity.has_progress_summary {
-        score += 0.15;
-    }
-    score.clamp(0.0, 1.0)
+pub fn continuity_score(model: &RepoModel, config: Option<&HarnessConfig>) -> f32 {
+    continuity_breakdown(model, config).total
+}
+
+pub fn continuity_breakdown(model: &RepoModel, config: Option<&HarnessConfig>) -> ScoreBreakdown {
+    let [prompts, progress_file, feature_state_file, progress_summary] = config
+        .map(HarnessConfig::continuity_weights)
+        .unwrap_or_else(HarnessConfig::default_continuity_weights);
+    let continuity = &model.continuity;
+    ScoreBreakdown::new(
+        0.0,
+        vec![
+            signal(
+                "initializer+coding_prompts",
+                continuity.has_initializer_prompt && continuity.has_coding_prompt,
+                prompts,
+            ),
+            signal("progress_file", continuity.has_progress_file, progress_file),
+            signal(
+                "feature_state_file",
+                continuity.has_feature_state_file,
+                feature_state_file,
+            ),
+            signal(
+                "progress_summary",
+                continuity.has_progress_summary,
+                progress_summary,
+            ),
+        ],
+    )
 }
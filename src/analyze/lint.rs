@@ -2,20 +2,30 @@ use crate::scan::RepoModel;
 use crate::types::config::HarnessConfig;
 use crate::types::report::Finding;
 
-pub fn lint_findings(model: &RepoModel, config: Option<&HarnessConfig>) -> Vec<Finding> {
-    super::analyze(model, config).findings
+pub fn lint_findings(
+    model: &RepoModel,
+    config: Option<&HarnessConfig>,
+    fail_fast: bool,
+) -> Vec<Finding> {
+    if fail_fast {
+        super::findings_through_first_blocking(model, config)
+    } else {
+        super::analyze(model, config).findings
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
     use crate::scan::{docs::DocSignals, tools::ToolSignals, ContinuitySignals, QualitySignals};
+    use std::collections::BTreeMap;
     use std::path::PathBuf;
 
     fn base_model() -> RepoModel {
         RepoModel {
             root: PathBuf::from("."),
             file_count: 100,
+            directory_file_counts: BTreeMap::new(),
             docs: DocSignals {
                 has_agents_md: true,
                 agents_has_section_header: true,
@@ -23,6 +33,8 @@ mod tests {
                 has_architecture_doc: true,
                 readme_links_architecture: true,
                 docs_age_days: Some(1),
+                context_token_estimate: 0,
+                possible_secrets: Vec::new(),
             },
             tools: ToolSignals::default(),
             continuity: ContinuitySignals::default(),
@@ -36,7 +48,7 @@ mod tests {
         model.docs.has_agents_md = false;
         model.docs.has_context_index = false;
 
-        let findings = lint_findings(&model, None);
+        let findings = lint_findings(&model, None, false);
         assert!(findings
             .iter()
             .any(|finding| finding.id == "context.missing_agents"));
@@ -45,12 +57,30 @@ mod tests {
             .any(|finding| finding.id == "context.missing_index"));
     }
 
+    #[test]
+    fn test_lint_fail_fast_stops_at_first_blocking_finding() {
+        let mut model = base_model();
+        model.docs.has_agents_md = false;
+        model.tools.unrestricted_destructive = 1;
+        model.quality.harness_artifacts_unignored = true;
+
+        let ids = lint_findings(&model, None, true)
+            .into_iter()
+            .map(|finding| finding.id)
+            .collect::<Vec<_>>();
+        assert_eq!(
+            ids,
+            vec!["context.missing_agents", "tools.destructive_exposed"]
+        );
+        assert!(lint_findings(&model, None, false).len() > ids.len());
+    }
+
     #[test]
     fn test_lint_reports_blocking_for_destructive_tools() {
         let mut model = base_model();
         model.tools.unrestricted_destructive = 1;
 
-        let findings = lint_findings(&model, None);
+        let findings = lint_findings(&model, None, false);
         assert!(findings
             .iter()
             .any(|finding| finding.id == "tools.destructive_exposed" && finding.blocking));
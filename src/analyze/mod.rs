@@ -2,194 +2,435 @@ pub mod context;
 pub mod continuity;
 pub mod lint;
 pub mod quality;
+pub mod registry;
 pub mod tools;
 pub mod verification;
 
+use crate::i18n::{self, Catalog};
 use crate::scan::RepoModel;
-use crate::types::config::HarnessConfig;
-use crate::types::report::{Effort, Finding, HarnessReport, Impact, Recommendation, Risk};
-use crate::types::scoring::ScoreCard;
+use crate::types::config::{HarnessConfig, ScoreScale, DEFAULT_MAX_FILE_LINES, DEFAULT_MAX_TOOLS};
+use crate::types::report::{Finding, Grade, HarnessReport, Recommendation, Verdict};
+use crate::types::scoring::{score_percent, ScoreBreakdown, ScoreCard};
+use crate::util::template::interpolate;
+use std::collections::BTreeMap;
 
-pub fn analyze(model: &RepoModel, config: Option<&HarnessConfig>) -> HarnessReport {
-    let context = context::context_score(model);
-    let tools = tools::tools_score(model);
-    let continuity = continuity::continuity_score(model);
-    let verification = verification::verification_score(config);
-    let repository_quality = quality::repository_quality_score(model);
-
-    let weights = config
-        .map(|cfg| cfg.weights())
-        .unwrap_or_else(HarnessConfig::default_weights);
-    let category_scores =
-        ScoreCard::new(context, tools, continuity, verification, repository_quality)
-            .finalize(&weights);
+const LARGE_FILES_LISTED: usize = 5;
 
-    let mut findings = Vec::new();
-    if !model.docs.has_agents_md {
-        findings.push(Finding {
-            id: "context.missing_agents".to_string(),
-            title: "Missing AGENTS.md".to_string(),
-            body: "Repository is missing AGENTS.md; agent legibility is reduced.".to_string(),
-            blocking: false,
-            file: Some("AGENTS.md".to_string()),
-        });
-    }
-    if !model.docs.has_context_index {
-        findings.push(Finding {
-            id: "context.missing_index".to_string(),
-            title: "Missing docs context index".to_string(),
-            body: "docs/context/INDEX.md is missing, reducing navigability for agents.".to_string(),
-            blocking: false,
-            file: Some("docs/context/INDEX.md".to_string()),
-        });
-    }
-    if model.tools.unrestricted_destructive > 0 {
-        findings.push(Finding {
-            id: "tools.destructive_exposed".to_string(),
-            title: "Potentially destructive tools exposed".to_string(),
-            body: "Detected unrestricted destructive commands in tool inventory.".to_string(),
-            blocking: true,
-            file: Some("harness.toml".to_string()),
-        });
-    }
-    if let Some(deprecated) = config
-        .and_then(|cfg| cfg.tools.as_ref())
-        .and_then(|tools| tools.deprecated.as_ref())
-    {
-        if !deprecated.observe.is_empty() {
-            findings.push(Finding {
-                id: "tools.observe".to_string(),
-                title: "Observed tools scheduled for deprecation".to_string(),
-                body: format!(
-                    "Observed tools are still allowed but tracked: {}.",
-                    deprecated.observe.join(", ")
-                ),
-                blocking: false,
-                file: Some("harness.toml".to_string()),
-            });
-        }
-        if !deprecated.deprecated.is_empty() {
-            findings.push(Finding {
-                id: "tools.deprecated".to_string(),
-                title: "Deprecated tools still enabled".to_string(),
-                body: format!(
-                    "Deprecated tools should be migrated off active workflows: {}.",
-                    deprecated.deprecated.join(", ")
-                ),
-                blocking: true,
-                file: Some("harness.toml".to_string()),
-            });
-        }
-        if !deprecated.disabled.is_empty() {
-            findings.push(Finding {
-                id: "tools.disabled".to_string(),
-                title: "Disabled tools are configured".to_string(),
-                body: format!(
-                    "Disabled tools are forbidden on apply and must not be used: {}.",
-                    deprecated.disabled.join(", ")
-                ),
-                blocking: true,
-                file: Some("harness.toml".to_string()),
-            });
-        }
-    }
-    if config.is_some() && verification < 0.5 {
-        findings.push(Finding {
-            id: "verification.incomplete".to_string(),
-            title: "Verification policy incomplete".to_string(),
-            body: "Verification requirements are incomplete or missing pre-completion checks."
-                .to_string(),
-            blocking: true,
-            file: Some("harness.toml".to_string()),
-        });
-    } else if config.is_none() {
-        findings.push(Finding {
-            id: "verification.missing_config".to_string(),
-            title: "Verification policy unavailable".to_string(),
-            body: "Verification checks cannot be evaluated because harness.toml is missing."
-                .to_string(),
-            blocking: false,
-            file: Some("harness.toml".to_string()),
-        });
-    }
+pub fn analyze(model: &RepoModel, config: Option<&HarnessConfig>) -> HarnessReport {
+    let (category_scores, verification) = category_scores(model, config);
+    let catalog = i18n::catalog(config.map_or(i18n::DEFAULT_LOCALE, HarnessConfig::locale));
+    let findings = collect_findings(
+        &FindingInputs {
+            model,
+            config,
+            category_scores: &category_scores,
+            verification,
+            catalog: &catalog,
+        },
+        false,
+    );
 
     let mut recommendations = Vec::new();
 
     if !model.docs.has_context_index {
-        recommendations.push(Recommendation::new(
-            "rec.context.index",
-            "Add Context Index",
-            "Create docs/context/INDEX.md and link it from AGENTS.md.",
-            Impact::High,
-            Effort::S,
-            Risk::Safe,
-            0.92,
-        ));
+        recommendations.push(registry::CONTEXT_INDEX.build(&catalog));
     }
 
     if config.is_none() || verification < 0.8 {
-        recommendations.push(Recommendation::new(
-            "rec.verification.gate",
-            "Enable Verification Gate",
-            "Set pre_completion_required and provide required verification commands.",
-            Impact::High,
-            Effort::S,
-            Risk::Medium,
-            0.88,
-        ));
+        recommendations.push(registry::VERIFICATION_GATE.build(&catalog));
     }
 
-    let has_tool_pressure = model.tools.tool_names.len() > 12
-        || model.tools.risky_overlap_clusters > 0
+    let max_tools = config.map_or(DEFAULT_MAX_TOOLS, HarnessConfig::max_tools);
+    let has_tool_pressure = model.tools.tool_names.len() > max_tools
+        || !model.tools.risky_overlap_clusters.is_empty()
         || model.tools.unrestricted_destructive > 0
-        || model.tools.has_ambiguous_duplicates;
+        || !model.tools.duplicate_tools.is_empty();
     if has_tool_pressure {
-        recommendations.push(Recommendation::new(
-            "rec.tools.prune",
-            "Prune Redundant Tools",
-            "Reduce overlap in grep/find-style tool clusters and remove risky commands.",
-            Impact::Medium,
-            Effort::M,
-            Risk::Medium,
-            0.84,
-        ));
+        recommendations.push(registry::TOOLS_PRUNE.build(&catalog));
     }
 
+    let blocking_count = findings.iter().filter(|finding| finding.blocking).count();
     let mut report = HarnessReport {
+        verdict: Verdict::from_findings(&findings),
+        grade: Grade::from_score(
+            category_scores.overall,
+            &config.map(HarnessConfig::grade_cutoffs).unwrap_or_default(),
+        ),
         overall_score: category_scores.overall,
+        overall_score_percent: config
+            .filter(|cfg| cfg.score_scale() == ScoreScale::Percent)
+            .map(|_| score_percent(category_scores.overall)),
         category_scores,
+        blocking_count,
+        warning_count: findings.len() - blocking_count,
+        context_token_estimate: model.docs.context_token_estimate,
         findings,
         recommendations: Vec::new(),
+        scan_stats: None,
+        score_breakdown: None,
+        baseline_changes: None,
     };
 
     if model.file_count < 20 {
-        recommendations.push(Recommendation::new(
-            "rec.repo.scale",
-            "Document Repository Scale",
-            "Add lightweight architecture notes to support agent understanding in small repos.",
-            Impact::Low,
-            Effort::Xs,
-            Risk::Safe,
-            0.60,
-        ));
+        recommendations.push(registry::REPO_SCALE.build(&catalog));
+    }
+
+    if model.quality.harness_artifacts_unignored {
+        recommendations.push(registry::GITIGNORE_HARNESS.build(&catalog));
     }
 
+    if let Some(cfg) = config {
+        recommendations.retain(|rec| !cfg.is_recommendation_excluded(&rec.id));
+        apply_recommendation_templates(&mut recommendations, cfg, model);
+    }
     report.recommendations = recommendations;
+    report.sort_findings();
     report.sort_recommendations();
     report
 }
 
+pub fn findings_through_first_blocking(
+    model: &RepoModel,
+    config: Option<&HarnessConfig>,
+) -> Vec<Finding> {
+    let (category_scores, verification) = category_scores(model, config);
+    let catalog = i18n::catalog(config.map_or(i18n::DEFAULT_LOCALE, HarnessConfig::locale));
+    collect_findings(
+        &FindingInputs {
+            model,
+            config,
+            category_scores: &category_scores,
+            verification,
+            catalog: &catalog,
+        },
+        true,
+    )
+}
+
+fn category_scores(model: &RepoModel, config: Option<&HarnessConfig>) -> (ScoreCard, f32) {
+    let context = context::context_score(model, config);
+    let tools = tools::tools_score(model, config);
+    let continuity = continuity::continuity_score(model, config);
+    let verification = verification::verification_score(model, config);
+    let repository_quality = quality::repository_quality_score(model, config);
+
+    let weights = config
+        .map(|cfg| cfg.weights())
+        .unwrap_or_else(HarnessConfig::default_weights);
+    let category_scores =
+        ScoreCard::new(context, tools, continuity, verification, repository_quality)
+            .finalize(&weights);
+    (category_scores, verification)
+}
+
+struct FindingInputs<'a> {
+    model: &'a RepoModel,
+    config: Option<&'a HarnessConfig>,
+    category_scores: &'a ScoreCard,
+    verification: f32,
+    catalog: &'a Catalog,
+}
+
+type FindingCheck = fn(&FindingInputs) -> Vec<Finding>;
+
+const FINDING_CHECKS: [FindingCheck; 11] = [
+    missing_agents_finding,
+    missing_context_index_finding,
+    context_token_budget_finding,
+    possible_secret_finding,
+    tool_exposure_findings,
+    tool_lifecycle_findings,
+    invalid_feature_state_finding,
+    verification_finding,
+    large_files_finding,
+    harness_artifacts_finding,
+    below_threshold_findings,
+];
+
+// With `stop_at_blocking`, the remaining checks are not run once a finding is
+// blocking after `[findings]` overrides.
+fn collect_findings(inputs: &FindingInputs, stop_at_blocking: bool) -> Vec<Finding> {
+    let mut findings = Vec::new();
+    for check in FINDING_CHECKS {
+        for mut finding in check(inputs) {
+            if let Some(blocking) = inputs
+                .config
+                .and_then(|cfg| cfg.blocking_override(&finding.id))
+            {
+                finding.blocking = blocking;
+            }
+            let blocking = finding.blocking;
+            findings.push(finding);
+            if stop_at_blocking && blocking {
+                return findings;
+            }
+        }
+    }
+    findings
+}
+
+fn missing_agents_finding(inputs: &FindingInputs) -> Vec<Finding> {
+    (!inputs.model.docs.has_agents_md)
+        .then(|| registry::MISSING_AGENTS.finding(inputs.catalog, &[]))
+        .into_iter()
+        .collect()
+}
+
+fn missing_context_index_finding(inputs: &FindingInputs) -> Vec<Finding> {
+    (!inputs.model.docs.has_context_index)
+        .then(|| registry::MISSING_CONTEXT_INDEX.finding(inputs.catalog, &[]))
+        .into_iter()
+        .collect()
+}
+
+fn context_token_budget_finding(inputs: &FindingInputs) -> Vec<Finding> {
+    let tokens = inputs.model.docs.context_token_estimate;
+    inputs
+        .config
+        .and_then(HarnessConfig::max_context_tokens)
+        .filter(|max_tokens| tokens > *max_tokens)
+        .map(|max_tokens| {
+            registry::CONTEXT_TOKEN_BUDGET.finding(
+                inputs.catalog,
+                &[
+                    ("tokens", tokens.to_string()),
+                    ("budget", max_tokens.to_string()),
+                ],
+            )
+        })
+        .into_iter()
+        .collect()
+}
+
+fn possible_secret_finding(inputs: &FindingInputs) -> Vec<Finding> {
+    let secrets = &inputs.model.docs.possible_secrets;
+    let Some(first) = secrets.first() else {
+        return Vec::new();
+    };
+    let locations = secrets
+        .iter()
+        .map(|secret| format!("{}:{} ({})", secret.path, secret.line, secret.kind))
+        .collect::<Vec<_>>();
+    vec![Finding {
+        file: Some(first.path.clone()),
+        ..registry::POSSIBLE_SECRET.finding(inputs.catalog, &[("locations", locations.join(", "))])
+    }]
+}
+
+fn tool_exposure_findings(inputs: &FindingInputs) -> Vec<Finding> {
+    let tools = &inputs.model.tools;
+    let mut findings = Vec::new();
+    if tools.unrestricted_destructive > 0 {
+        findings.push(registry::DESTRUCTIVE_TOOLS_EXPOSED.finding(inputs.catalog, &[]));
+    }
+    if !tools.risky_overlap_clusters.is_empty() {
+        let clusters = tools
+            .risky_overlap_clusters
+            .iter()
+            .map(|cluster| cluster.join("/"))
+            .collect::<Vec<_>>();
+        findings.push(
+            registry::TOOLS_OVERLAP.finding(inputs.catalog, &[("clusters", clusters.join(", "))]),
+        );
+    }
+    if !tools.duplicate_tools.is_empty() {
+        findings.push(registry::TOOLS_DUPLICATE_ENTRIES.finding(
+            inputs.catalog,
+            &[("tools", tools.duplicate_tools.join(", "))],
+        ));
+    }
+    findings
+}
+
+fn tool_lifecycle_findings(inputs: &FindingInputs) -> Vec<Finding> {
+    let Some(deprecated) = inputs
+        .config
+        .and_then(|cfg| cfg.tools.as_ref())
+        .and_then(|tools| tools.deprecated.as_ref())
+    else {
+        return Vec::new();
+    };
+    [
+        (&registry::TOOLS_OBSERVED, &deprecated.observe),
+        (&registry::TOOLS_DEPRECATED, &deprecated.deprecated),
+        (&registry::TOOLS_DISABLED, &deprecated.disabled),
+    ]
+    .into_iter()
+    .filter(|(_, tools)| !tools.is_empty())
+    .map(|(spec, tools)| spec.finding(inputs.catalog, &[("tools", tools.join(", "))]))
+    .collect()
+}
+
+fn invalid_feature_state_finding(inputs: &FindingInputs) -> Vec<Finding> {
+    let continuity = &inputs.model.continuity;
+    continuity
+        .feature_state_issue
+        .as_ref()
+        .map(|issue| Finding {
+            file: Some(continuity.feature_state_file.clone()),
+            ..registry::INVALID_FEATURE_STATE.finding(inputs.catalog, &[("issue", issue.clone())])
+        })
+        .into_iter()
+        .collect()
+}
+
+fn verification_finding(inputs: &FindingInputs) -> Vec<Finding> {
+    let spec = match inputs.config {
+        Some(_) if inputs.verification < 0.5 => &registry::VERIFICATION_INCOMPLETE,
+        Some(_) => return Vec::new(),
+        None => &registry::VERIFICATION_MISSING_CONFIG,
+    };
+    vec![spec.finding(inputs.catalog, &[])]
+}
+
+fn large_files_finding(inputs: &FindingInputs) -> Vec<Finding> {
+    let large_files = &inputs.model.quality.large_files;
+    let Some(largest) = large_files.first() else {
+        return Vec::new();
+    };
+    let listed = large_files
+        .iter()
+        .take(LARGE_FILES_LISTED)
+        .map(|file| {
+            if file.capped {
+                format!("{} ({}+ lines)", file.path, file.lines)
+            } else {
+                format!("{} ({} lines)", file.path, file.lines)
+            }
+        })
+        .collect::<Vec<_>>();
+    let limit = inputs
+        .config
+        .map_or(DEFAULT_MAX_FILE_LINES, HarnessConfig::max_file_lines);
+    vec![Finding {
+        file: Some(largest.path.clone()),
+        ..registry::LARGE_FILES.finding(
+            inputs.catalog,
+            &[
+                ("count", large_files.len().to_string()),
+                ("limit", limit.to_string()),
+                ("files", listed.join(", ")),
+            ],
+        )
+    }]
+}
+
+fn harness_artifacts_finding(inputs: &FindingInputs) -> Vec<Finding> {
+    inputs
+        .model
+        .quality
+        .harness_artifacts_unignored
+        .then(|| registry::HARNESS_ARTIFACTS_TRACKED.finding(inputs.catalog, &[]))
+        .into_iter()
+        .collect()
+}
+
+fn below_threshold_findings(inputs: &FindingInputs) -> Vec<Finding> {
+    let Some(cfg) = inputs.config else {
+        return Vec::new();
+    };
+    let thresholds = cfg.category_thresholds();
+    let blocking = cfg.thresholds_blocking();
+    inputs
+        .category_scores
+        .categories()
+        .into_iter()
+        .filter_map(|(category, score)| {
+            let threshold = thresholds
+                .get(category)
+                .filter(|threshold| score < **threshold)?;
+            Some(Finding {
+                blocking,
+                ..registry::BELOW_THRESHOLD.category_finding(
+                    inputs.catalog,
+                    category,
+                    &[
+                        ("category", category.to_string()),
+                        ("score", format!("{score:.2}")),
+                        ("threshold", format!("{threshold:.2}")),
+                    ],
+                )
+            })
+        })
+        .collect()
+}
+
+pub fn score_breakdown(
+    model: &RepoModel,
+    config: Option<&HarnessConfig>,
+) -> BTreeMap<String, ScoreBreakdown> {
+    BTreeMap::from([
+        (
+            "context".to_string(),
+            context::context_breakdown(model, config),
+        ),
+        ("tools".to_string(), tools::tools_breakdown(model, config)),
+        (
+            "continuity".to_string(),
+            continuity::continuity_breakdown(model, config),
+        ),
+        (
+            "verification".to_string(),
+            verification::verification_breakdown(model, config),
+        ),
+        (
+            "repository_quality".to_string(),
+            quality::repository_quality_breakdown(model, config),
+        ),
+    ])
+}
+
+fn recommendation_placeholder_values(model: &RepoModel) -> BTreeMap<&'static str, String> {
+    let missing_file = if !model.docs.has_agents_md {
+        "AGENTS.md"
+    } else if !model.docs.has_context_index {
+        "docs/context/INDEX.md"
+    } else {
+        "none"
+    };
+    BTreeMap::from([
+        ("file_count", model.file_count.to_string()),
+        ("missing_file", missing_file.to_string()),
+        ("tool_count", model.tools.tool_names.len().to_string()),
+        (
+            "context_tokens",
+            model.docs.context_token_estimate.to_string(),
+        ),
+    ])
+}
+
+fn apply_recommendation_templates(
+    recommendations: &mut [Recommendation],
+    config: &HarnessConfig,
+    model: &RepoModel,
+) {
+    let values = recommendation_placeholder_values(model);
+    for recommendation in recommendations {
+        let Some(template) = config.recommendation_template(&recommendation.id) else {
+            continue;
+        };
+        if let Some(title) = &template.title {
+            recommendation.title = interpolate(title, &values);
+        }
+        if let Some(summary) = &template.summary {
+            recommendation.summary = interpolate(summary, &values);
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
     use crate::scan::{docs::DocSignals, tools::ToolSignals};
     use crate::scan::{ContinuitySignals, QualitySignals};
+    use std::collections::BTreeMap;
     use std::path::PathBuf;
 
     fn base_model() -> RepoModel {
         RepoModel {
             root: PathBuf::from("."),
             file_count: 100,
+            directory_file_counts: BTreeMap::new(),
             docs: DocSignals {
                 has_agents_md: true,
                 agents_has_section_header: true,
@@ -197,6 +438,8 @@ mod tests {
                 has_architecture_doc: true,
                 readme_links_architecture: true,
                 docs_age_days: Some(1),
+                context_token_estimate: 0,
+                possible_secrets: Vec::new(),
             },
             tools: ToolSignals::default(),
             continuity: ContinuitySignals::default(),
@@ -214,6 +457,198 @@ mod tests {
             .iter()
             .any(|finding| finding.id == "verification.missing_config" && !finding.blocking));
         assert!((0.0..=1.0).contains(&report.overall_score));
+        assert_eq!(report.verdict, Verdict::Warn);
+        assert_eq!(report.blocking_count, 0);
+        assert_eq!(report.warning_count, report.findings.len());
+    }
+
+    #[test]
+    fn analyze_doc_freshness_bonus_respects_fresh_days_boundary() {
+        let config: HarnessConfig = toml::from_str(
+            r#"
+[project]
+name = "sample"
+
+[context]
+fresh_days = 30
+"#,
+        )
+        .expect("config should parse");
+        let context_score_at = |age: i64, config: Option<&HarnessConfig>| {
+            let mut model = base_model();
+            model.docs.docs_age_days = Some(age);
+            analyze(&model, config).category_scores.context
+        };
+
+        assert!((context_score_at(29, Some(&config)) - 1.0).abs() < 0.001);
+        assert!((context_score_at(30, Some(&config)) - 0.8).abs() < 0.001);
+        assert!((context_score_at(89, None) - 1.0).abs() < 0.001);
+        assert!((context_score_at(90, None) - 0.8).abs() < 0.001);
+    }
+
+    #[test]
+    fn analyze_tool_count_penalty_respects_max_tools_boundary() {
+        let config: HarnessConfig = toml::from_str(
+            r#"
+[project]
+name = "sample"
+
+[tools]
+max_tools = 20
+tool_count_penalty = 0.25
+"#,
+        )
+        .expect("config should parse");
+        let tools_score_with = |count: usize, config: Option<&HarnessConfig>| {
+            let mut model = base_model();
+            model.tools.tool_names = (0..count).map(|index| format!("tool-{index}")).collect();
+            analyze(&model, config).category_scores.tools
+        };
+
+        assert!((tools_score_with(12, None) - 1.0).abs() < 0.001);
+        assert!((tools_score_with(13, None) - 0.9).abs() < 0.001);
+        assert!((tools_score_with(20, Some(&config)) - 1.0).abs() < 0.001);
+        assert!((tools_score_with(21, Some(&config)) - 0.75).abs() < 0.001);
+    }
+
+    #[test]
+    fn analyze_reports_context_token_estimate_and_budget_finding() {
+        let config: HarnessConfig = toml::from_str(
+            r#"
+[project]
+name = "sample"
+
+[context]
+max_context_tokens = 500
+"#,
+        )
+        .expect("config should parse");
+        let report_at = |tokens: usize| {
+            let mut model = base_model();
+            model.docs.context_token_estimate = tokens;
+            analyze(&model, Some(&config))
+        };
+        let has_budget_finding = |report: &HarnessReport| {
+            report
+                .findings
+                .iter()
+                .any(|finding| finding.id == "context.token_budget_exceeded" && !finding.blocking)
+        };
+
+        let within = report_at(500);
+        assert_eq!(within.context_token_estimate, 500);
+        assert!(!has_budget_finding(&within));
+        assert!(has_budget_finding(&report_at(501)));
+
+        let mut model = base_model();
+        model.docs.context_token_estimate = 1_000_000;
+        assert!(!has_budget_finding(&analyze(&model, None)));
+    }
+
+    #[test]
+    fn analyze_lists_largest_files_and_points_at_the_first() {
+        let mut model = base_model();
+        model.quality.large_files = (0..7)
+            .map(|index| crate::scan::LargeFile {
+                path: format!("src/file{index}.rs"),
+                lines: 10_000 - index,
+                capped: index == 0,
+            })
+            .collect();
+
+        let report = analyze(&model, None);
+        let finding = report
+            .findings
+            .iter()
+            .find(|finding| finding.id == "repository_quality.large_files")
+            .expect("large files finding should be emitted");
+        assert!(!finding.blocking);
+        assert_eq!(finding.file.as_deref(), Some("src/file0.rs"));
+        assert!(finding.body.starts_with(
+            "7 file(s) exceed the 1000-line limit; largest: src/file0.rs (10000+ lines), \
+             src/file1.rs (9999 lines)"
+        ));
+        assert!(finding.body.contains("src/file4.rs (9996 lines)"));
+        assert!(!finding.body.contains("src/file5.rs"));
+    }
+
+    #[test]
+    fn analyze_reports_possible_secrets_as_blocking_with_locations() {
+        let mut model = base_model();
+        model.docs.possible_secrets = vec![
+            crate::scan::secrets::SecretMatch {
+                path: "docs/context/INDEX.md".to_string(),
+                line: 7,
+                kind: "GitHub token",
+            },
+            crate::scan::secrets::SecretMatch {
+                path: "AGENTS.md".to_string(),
+                line: 2,
+                kind: "password assignment",
+            },
+        ];
+
+        let report = analyze(&model, None);
+        let finding = report
+            .findings
+            .iter()
+            .find(|finding| finding.id == "context.possible_secret")
+            .expect("secret finding should be emitted");
+        assert!(finding.blocking);
+        assert_eq!(finding.file.as_deref(), Some("docs/context/INDEX.md"));
+        assert!(finding
+            .body
+            .contains("docs/context/INDEX.md:7 (GitHub token), AGENTS.md:2 (password assignment)"));
+    }
+
+    #[test]
+    fn analyze_flags_unignored_harness_artifacts_as_warning() {
+        let mut model = base_model();
+        let is_flagged = |report: &HarnessReport| {
+            report
+                .findings
+                .iter()
+                .any(|finding| finding.id == "repository_quality.harness_artifacts_tracked")
+        };
+        assert!(!is_flagged(&analyze(&model, None)));
+
+        model.quality.harness_artifacts_unignored = true;
+        let report = analyze(&model, None);
+        let finding = report
+            .findings
+            .iter()
+            .find(|finding| finding.id == "repository_quality.harness_artifacts_tracked")
+            .expect("artifacts finding should be emitted");
+        assert!(is_flagged(&report));
+        assert!(!finding.blocking);
+        assert!(finding.body.contains("`.harness/`"));
+        assert!(report
+            .recommendations
+            .iter()
+            .any(|rec| rec.id == "rec.repo.gitignore_harness"));
+    }
+
+    #[test]
+    fn analyze_continuity_score_uses_configured_signal_weights() {
+        let mut model = base_model();
+        model.continuity.has_progress_file = true;
+        let config: HarnessConfig = toml::from_str(
+            r#"
+[project]
+name = "sample"
+
+[continuity.weights]
+prompts = 0.4
+progress_file = 0.45
+feature_state_file = 0.0
+progress_summary = 0.15
+"#,
+        )
+        .expect("config should parse");
+
+        let report = analyze(&model, Some(&config));
+        assert!((report.category_scores.continuity - 0.45).abs() < 0.001);
+        assert!((analyze(&model, None).category_scores.continuity - 0.25).abs() < 0.001);
     }
 
     #[test]
@@ -246,6 +681,136 @@ disabled = ["apply_patch"]
             .findings
             .iter()
             .any(|finding| finding.id == "tools.disabled" && finding.blocking));
+        assert_eq!(report.verdict, Verdict::Fail);
+        let blocking = report
+            .findings
+            .iter()
+            .filter(|finding| finding.blocking)
+            .count();
+        assert_eq!(report.blocking_count, blocking);
+        assert_eq!(report.warning_count, report.findings.len() - blocking);
+    }
+
+    #[test]
+    fn analyze_emits_finding_for_category_below_threshold() {
+        let model = base_model();
+        let config: HarnessConfig = toml::from_str(
+            r#"
+[project]
+name = "sample"
+profile = "general"
+
+[metrics]
+thresholds_blocking = true
+
+[metrics.thresholds]
+verification = 0.99
+context = 0.0
+"#,
+        )
+        .expect("config should parse");
+
+        let report = analyze(&model, Some(&config));
+        assert!(report.findings.iter().any(|finding| finding.id
+            == "metrics.below_threshold.verification"
+            && finding.blocking));
+        assert!(!report
+            .findings
+            .iter()
+            .any(|finding| finding.id == "metrics.below_threshold.context"));
+    }
+
+    #[test]
+    fn analyze_sets_overall_percent_only_in_percent_scale() {
+        let model = base_model();
+        let config: HarnessConfig = toml::from_str(
+            r#"
+[project]
+name = "sample"
+
+[report]
+score_scale = "percent"
+"#,
+        )
+        .expect("config should parse");
+
+        let report = analyze(&model, Some(&config));
+        assert_eq!(
+            report.overall_score_percent,
+            Some(score_percent(report.overall_score))
+        );
+        assert_eq!(analyze(&model, None).overall_score_percent, None);
+    }
+
+    #[test]
+    fn analyze_applies_blocking_overrides_by_finding_id() {
+        let mut model = base_model();
+        model.docs.has_context_index = false;
+        let config: HarnessConfig = toml::from_str(
+            r#"
+[project]
+name = "sample"
+
+[tools.deprecated]
+deprecated = ["grep"]
+
+[findings]
+blocking = ["context.missing_index"]
+non_blocking = ["tools.deprecated"]
+"#,
+        )
+        .expect("config should parse");
+
+        let report = analyze(&model, Some(&config));
+        assert!(report
+            .findings
+            .iter()
+            .any(|finding| finding.id == "context.missing_index" && finding.blocking));
+        assert!(report
+            .findings
+            .iter()
+            .any(|finding| finding.id == "tools.deprecated" && !finding.blocking));
+    }
+
+    #[test]
+    fn analyze_emits_only_cataloged_finding_ids() {
+        let mut model = base_model();
+        model.docs.has_agents_md = false;
+        model.docs.has_context_index = false;
+        model.tools.unrestricted_destructive = 1;
+        model.continuity.feature_state_issue = Some("not valid JSON".to_string());
+        let config: HarnessConfig = toml::from_str(
+            r#"
+[project]
+name = "sample"
+
+[tools.deprecated]
+observe = ["find"]
+deprecated = ["grep"]
+disabled = ["apply_patch"]
+
+[metrics.thresholds]
+verification = 0.99
+"#,
+        )
+        .expect("config should parse");
+
+        let mut findings = analyze(&model, Some(&config)).findings;
+        assert!(findings
+            .iter()
+            .any(|finding| finding.id == "continuity.invalid_feature_state" && !finding.blocking));
+        findings.extend(analyze(&model, None).findings);
+        for finding in &findings {
+            let id = if finding.id.starts_with("metrics.below_threshold.") {
+                "metrics.below_threshold.<category>"
+            } else {
+                finding.id.as_str()
+            };
+            assert!(
+                registry::FINDINGS.iter().any(|spec| spec.id == id),
+                "finding {id} should be listed in the registry"
+            );
+        }
     }
 
     #[test]
@@ -266,18 +831,68 @@ loop_guard_enabled = true
         .expect("config should parse");
 
         let report = analyze(&model, Some(&config));
-        assert!(
-            !report
-                .recommendations
-                .iter()
-                .any(|rec| rec.id == "rec.tools.prune")
-        );
+        assert!(!report
+            .recommendations
+            .iter()
+            .any(|rec| rec.id == "rec.tools.prune"));
+    }
+
+    #[test]
+    fn analyze_reports_duplicate_tool_entries_from_config() {
+        let config: HarnessConfig = toml::from_str(
+            r#"
+[project]
+name = "sample"
+
+[tools.baseline]
+read = ["rg", "cat"]
+write = ["rg"]
+"#,
+        )
+        .expect("config should parse");
+        let mut model = base_model();
+        model.tools = crate::scan::tools::detect_tools(Some(&config));
+
+        let report = analyze(&model, Some(&config));
+        let duplicate = report
+            .findings
+            .iter()
+            .find(|finding| finding.id == "tools.duplicate_entries")
+            .expect("duplicate finding should be emitted");
+        assert!(!duplicate.blocking);
+        assert!(duplicate.body.contains("rg"));
+    }
+
+    #[test]
+    fn analyze_skips_destructive_finding_for_allowed_tools() {
+        let config: HarnessConfig = toml::from_str(
+            r#"
+[project]
+name = "sample"
+
+[tools]
+allow_destructive = ["rm"]
+
+[tools.baseline]
+read = ["cat", "rg"]
+write = ["rm"]
+"#,
+        )
+        .expect("config should parse");
+        let mut model = base_model();
+        model.tools = crate::scan::tools::detect_tools(Some(&config));
+
+        let report = analyze(&model, Some(&config));
+        assert!(!report
+            .findings
+            .iter()
+            .any(|finding| finding.id == "tools.destructive_exposed"));
     }
 
     #[test]
     fn analyze_recommends_tool_prune_when_tool_pressure_exists() {
         let mut model = base_model();
-        model.tools.risky_overlap_clusters = 1;
+        model.tools.risky_overlap_clusters = vec![vec!["grep".to_string(), "rg".to_string()]];
         let config: HarnessConfig = toml::from_str(
             r#"
 [project]
@@ -293,12 +908,208 @@ loop_guard_enabled = true
         .expect("config should parse");
 
         let report = analyze(&model, Some(&config));
-        assert!(
-            report
-                .recommendations
-                .iter()
-                .any(|rec| rec.id == "rec.tools.prune")
+        assert!(report
+            .recommendations
+            .iter()
+            .any(|rec| rec.id == "rec.tools.prune"));
+        let overlap = report
+            .findings
+            .iter()
+            .find(|finding| finding.id == "tools.overlap_detected")
+            .expect("overlap finding should be emitted");
+        assert!(!overlap.blocking);
+        assert!(overlap.body.contains("grep/rg"));
+    }
+
+    #[test]
+    fn analyze_applies_configured_recommendation_templates() {
+        let mut model = base_model();
+        model.docs.has_context_index = false;
+        model.file_count = 42;
+        let config: HarnessConfig = toml::from_str(
+            r#"
+[project]
+name = "sample"
+profile = "general"
+
+[recommendations."rec.context.index"]
+summary = "Write {missing_file} to map {file_count} files."
+"#,
+        )
+        .expect("config should parse");
+        config
+            .validate()
+            .expect("template placeholders should be known");
+
+        let report = analyze(&model, Some(&config));
+        let index = report
+            .recommendations
+            .iter()
+            .find(|rec| rec.id == "rec.context.index")
+            .expect("context index recommendation should be emitted");
+        assert_eq!(index.title, "Add Context Index");
+        assert_eq!(
+            index.summary,
+            "Write docs/context/INDEX.md to map 42 files."
+        );
+    }
+
+    #[test]
+    fn analyze_localizes_findings_and_recommendations_for_configured_locale() {
+        let mut model = base_model();
+        model.docs.has_context_index = false;
+        model.tools.duplicate_tools = vec!["grep".to_string()];
+        let config: HarnessConfig = toml::from_str(
+            r#"
+[project]
+name = "sample"
+profile = "general"
+
+[report]
+locale = "es"
+"#,
+        )
+        .expect("config should parse");
+
+        let report = analyze(&model, Some(&config));
+        let missing_index = report
+            .findings
+            .iter()
+            .find(|finding| finding.id == "context.missing_index")
+            .expect("missing index finding should be emitted");
+        assert_eq!(missing_index.title, "Falta el índice de contexto en docs");
+        let duplicates = report
+            .findings
+            .iter()
+            .find(|finding| finding.id == "tools.duplicate_entries")
+            .expect("duplicate finding should be emitted");
+        assert_eq!(
+            duplicates.body,
+            "Hay herramientas listadas más de una vez: grep."
+        );
+        let index = report
+            .recommendations
+            .iter()
+            .find(|rec| rec.id == "rec.context.index")
+            .expect("context index recommendation should be emitted");
+        assert_eq!(index.title, "Añadir índice de contexto");
+    }
+
+    #[test]
+    fn score_breakdown_totals_match_category_scores() {
+        let mut sparse = base_model();
+        sparse.docs.agents_has_section_header = false;
+        sparse.docs.docs_age_days = None;
+        sparse.tools.tool_names = (0..20).map(|index| format!("tool{index}")).collect();
+        sparse.tools.risky_overlap_clusters = vec![vec!["grep".to_string(), "rg".to_string()]];
+        sparse.tools.unrestricted_destructive = 2;
+        sparse.quality.has_tests = true;
+        let config: HarnessConfig = toml::from_str(
+            r#"
+[project]
+name = "sample"
+
+[verification]
+required = ["cargo check"]
+pre_completion_required = false
+loop_guard_enabled = true
+"#,
+        )
+        .expect("config should parse");
+
+        for (model, config) in [
+            (base_model(), None),
+            (sparse.clone(), None),
+            (sparse.clone(), Some(&config)),
+        ] {
+            let report = analyze(&model, config);
+            let breakdown = score_breakdown(&model, config);
+            assert_eq!(breakdown.len(), 5);
+            for (category, score) in report.category_scores.categories() {
+                assert_eq!(breakdown[category].total, score, "{category}");
+            }
+        }
+
+        let breakdown = score_breakdown(&sparse, Some(&config));
+        let totals = [
+            "context",
+            "tools",
+            "continuity",
+            "verification",
+            "repository_quality",
+        ]
+        .map(|category| breakdown[category].total);
+        let legacy = [
+            0.0_f32 + 0.20 + 0.15 + 0.10,
+            1.0_f32 - 0.10 - 1.0 * 0.05 - 2.0 * 0.20,
+            0.0,
+            0.0_f32 + 0.50 + 0.20,
+            0.0_f32 + 0.30,
+        ];
+        assert_eq!(totals, legacy);
+    }
+
+    #[test]
+    fn tools_breakdown_lists_each_penalty() {
+        let mut model = base_model();
+        model.tools.risky_overlap_clusters = vec![vec!["grep".to_string(), "rg".to_string()]];
+        model.tools.duplicate_tools = vec!["grep".to_string()];
+
+        let breakdown = tools::tools_breakdown(&model, None);
+        assert_eq!(breakdown.base, 1.0);
+        let contributions = breakdown
+            .contributions
+            .iter()
+            .map(|contribution| (contribution.signal.as_str(), contribution.delta))
+            .collect::<Vec<_>>();
+        assert_eq!(
+            contributions,
+            vec![
+                ("tool_count>12", 0.0),
+                ("overlap_clusters x1", -0.05),
+                ("unrestricted_destructive x0", -0.0),
+                ("duplicate_entries", -0.15),
+            ]
         );
+        assert_eq!(breakdown.total, tools::tools_score(&model, None));
+    }
+
+    #[test]
+    fn analyze_never_emits_excluded_recommendations() {
+        let mut model = base_model();
+        model.file_count = 3;
+        model.docs.has_context_index = false;
+        let config: HarnessConfig = toml::from_str(
+            r#"
+[project]
+name = "sample"
+profile = "general"
+
+[recommendations]
+exclude = ["rec.repo.scale", "rec.context.index"]
+"#,
+        )
+        .expect("config should parse");
+
+        let baseline = analyze(&model, None);
+        assert!(baseline
+            .recommendations
+            .iter()
+            .any(|rec| rec.id == "rec.repo.scale"));
+        let report = analyze(&model, Some(&config));
+        assert!(!report.recommendations.is_empty());
+        assert!(report
+            .recommendations
+            .iter()
+            .all(|rec| rec.id != "rec.repo.scale" && rec.id != "rec.context.index"));
+    }
+
+    #[test]
+    fn recommendation_placeholder_values_cover_every_documented_placeholder() {
+        let values = recommendation_placeholder_values(&base_model());
+        for key in crate::types::config::RECOMMENDATION_PLACEHOLDERS {
+            assert!(values.contains_key(key), "missing placeholder value: {key}");
+        }
     }
 
     #[test]
@@ -319,11 +1130,9 @@ loop_guard_enabled = true
         .expect("config should parse");
 
         let report = analyze(&model, Some(&config));
-        assert!(
-            !report
-                .recommendations
-                .iter()
-                .any(|rec| rec.id == "rec.verification.gate")
-        );
+        assert!(!report
+            .recommendations
+            .iter()
+            .any(|rec| rec.id == "rec.verification.gate"));
     }
 }
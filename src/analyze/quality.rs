@@ -1,15 +1,21 @@
 use crate::scan::RepoModel;
+use crate::types::config::HarnessConfig;
+use crate::types::scoring::{signal, ScoreBreakdown};
 
-pub fn repository_quality_score(model: &RepoModel) -> f32 {
-    let mut score: f32 = 0.0;
-    if model.quality.has_ci_workflow {
-        score += 0.40;
-    }
-    if model.quality.has_tests {
-        score += 0.30;
-    }
-    if model.quality.has_lint_config {
-        score += 0.30;
-    }
-    score.clamp(0.0, 1.0)
+pub fn repository_quality_score(model: &RepoModel, config: Option<&HarnessConfig>) -> f32 {
+    repository_quality_breakdown(model, config).total
+}
+
+pub fn repository_quality_breakdown(
+    model: &RepoModel,
+    _config: Option<&HarnessConfig>,
+) -> ScoreBreakdown {
+    ScoreBreakdown::new(
+        0.0,
+        vec![
+            signal("ci_workflow", model.quality.has_ci_workflow, 0.40),
+            signal("tests", model.quality.has_tests, 0.30),
+            signal("lint_config", model.quality.has_lint_config, 0.30),
+        ],
+    )
 }
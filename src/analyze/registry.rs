@@ -0,0 +1,393 @@
+use crate::i18n::Catalog;
+use crate::types::report::{Effort, Finding, Impact, Recommendation, Risk};
+use crate::util::template::interpolate;
+use std::collections::BTreeMap;
+
+const CATEGORY_PLACEHOLDER: &str = "<category>";
+
+#[derive(Debug, Clone, Copy)]
+pub struct FindingSpec {
+    pub id: &'static str,
+    pub title_template: &'static str,
+    pub description: &'static str,
+    pub body_template: &'static str,
+    pub default_blocking: bool,
+    pub file: &'static str,
+}
+
+impl FindingSpec {
+    pub fn finding(&self, catalog: &Catalog, values: &[(&str, String)]) -> Finding {
+        let values = values.iter().cloned().collect::<BTreeMap<_, _>>();
+        Finding {
+            id: self.id.to_string(),
+            title: catalog
+                .finding_title(self.id, self.title_template)
+                .to_string(),
+            body: interpolate(catalog.finding_body(self.id, self.body_template), &values),
+            blocking: self.default_blocking,
+            file: Some(self.file.to_string()),
+        }
+    }
+
+    pub fn category_finding(
+        &self,
+        catalog: &Catalog,
+        category: &str,
+        values: &[(&str, String)],
+    ) -> Finding {
+        let finding = self.finding(catalog, values);
+        Finding {
+            id: self.id.replace(CATEGORY_PLACEHOLDER, category),
+            title: finding.title.replace(CATEGORY_PLACEHOLDER, category),
+            ..finding
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct RecommendationSpec {
+    pub id: &'static str,
+    pub title: &'static str,
+    pub summary: &'static str,
+    pub impact: Impact,
+    pub effort: Effort,
+    pub risk: Risk,
+    pub confidence: f32,
+}
+
+impl RecommendationSpec {
+    pub fn build(&self, catalog: &Catalog) -> Recommendation {
+        Recommendation::new(
+            self.id,
+            catalog.recommendation_title(self.id, self.title),
+            catalog.recommendation_summary(self.id, self.summary),
+            self.impact,
+            self.effort,
+            self.risk,
+            self.confidence,
+        )
+    }
+}
+
+pub const MISSING_AGENTS: FindingSpec = FindingSpec {
+    id: "context.missing_agents",
+    title_template: "Missing AGENTS.md",
+    description: "AGENTS.md is missing from the repository root.",
+    body_template: "Repository is missing AGENTS.md; agent legibility is reduced.",
+    default_blocking: false,
+    file: "AGENTS.md",
+};
+
+pub const MISSING_CONTEXT_INDEX: FindingSpec = FindingSpec {
+    id: "context.missing_index",
+    title_template: "Missing docs context index",
+    description: "docs/context/INDEX.md is missing.",
+    body_template: "docs/context/INDEX.md is missing, reducing navigability for agents.",
+    default_blocking: false,
+    file: "docs/context/INDEX.md",
+};
+
+pub const CONTEXT_TOKEN_BUDGET: FindingSpec = FindingSpec {
+    id: "context.token_budget_exceeded",
+    title_template: "Agent context exceeds token budget",
+    description: "Estimated tokens in agent context files exceed [context] max_context_tokens.",
+    body_template:
+        "Agent context files are an estimated {tokens} tokens, above the budget of {budget}.",
+    default_blocking: false,
+    file: "AGENTS.md",
+};
+
+pub const POSSIBLE_SECRET: FindingSpec = FindingSpec {
+    id: "context.possible_secret",
+    title_template: "Possible secret in agent context",
+    description: "AGENTS.md or the context index matches a secret pattern ([scan] secret_check).",
+    body_template: "Possible secrets found at {locations}; remove them and rotate the credentials.",
+    default_blocking: true,
+    file: "AGENTS.md",
+};
+
+pub const DESTRUCTIVE_TOOLS_EXPOSED: FindingSpec = FindingSpec {
+    id: "tools.destructive_exposed",
+    title_template: "Potentially destructive tools exposed",
+    description: "Unrestricted destructive commands are present in the tool inventory.",
+    body_template: "Detected unrestricted destructive commands in tool inventory.",
+    default_blocking: true,
+    file: "harness.toml",
+};
+
+pub const TOOLS_OVERLAP: FindingSpec = FindingSpec {
+    id: "tools.overlap_detected",
+    title_template: "Overlapping tools detected",
+    description: "More than one tool from the same capability cluster (e.g. grep/rg) is enabled.",
+    body_template: "Overlapping tool clusters lower the tools score: {clusters}.",
+    default_blocking: false,
+    file: "harness.toml",
+};
+
+pub const TOOLS_DUPLICATE_ENTRIES: FindingSpec = FindingSpec {
+    id: "tools.duplicate_entries",
+    title_template: "Duplicate tool entries",
+    description: "The same tool is listed more than once across the tool inventory.",
+    body_template: "Tools are listed more than once: {tools}.",
+    default_blocking: false,
+    file: "harness.toml",
+};
+
+pub const TOOLS_OBSERVED: FindingSpec = FindingSpec {
+    id: "tools.observe",
+    title_template: "Observed tools scheduled for deprecation",
+    description: "Tools listed in tools.deprecated.observe are still in use.",
+    body_template: "Observed tools are still allowed but tracked: {tools}.",
+    default_blocking: false,
+    file: "harness.toml",
+};
+
+pub const TOOLS_DEPRECATED: FindingSpec = FindingSpec {
+    id: "tools.deprecated",
+    title_template: "Deprecated tools still enabled",
+    description: "Tools listed in tools.deprecated.deprecated are still enabled.",
+    body_template: "Deprecated tools should be migrated off active workflows: {tools}.",
+    default_blocking: true,
+    file: "harness.toml",
+};
+
+pub const TOOLS_DISABLED: FindingSpec = FindingSpec {
+    id: "tools.disabled",
+    title_template: "Disabled tools are configured",
+    description: "Tools listed in tools.deprecated.disabled are configured.",
+    body_template: "Disabled tools are forbidden on apply and must not be used: {tools}.",
+    default_blocking: true,
+    file: "harness.toml",
+};
+
+pub const VERIFICATION_INCOMPLETE: FindingSpec = FindingSpec {
+    id: "verification.incomplete",
+    title_template: "Verification policy incomplete",
+    description: "Verification policy lacks required commands or pre-completion checks.",
+    body_template: "Verification requirements are incomplete or missing pre-completion checks.",
+    default_blocking: true,
+    file: "harness.toml",
+};
+
+pub const VERIFICATION_MISSING_CONFIG: FindingSpec = FindingSpec {
+    id: "verification.missing_config",
+    title_template: "Verification policy unavailable",
+    description: "harness.toml is missing, so verification cannot be evaluated.",
+    body_template: "Verification checks cannot be evaluated because harness.toml is missing.",
+    default_blocking: false,
+    file: "harness.toml",
+};
+
+pub const LARGE_FILES: FindingSpec = FindingSpec {
+    id: "repository_quality.large_files",
+    title_template: "Oversized source files",
+    description: "Files exceed [scan] max_file_lines, which hurts agent comprehension.",
+    body_template: "{count} file(s) exceed the {limit}-line limit; largest: {files}.",
+    default_blocking: false,
+    file: "harness.toml",
+};
+
+pub const HARNESS_ARTIFACTS_TRACKED: FindingSpec = FindingSpec {
+    id: "repository_quality.harness_artifacts_tracked",
+    title_template: "Harness artifacts are not gitignored",
+    description: ".gitignore exists but does not ignore the .harness/ artifact directory.",
+    body_template:
+        "Add `.harness/` to .gitignore so plans, bench, optimize, progress and rollback \
+                    artifacts are not committed.",
+    default_blocking: false,
+    file: ".gitignore",
+};
+
+pub const BELOW_THRESHOLD: FindingSpec = FindingSpec {
+    id: "metrics.below_threshold.<category>",
+    title_template: "<category> score below threshold",
+    description: "A category score is below its [metrics.thresholds] minimum.",
+    body_template: "{category} scored {score}, below the configured threshold of {threshold}.",
+    default_blocking: false,
+    file: "harness.toml",
+};
+
+pub const INVALID_FEATURE_STATE: FindingSpec = FindingSpec {
+    id: "continuity.invalid_feature_state",
+    title_template: "Feature state file is invalid",
+    description: "The feature-state file is malformed or its schema_version does not match.",
+    body_template: "Feature state file is unusable: {issue}.",
+    default_blocking: false,
+    file: ".harness/feature_list.json",
+};
+
+pub const FINDINGS: [FindingSpec; 16] = [
+    MISSING_AGENTS,
+    MISSING_CONTEXT_INDEX,
+    CONTEXT_TOKEN_BUDGET,
+    POSSIBLE_SECRET,
+    DESTRUCTIVE_TOOLS_EXPOSED,
+    TOOLS_OVERLAP,
+    TOOLS_DUPLICATE_ENTRIES,
+    TOOLS_OBSERVED,
+    TOOLS_DEPRECATED,
+    TOOLS_DISABLED,
+    VERIFICATION_INCOMPLETE,
+    VERIFICATION_MISSING_CONFIG,
+    INVALID_FEATURE_STATE,
+    LARGE_FILES,
+    HARNESS_ARTIFACTS_TRACKED,
+    BELOW_THRESHOLD,
+];
+
+pub const CONTEXT_INDEX: RecommendationSpec = RecommendationSpec {
+    id: "rec.context.index",
+    title: "Add Context Index",
+    summary: "Create docs/context/INDEX.md and link it from AGENTS.md.",
+    impact: Impact::High,
+    effort: Effort::S,
+    risk: Risk::Safe,
+    confidence: 0.92,
+};
+
+pub const VERIFICATION_GATE: RecommendationSpec = RecommendationSpec {
+    id: "rec.verification.gate",
+    title: "Enable Verification Gate",
+    summary: "Set pre_completion_required and provide required verification commands.",
+    impact: Impact::High,
+    effort: Effort::S,
+    risk: Risk::Medium,
+    confidence: 0.88,
+};
+
+pub const TOOLS_PRUNE: RecommendationSpec = RecommendationSpec {
+    id: "rec.tools.prune",
+    title: "Prune Redundant Tools",
+    summary: "Reduce overlap in grep/find-style tool clusters and remove risky commands.",
+    impact: Impact::Medium,
+    effort: Effort::M,
+    risk: Risk::Medium,
+    confidence: 0.84,
+};
+
+pub const REPO_SCALE: RecommendationSpec = RecommendationSpec {
+    id: "rec.repo.scale",
+    title: "Document Repository Scale",
+    summary: "Add lightweight architecture notes to support agent understanding in small repos.",
+    impact: Impact::Low,
+    effort: Effort::Xs,
+    risk: Risk::Safe,
+    confidence: 0.60,
+};
+
+pub const GITIGNORE_HARNESS: RecommendationSpec = RecommendationSpec {
+    id: "rec.repo.gitignore_harness",
+    title: "Ignore Harness Artifacts",
+    summary: "Append .harness/ to .gitignore so generated artifacts are not committed.",
+    impact: Impact::Low,
+    effort: Effort::Xs,
+    risk: Risk::Safe,
+    confidence: 0.90,
+};
+
+pub const RECOMMENDATIONS: [RecommendationSpec; 5] = [
+    CONTEXT_INDEX,
+    VERIFICATION_GATE,
+    TOOLS_PRUNE,
+    REPO_SCALE,
+    GITIGNORE_HARNESS,
+];
+
+pub fn is_known_recommendation(id: &str) -> bool {
+    RECOMMENDATIONS.iter().any(|spec| spec.id == id)
+}
+
+pub fn render_catalog() -> String {
+    let mut output = String::from("Findings:\n");
+    for spec in &FINDINGS {
+        let severity = if spec.default_blocking {
+            "blocking"
+        } else {
+            "warning"
+        };
+        output.push_str(&format!(
+            "  {} [{}]: {}\n",
+            spec.id, severity, spec.description
+        ));
+    }
+    output.push_str("\nRecommendations:\n");
+    for spec in &RECOMMENDATIONS {
+        output.push_str(&format!(
+            "  {} [{:?} impact, {:?} risk]: {}\n",
+            spec.id, spec.impact, spec.risk, spec.title
+        ));
+    }
+    output
+}
+
+pub fn catalog_json() -> serde_json::Value {
+    serde_json::json!({
+        "findings": FINDINGS
+            .iter()
+            .map(|spec| serde_json::json!({
+                "id": spec.id,
+                "blocking": spec.default_blocking,
+                "description": spec.description,
+            }))
+            .collect::<Vec<_>>(),
+        "recommendations": RECOMMENDATIONS
+            .iter()
+            .map(|spec| serde_json::json!({
+                "id": spec.id,
+                "title": spec.title,
+                "impact": spec.impact,
+                "effort": spec.effort,
+                "risk": spec.risk,
+            }))
+            .collect::<Vec<_>>(),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::HashSet;
+
+    #[test]
+    fn catalog_ids_are_unique() {
+        let mut seen = HashSet::new();
+        for id in FINDINGS
+            .iter()
+            .map(|spec| spec.id)
+            .chain(RECOMMENDATIONS.iter().map(|spec| spec.id))
+        {
+            assert!(seen.insert(id), "duplicate catalog id: {id}");
+        }
+    }
+
+    #[test]
+    fn category_finding_fills_id_and_title_placeholders() {
+        let finding = BELOW_THRESHOLD.category_finding(
+            &Catalog::default(),
+            "continuity",
+            &[
+                ("category", "continuity".to_string()),
+                ("score", "0.40".to_string()),
+                ("threshold", "0.50".to_string()),
+            ],
+        );
+        assert_eq!(finding.id, "metrics.below_threshold.continuity");
+        assert_eq!(finding.title, "continuity score below threshold");
+        assert_eq!(
+            finding.body,
+            "continuity scored 0.40, below the configured threshold of 0.50."
+        );
+        assert!(!finding.blocking);
+    }
+
+    #[test]
+    fn catalog_lists_every_finding_and_recommendation() {
+        let rendered = render_catalog();
+        for spec in &FINDINGS {
+            assert!(rendered.contains(spec.id));
+        }
+        for spec in &RECOMMENDATIONS {
+            assert!(rendered.contains(spec.id));
+        }
+    }
+}
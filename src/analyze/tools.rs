@@ -1,16 +1,42 @@
 use crate::scan::RepoModel;
+use crate::types::config::{HarnessConfig, DEFAULT_MAX_TOOLS, DEFAULT_TOOL_COUNT_PENALTY};
+use crate::types::scoring::{signal, ScoreBreakdown, ScoreContribution};
 
-pub fn tools_score(model: &RepoModel) -> f32 {
-    let mut score: f32 = 1.0;
+pub fn tools_score(model: &RepoModel, config: Option<&HarnessConfig>) -> f32 {
+    tools_breakdown(model, config).total
+}
 
-    if model.tools.tool_names.len() > 12 {
-        score -= 0.10;
-    }
-    score -= model.tools.risky_overlap_clusters as f32 * 0.05;
-    score -= model.tools.unrestricted_destructive as f32 * 0.20;
-    if model.tools.has_ambiguous_duplicates {
-        score -= 0.15;
-    }
+pub fn tools_breakdown(model: &RepoModel, config: Option<&HarnessConfig>) -> ScoreBreakdown {
+    let max_tools = config.map_or(DEFAULT_MAX_TOOLS, HarnessConfig::max_tools);
+    let tool_count_penalty = config.map_or(
+        DEFAULT_TOOL_COUNT_PENALTY,
+        HarnessConfig::tool_count_penalty,
+    );
+    let tools = &model.tools;
+    let overlap_clusters = tools.risky_overlap_clusters.len();
+    let destructive = tools.unrestricted_destructive;
 
-    score.clamp(0.0, 1.0)
+    ScoreBreakdown::new(
+        1.0,
+        vec![
+            signal(
+                format!("tool_count>{max_tools}"),
+                tools.tool_names.len() > max_tools,
+                -tool_count_penalty,
+            ),
+            ScoreContribution::new(
+                format!("overlap_clusters x{overlap_clusters}"),
+                -(overlap_clusters as f32 * 0.05),
+            ),
+            ScoreContribution::new(
+                format!("unrestricted_destructive x{destructive}"),
+                -(destructive as f32 * 0.20),
+            ),
+            signal(
+                "duplicate_entries",
+                !tools.duplicate_tools.is_empty(),
+                -0.15,
+            ),
+        ],
+    )
 }
@@ -1,17 +1,34 @@
+use crate::scan::RepoModel;
 use crate::types::config::HarnessConfig;
+use crate::types::scoring::{signal, ScoreBreakdown};
 
-pub fn verification_score(config: Option<&HarnessConfig>) -> f32 {
-    let mut score: f32 = 0.0;
-    if let Some(verification) = config.and_then(|cfg| cfg.verification.as_ref()) {
-        if !verification.required.is_empty() {
-            score += 0.50;
-        }
-        if verification.pre_completion_required {
-            score += 0.30;
-        }
-        if verification.loop_guard_enabled {
-            score += 0.20;
-        }
-    }
-    score.clamp(0.0, 1.0)
+pub fn verification_score(model: &RepoModel, config: Option<&HarnessConfig>) -> f32 {
+    verification_breakdown(model, config).total
+}
+
+pub fn verification_breakdown(
+    _model: &RepoModel,
+    config: Option<&HarnessConfig>,
+) -> ScoreBreakdown {
+    let verification = config.and_then(|cfg| cfg.verification.as_ref());
+    ScoreBreakdown::new(
+        0.0,
+        vec![
+            signal(
+                "required_commands",
+                verification.is_some_and(|verification| !verification.required.is_empty()),
+                0.50,
+            ),
+            signal(
+                "pre_completion_required",
+                verification.is_some_and(|verification| verification.pre_completion_required),
+                0.30,
+            ),
+            signal(
+                "loop_guard_enabled",
+                verification.is_some_and(|verification| verification.loop_guard_enabled),
+                0.20,
+            ),
+        ],
+    )
 }
@@ -0,0 +1,82 @@
+use crate::error::{HarnessError, Result};
+use chrono::{DateTime, Utc};
+use std::path::{Path, PathBuf};
+
+pub const DEFAULT_ARTIFACT_ROOT: &str = ".harness";
+pub const SOURCE_DATE_EPOCH_ENV: &str = "SOURCE_DATE_EPOCH";
+const FILE_STAMP_FORMAT: &str = "%Y%m%dT%H%M%SZ";
+
+pub fn artifact_dir(root: &Path, output_dir: Option<&Path>, subdir: &str) -> PathBuf {
+    match output_dir {
+        Some(base) => base.join(subdir),
+        None => root.join(DEFAULT_ARTIFACT_ROOT).join(subdir),
+    }
+}
+
+pub fn resolve_now(frozen_time: Option<&str>) -> Result<DateTime<Utc>> {
+    if let Some(value) = frozen_time {
+        return parse_frozen_time(value, "--frozen-time");
+    }
+    match std::env::var(SOURCE_DATE_EPOCH_ENV) {
+        Ok(value) if !value.trim().is_empty() => parse_frozen_time(&value, SOURCE_DATE_EPOCH_ENV),
+        _ => Ok(Utc::now()),
+    }
+}
+
+pub fn file_stamp(now: DateTime<Utc>) -> String {
+    now.format(FILE_STAMP_FORMAT).to_string()
+}
+
+fn parse_frozen_time(value: &str, source: &str) -> Result<DateTime<Utc>> {
+    let trimmed = value.trim();
+    if let Ok(seconds) = trimmed.parse::<i64>() {
+        return DateTime::from_timestamp(seconds, 0).ok_or_else(|| {
+            HarnessError::ConfigParse(format!("{source} is out of range: {trimmed}"))
+        });
+    }
+    DateTime::parse_from_rfc3339(trimmed)
+        .map(|parsed| parsed.with_timezone(&Utc))
+        .map_err(|_| {
+            HarnessError::ConfigParse(format!(
+                "{source} must be unix seconds or an RFC 3339 timestamp, got '{trimmed}'"
+            ))
+        })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_artifact_dir_defaults_under_repo_harness_dir() {
+        let dir = artifact_dir(Path::new("/repo"), None, "bench");
+        assert_eq!(dir, PathBuf::from("/repo/.harness/bench"));
+    }
+
+    #[test]
+    fn test_artifact_dir_relocates_under_output_dir() {
+        let dir = artifact_dir(
+            Path::new("/repo"),
+            Some(Path::new("/ci/artifacts")),
+            "rollback",
+        );
+        assert_eq!(dir, PathBuf::from("/ci/artifacts/rollback"));
+    }
+
+    #[test]
+    fn test_frozen_time_accepts_unix_seconds_and_rfc3339() {
+        let from_epoch = resolve_now(Some("1700000000")).expect("epoch seconds should parse");
+        assert_eq!(from_epoch.to_rfc3339(), "2023-11-14T22:13:20+00:00");
+        assert_eq!(file_stamp(from_epoch), "20231114T221320Z");
+
+        let from_rfc =
+            resolve_now(Some("2026-01-02T03:04:05+02:00")).expect("rfc3339 should parse");
+        assert_eq!(file_stamp(from_rfc), "20260102T010405Z");
+    }
+
+    #[test]
+    fn test_frozen_time_rejects_garbage() {
+        let error = resolve_now(Some("yesterday")).expect_err("garbage should be rejected");
+        assert!(error.to_string().contains("--frozen-time"));
+    }
+}
@@ -1,4 +1,6 @@
-use clap::{Args, Parser, Subcommand, ValueEnum};
+use clap::{Args, CommandFactory, Parser, Subcommand, ValueEnum};
+use clap_complete::Shell;
+use std::io::Write;
 use std::path::PathBuf;
 
 #[derive(Parser)]
@@ -16,6 +18,38 @@ pub struct Cli {
     #[arg(short, long, global = true, conflicts_with = "verbose")]
     pub quiet: bool,
 
+    /// Abort repository scans that walk more than this many files
+    #[arg(long, global = true)]
+    pub max_files: Option<std::num::NonZeroUsize>,
+
+    /// Write generated artifacts under this directory instead of <repo>/.harness
+    #[arg(long, global = true)]
+    pub output_dir: Option<PathBuf>,
+
+    /// Fix artifact timestamps (unix seconds or RFC 3339); overrides SOURCE_DATE_EPOCH
+    #[arg(long, global = true)]
+    pub frozen_time: Option<String>,
+
+    /// Allow plain directories without .git; git-derived signals are skipped
+    #[arg(long, global = true)]
+    pub no_git: bool,
+
+    /// Load only the repository harness.toml, ignoring global and .harness/local.toml layers
+    #[arg(long, global = true)]
+    pub no_merge: bool,
+
+    /// Print wall time of each phase (config, scan, analyze, render) to stderr
+    #[arg(long, global = true)]
+    pub profile_timing: bool,
+
+    /// Worker threads for parallel scan and trace parsing (default: available parallelism)
+    #[arg(long, global = true)]
+    pub threads: Option<std::num::NonZeroUsize>,
+
+    /// Print a {command, status, data, exit_code} JSON envelope on stdout instead of text
+    #[arg(long, global = true)]
+    pub json: bool,
+
     #[command(subcommand)]
     pub command: Commands,
 }
@@ -29,6 +63,40 @@ pub enum Commands {
     Optimize(OptimizeCommand),
     Bench(BenchCommand),
     Lint(LintCommand),
+    Completions(CompletionsCommand),
+    Man(ManCommand),
+    ListChecks,
+    CompareReports(CompareReportsCommand),
+    Budget(BudgetCommand),
+    Scrub(ScrubCommand),
+}
+
+impl Commands {
+    pub fn name(&self) -> &'static str {
+        match self {
+            Self::Init(_) => "init",
+            Self::Analyze(_) => "analyze",
+            Self::Suggest(_) => "suggest",
+            Self::Apply(_) => "apply",
+            Self::Optimize(_) => "optimize",
+            Self::Bench(_) => "bench",
+            Self::Lint(_) => "lint",
+            Self::Completions(_) => "completions",
+            Self::Man(_) => "man",
+            Self::ListChecks => "list-checks",
+            Self::CompareReports(_) => "compare-reports",
+            Self::Budget(_) => "budget",
+            Self::Scrub(_) => "scrub",
+        }
+    }
+
+    pub fn emits_raw_output(&self) -> bool {
+        match self {
+            Self::Completions(_) | Self::CompareReports(_) => true,
+            Self::Man(cmd) => cmd.out.is_none(),
+            _ => false,
+        }
+    }
 }
 
 #[derive(Clone, Debug, ValueEnum)]
@@ -39,6 +107,17 @@ pub enum Profile {
 
 #[derive(Clone, Debug, ValueEnum)]
 pub enum MinImpact {
+    Low,
+    Medium,
+    High,
+    #[value(hide = true)]
+    All,
+    #[value(hide = true)]
+    Safe,
+}
+
+#[derive(Clone, Debug, ValueEnum)]
+pub enum MinRisk {
     Safe,
     All,
 }
@@ -58,6 +137,10 @@ pub struct InitCommand {
     pub dry_run: bool,
     #[arg(long)]
     pub no_overwrite: bool,
+    #[arg(long)]
+    pub from: Option<String>,
+    #[arg(long)]
+    pub offline: bool,
 }
 
 #[derive(Args)]
@@ -65,8 +148,24 @@ pub struct AnalyzeCommand {
     pub path: PathBuf,
     #[arg(short, long, value_enum, default_value = "md")]
     pub format: ReportFormat,
-    #[arg(long, value_enum, default_value = "all")]
+    #[arg(long, value_enum, default_value = "low")]
     pub min_impact: MinImpact,
+    #[arg(long, value_enum, default_value = "all")]
+    pub min_risk: MinRisk,
+    #[arg(long)]
+    pub scan_stats: bool,
+    #[arg(long)]
+    pub explain_score: bool,
+    #[arg(long)]
+    pub stdin_config: bool,
+    #[arg(long)]
+    pub compare_report: Option<PathBuf>,
+    #[arg(long)]
+    pub keep_clone: bool,
+    /// Spawn no git subprocesses; doc age is unknown, so the context score never
+    /// gets the doc-freshness bonus, and remote URLs are refused
+    #[arg(long)]
+    pub offline: bool,
 }
 
 #[derive(Args)]
@@ -82,24 +181,42 @@ pub struct ApplyCommand {
 
     #[arg(
         long,
-        required_unless_present = "plan_all",
-        conflicts_with = "plan_all"
+        required_unless_present_any = ["plan_all", "plan_ids"],
+        conflicts_with_all = ["plan_all", "plan_ids"]
     )]
     pub plan_file: Option<String>,
 
     #[arg(
         long,
-        required_unless_present = "plan_file",
-        conflicts_with = "plan_file"
+        required_unless_present_any = ["plan_file", "plan_ids"],
+        conflicts_with_all = ["plan_file", "plan_ids"]
     )]
     pub plan_all: bool,
 
+    #[arg(
+        long,
+        value_delimiter = ',',
+        required_unless_present_any = ["plan_file", "plan_all"],
+        conflicts_with_all = ["plan_file", "plan_all"]
+    )]
+    pub plan_ids: Vec<String>,
+
     #[arg(long, value_enum, default_value = "preview")]
     pub apply_mode: ApplyMode,
     #[arg(long)]
     pub allow_dirty: bool,
     #[arg(long, short)]
     pub yes: bool,
+    #[arg(long)]
+    pub ignore_unknown: bool,
+    #[arg(long, conflicts_with = "yes")]
+    pub interactive: bool,
+    #[arg(long)]
+    pub max_changes: Option<usize>,
+    #[arg(long, requires = "plan_file")]
+    pub allow_edited_plan: bool,
+    #[arg(long, conflicts_with = "interactive")]
+    pub check: bool,
 }
 
 #[derive(Args)]
@@ -107,6 +224,16 @@ pub struct OptimizeCommand {
     pub path: PathBuf,
     #[arg(long)]
     pub trace_dir: Option<PathBuf>,
+    #[arg(long, conflicts_with = "trace_dir")]
+    pub trace_stdin: bool,
+    #[arg(long, requires = "current_rev")]
+    pub baseline_rev: Option<String>,
+    #[arg(long, requires = "baseline_rev")]
+    pub current_rev: Option<String>,
+    #[arg(long)]
+    pub summary_only: bool,
+    #[arg(long)]
+    pub verbose_traces: bool,
 }
 
 #[derive(Args)]
@@ -116,15 +243,80 @@ pub struct BenchCommand {
     pub suite: Option<String>,
     #[arg(long, default_value_t = 1)]
     pub runs: u32,
+    #[arg(long, default_value_t = 0)]
+    pub warmup: u32,
+    #[arg(long)]
+    pub fixtures: Option<PathBuf>,
     #[arg(long)]
     pub compare: Option<PathBuf>,
     #[arg(long)]
     pub force_compare: bool,
+    #[arg(long, requires = "compare", value_parser = parse_regression_threshold)]
+    pub regression_threshold: Option<f32>,
+}
+
+fn parse_regression_threshold(value: &str) -> Result<f32, String> {
+    let threshold: f32 = value.parse().map_err(|err| format!("{err}"))?;
+    if threshold.is_finite() && threshold >= 0.0 {
+        Ok(threshold)
+    } else {
+        Err("must be a non-negative number".to_string())
+    }
+}
+
+#[derive(Args)]
+pub struct ScrubCommand {
+    pub path: PathBuf,
 }
 
 #[derive(Args)]
 pub struct LintCommand {
     pub path: PathBuf,
+    #[arg(long, conflicts_with = "warnings_as_errors")]
+    pub errors_only: bool,
+    #[arg(long)]
+    pub warnings_as_errors: bool,
+    #[arg(long)]
+    pub fail_fast: bool,
+}
+
+#[derive(Args)]
+pub struct CompletionsCommand {
+    #[arg(value_enum)]
+    pub shell: Shell,
+}
+
+pub fn write_completions(shell: Shell, out: &mut dyn Write) {
+    clap_complete::generate(shell, &mut Cli::command(), "harness", out);
+}
+
+#[derive(Args)]
+pub struct ManCommand {
+    #[arg(long)]
+    pub out: Option<PathBuf>,
+}
+
+#[derive(Args)]
+pub struct BudgetCommand {
+    pub path: PathBuf,
+    #[arg(short, long, value_enum, default_value = "text")]
+    pub format: BudgetFormat,
+}
+
+#[derive(Clone, Debug, ValueEnum)]
+pub enum BudgetFormat {
+    Text,
+    Json,
+}
+
+#[derive(Args)]
+pub struct CompareReportsCommand {
+    pub baseline: PathBuf,
+    pub current: PathBuf,
+}
+
+pub fn render_man_page(out: &mut dyn Write) -> std::io::Result<()> {
+    clap_mangen::Man::new(Cli::command()).render(out)
 }
 
 #[derive(Clone, ValueEnum)]
@@ -132,4 +324,48 @@ pub enum ReportFormat {
     Json,
     Md,
     Sarif,
+    Summary,
+    GithubActions,
+    Count,
+    Ndjson,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn completions_generate_for_every_supported_shell() {
+        for shell in Shell::value_variants() {
+            let mut out = Vec::new();
+            write_completions(*shell, &mut out);
+            let script = String::from_utf8(out).expect("completion script should be utf-8");
+            assert!(
+                script.contains("harness"),
+                "{shell} completion should reference the binary name"
+            );
+        }
+    }
+
+    #[test]
+    fn man_page_renders_roff_with_subcommands() {
+        let mut out = Vec::new();
+        render_man_page(&mut out).expect("man page should render");
+        let page = String::from_utf8(out).expect("man page should be utf-8");
+        assert!(page.starts_with(".ie"), "man page should be roff");
+        assert!(
+            page.contains(".TH harness"),
+            "man page should carry a title header"
+        );
+        assert!(page.contains("analyze"), "man page should list subcommands");
+    }
+
+    #[test]
+    fn regression_threshold_must_be_finite_and_non_negative() {
+        assert_eq!(parse_regression_threshold("0"), Ok(0.0));
+        assert_eq!(parse_regression_threshold("0.25"), Ok(0.25));
+        for value in ["-0.1", "NaN", "inf", "abc"] {
+            assert!(parse_regression_threshold(value).is_err(), "{value}");
+        }
+    }
 }
@@ -1,5 +1,6 @@
 use crate::error::{HarnessError, Result};
 use crate::types::config::HarnessConfig;
+use crate::util::hash::sha256_hex;
 use std::path::{Path, PathBuf};
 use toml::map::Map;
 use toml::Value;
@@ -8,34 +9,122 @@ pub const DEFAULT_CONFIG_FILE: &str = "harness.toml";
 pub const DEFAULT_LOCAL_FILE: &str = ".harness/local.toml";
 pub const DEFAULT_GLOBAL_CONFIG_FILE: &str = ".config/harness/config.toml";
 
-pub fn load_config(root: &Path) -> Result<Option<HarnessConfig>> {
-    let global = std::env::var_os("HOME")
+/// Renamed keys as `(section, deprecated, replacement)`; add an entry whenever a key is renamed
+/// so configs written for older releases keep loading despite `deny_unknown_fields`.
+const DEPRECATED_KEYS: &[(&str, &str, &str)] = &[];
+
+pub fn load_config(root: &Path, no_merge: bool) -> Result<Option<HarnessConfig>> {
+    load_config_with_global(root, global_config_path().as_deref(), no_merge)
+}
+
+pub fn load_config_with_repo_layer(
+    root: &Path,
+    repo_layer: &str,
+    no_merge: bool,
+) -> Result<HarnessConfig> {
+    load_repo_layer_with_global(root, repo_layer, global_config_path().as_deref(), no_merge)
+}
+
+pub fn parse_config(content: &str, source: &str) -> Result<HarnessConfig> {
+    config_from_value(parse_toml_layer(content, source)?)
+}
+
+pub fn config_hash(root: &Path, no_merge: bool) -> Result<String> {
+    config_hash_with_global(root, global_config_path().as_deref(), no_merge)
+}
+
+fn global_config_path() -> Option<PathBuf> {
+    std::env::var_os("HOME")
         .map(PathBuf::from)
-        .map(|home| home.join(DEFAULT_GLOBAL_CONFIG_FILE));
-    load_config_with_global(root, global.as_deref())
+        .map(|home| home.join(DEFAULT_GLOBAL_CONFIG_FILE))
 }
 
 pub(crate) fn load_config_with_global(
     root: &Path,
     global_path: Option<&Path>,
+    no_merge: bool,
 ) -> Result<Option<HarnessConfig>> {
+    let Some(merged) = merged_config_value(root, global_path, no_merge)? else {
+        return Ok(None);
+    };
+    config_from_value(merged).map(Some)
+}
+
+fn config_from_value(merged: Value) -> Result<HarnessConfig> {
+    let cfg: HarnessConfig = merged
+        .try_into()
+        .map_err(|e: toml::de::Error| HarnessError::ConfigParse(e.to_string()))?;
+    cfg.validate()?;
+    warn_unknown_excluded_recommendations(&cfg);
+    Ok(cfg)
+}
+
+pub(crate) fn load_repo_layer_with_global(
+    root: &Path,
+    repo_layer: &str,
+    global_path: Option<&Path>,
+    no_merge: bool,
+) -> Result<HarnessConfig> {
+    let repo_layer = parse_toml_layer(repo_layer, "<stdin>")?;
+    config_from_value(merged_layers(root, global_path, repo_layer, no_merge)?)
+}
+
+fn warn_unknown_excluded_recommendations(cfg: &HarnessConfig) {
+    let unknown = cfg
+        .excluded_recommendations()
+        .iter()
+        .filter(|id| !crate::analyze::registry::is_known_recommendation(id))
+        .cloned()
+        .collect::<Vec<_>>();
+    if !unknown.is_empty() {
+        eprintln!(
+            "warning: [recommendations] exclude lists unrecognized id(s): {}",
+            unknown.join(", ")
+        );
+    }
+}
+
+pub(crate) fn config_hash_with_global(
+    root: &Path,
+    global_path: Option<&Path>,
+    no_merge: bool,
+) -> Result<String> {
+    let canonical = match merged_config_value(root, global_path, no_merge)? {
+        Some(merged) => toml::to_string(&merged)
+            .map_err(|e| HarnessError::ConfigParse(format!("config hash: {e}")))?,
+        None => String::new(),
+    };
+    Ok(sha256_hex(canonical.as_bytes()))
+}
+
+fn merged_config_value(
+    root: &Path,
+    global_path: Option<&Path>,
+    no_merge: bool,
+) -> Result<Option<Value>> {
     let repo_path = root.join(DEFAULT_CONFIG_FILE);
     if !repo_path.exists() {
         return Ok(None);
     }
+    merged_layers(root, global_path, read_toml_value(&repo_path)?, no_merge).map(Some)
+}
 
+fn merged_layers(
+    root: &Path,
+    global_path: Option<&Path>,
+    repo_layer: Value,
+    no_merge: bool,
+) -> Result<Value> {
+    if no_merge {
+        return Ok(repo_layer);
+    }
     let mut merged = Value::Table(Map::new());
     if let Some(path) = global_path {
         merge_file_if_exists(&mut merged, path)?;
     }
-    merge_file_if_exists(&mut merged, &repo_path)?;
+    merge_toml(&mut merged, repo_layer);
     merge_file_if_exists(&mut merged, &root.join(DEFAULT_LOCAL_FILE))?;
-
-    let cfg: HarnessConfig = merged
-        .try_into()
-        .map_err(|e: toml::de::Error| HarnessError::ConfigParse(e.to_string()))?;
-    cfg.validate()?;
-    Ok(Some(cfg))
+    Ok(merged)
 }
 
 fn merge_file_if_exists(merged: &mut Value, path: &Path) -> Result<()> {
@@ -49,8 +138,42 @@ fn merge_file_if_exists(merged: &mut Value, path: &Path) -> Result<()> {
 
 fn read_toml_value(path: &Path) -> Result<Value> {
     let content = std::fs::read_to_string(path)?;
-    toml::from_str(&content)
-        .map_err(|e| HarnessError::ConfigParse(format!("{}: {}", path.display(), e)))
+    parse_toml_layer(&content, &path.display().to_string())
+}
+
+fn parse_toml_layer(content: &str, source: &str) -> Result<Value> {
+    let mut value =
+        toml::from_str(content).map_err(|e| HarnessError::ConfigParse(format!("{source}: {e}")))?;
+    for (deprecated, replacement) in migrate_deprecated_keys(&mut value, DEPRECATED_KEYS) {
+        eprintln!("warning: {source}: {deprecated} is deprecated; use {replacement}");
+    }
+    Ok(value)
+}
+
+fn migrate_deprecated_keys(
+    value: &mut Value,
+    aliases: &[(&str, &str, &str)],
+) -> Vec<(String, String)> {
+    let mut migrated = Vec::new();
+    let Value::Table(root) = value else {
+        return migrated;
+    };
+    for (section, deprecated, replacement) in aliases {
+        let Some(Value::Table(table)) = root.get_mut(*section) else {
+            continue;
+        };
+        let Some(old_value) = table.remove(*deprecated) else {
+            continue;
+        };
+        if !table.contains_key(*replacement) {
+            table.insert(replacement.to_string(), old_value);
+        }
+        migrated.push((
+            format!("{section}.{deprecated}"),
+            format!("{section}.{replacement}"),
+        ));
+    }
+    migrated
 }
 
 fn merge_toml(base: &mut Value, overlay: Value) {
@@ -80,10 +203,131 @@ mod tests {
     #[test]
     fn load_config_returns_none_when_repo_file_missing() {
         let dir = TempDir::new().expect("temp dir should be created");
-        let cfg = load_config_with_global(dir.path(), None).expect("load should not fail");
+        let cfg = load_config_with_global(dir.path(), None, false).expect("load should not fail");
         assert!(cfg.is_none());
     }
 
+    #[test]
+    fn repo_layer_replaces_harness_toml_but_keeps_global_and_local() {
+        let root = TempDir::new().expect("root temp dir should be created");
+        let global_root = TempDir::new().expect("global temp dir should be created");
+        let global_path = global_root.path().join("config.toml");
+        fs::write(&global_path, "[context]\nagents_map = \"GLOBAL.md\"\n")
+            .expect("global config should write");
+        fs::write(
+            root.path().join(DEFAULT_CONFIG_FILE),
+            "[project]\nname = \"file\"\nprofile = \"general\"\n",
+        )
+        .expect("repo config should write");
+        fs::create_dir_all(root.path().join(".harness")).expect("local harness dir should create");
+        fs::write(
+            root.path().join(DEFAULT_LOCAL_FILE),
+            "[project]\nprofile = \"agent\"\n",
+        )
+        .expect("local override should write");
+
+        let cfg = load_repo_layer_with_global(
+            root.path(),
+            "[project]\nname = \"piped\"\nprofile = \"general\"\n",
+            Some(&global_path),
+            false,
+        )
+        .expect("piped config should load");
+
+        assert_eq!(cfg.project.name, "piped");
+        assert_eq!(cfg.project.profile, "agent");
+        assert_eq!(
+            cfg.context
+                .as_ref()
+                .and_then(|context| context.agents_map.as_deref()),
+            Some("GLOBAL.md")
+        );
+
+        let err = load_repo_layer_with_global(root.path(), "[project", None, false)
+            .expect_err("malformed piped config should fail");
+        assert!(err.to_string().contains("<stdin>"));
+    }
+
+    #[test]
+    fn no_merge_ignores_global_and_local_layers() {
+        let root = TempDir::new().expect("root temp dir should be created");
+        let global_root = TempDir::new().expect("global temp dir should be created");
+        let global_path = global_root.path().join("config.toml");
+        fs::write(&global_path, "[context]\nagents_map = \"GLOBAL.md\"\n")
+            .expect("global config should write");
+        fs::write(
+            root.path().join(DEFAULT_CONFIG_FILE),
+            "[project]\nname = \"repo\"\nprofile = \"general\"\n",
+        )
+        .expect("repo config should write");
+        fs::create_dir_all(root.path().join(".harness")).expect("local harness dir should create");
+        fs::write(
+            root.path().join(DEFAULT_LOCAL_FILE),
+            "[project]\nprofile = \"agent\"\n",
+        )
+        .expect("local override should write");
+
+        let cfg = load_config_with_global(root.path(), Some(&global_path), true)
+            .expect("load should succeed")
+            .expect("repo config should exist");
+        assert_eq!(cfg.project.profile, "general");
+        assert!(cfg.context.is_none());
+
+        let merged = config_hash_with_global(root.path(), Some(&global_path), false)
+            .expect("hash should compute");
+        let hermetic = config_hash_with_global(root.path(), Some(&global_path), true)
+            .expect("hash should compute");
+        assert_ne!(merged, hermetic);
+    }
+
+    const TEST_ALIASES: &[(&str, &str, &str)] = &[
+        ("scan", "max_lines", "max_file_lines"),
+        ("context", "max_tokens", "max_context_tokens"),
+    ];
+
+    #[test]
+    fn deprecated_keys_are_migrated_before_deserializing() {
+        let mut value: Value = toml::from_str(
+            r#"
+[project]
+name = "repo"
+profile = "general"
+
+[scan]
+max_lines = 250
+
+[context]
+max_tokens = 100
+max_context_tokens = 200
+"#,
+        )
+        .expect("toml should parse");
+
+        let migrated = migrate_deprecated_keys(&mut value, TEST_ALIASES);
+        assert_eq!(
+            migrated,
+            vec![
+                (
+                    "scan.max_lines".to_string(),
+                    "scan.max_file_lines".to_string()
+                ),
+                (
+                    "context.max_tokens".to_string(),
+                    "context.max_context_tokens".to_string()
+                ),
+            ]
+        );
+        assert!(value["scan"].get("max_lines").is_none());
+        assert!(value["context"].get("max_tokens").is_none());
+
+        let cfg = config_from_value(value).expect("migrated config should deserialize");
+        assert_eq!(
+            cfg.scan.as_ref().and_then(|scan| scan.max_file_lines),
+            Some(250)
+        );
+        assert_eq!(cfg.max_context_tokens(), Some(200));
+    }
+
     #[test]
     fn load_config_merges_global_repo_and_local_in_order() {
         let root = TempDir::new().expect("root temp dir should be created");
@@ -127,7 +371,7 @@ profile = "agent"
         )
         .expect("local override should write");
 
-        let cfg = load_config_with_global(root.path(), Some(&global_path))
+        let cfg = load_config_with_global(root.path(), Some(&global_path), false)
             .expect("load should succeed")
             .expect("merged config should exist");
 
@@ -165,7 +409,7 @@ profile = "general"
         )
         .expect("repo config should write");
 
-        let err = load_config_with_global(root.path(), Some(&global_path))
+        let err = load_config_with_global(root.path(), Some(&global_path), false)
             .expect_err("load should fail");
         let message = err.to_string();
         assert!(message.contains("config parse error"));
@@ -188,9 +432,34 @@ profile = "general"
         let local_path = root.path().join(DEFAULT_LOCAL_FILE);
         fs::write(&local_path, "[project").expect("local config should write");
 
-        let err = load_config_with_global(root.path(), None).expect_err("load should fail");
+        let err = load_config_with_global(root.path(), None, false).expect_err("load should fail");
         let message = err.to_string();
         assert!(message.contains("config parse error"));
         assert!(message.contains(&local_path.display().to_string()));
     }
+
+    #[test]
+    fn config_hash_tracks_effective_config_changes() {
+        let root = TempDir::new().expect("root temp dir should be created");
+        let missing =
+            config_hash_with_global(root.path(), None, false).expect("hash should compute");
+
+        let base = "[project]\nname = \"repo\"\nprofile = \"general\"\n";
+        fs::write(root.path().join(DEFAULT_CONFIG_FILE), base).expect("repo config should write");
+        let first = config_hash_with_global(root.path(), None, false).expect("hash should compute");
+        let again = config_hash_with_global(root.path(), None, false).expect("hash should compute");
+        assert_eq!(first, again);
+        assert_ne!(first, missing);
+
+        fs::create_dir_all(root.path().join(".harness")).expect("local harness dir should create");
+        fs::write(
+            root.path().join(DEFAULT_LOCAL_FILE),
+            "[metrics.weights]\ncontext = 0.5\n",
+        )
+        .expect("local config should write");
+        let overridden =
+            config_hash_with_global(root.path(), None, false).expect("hash should compute");
+        assert_ne!(first, overridden);
+        assert_eq!(overridden.len(), 64);
+    }
 }
@@ -5,6 +5,8 @@ use std::fs::OpenOptions;
 use std::io::Write;
 use std::path::{Path, PathBuf};
 
+pub const PROGRESS_FILE_ENV: &str = "HARNESS_PROGRESS_FILE";
+
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 enum SamplingMode {
     Milestones,
@@ -14,11 +16,13 @@ enum SamplingMode {
 
 #[derive(Debug, Clone)]
 struct ContinuitySettings {
+    enabled: bool,
     progress_file: PathBuf,
     sampling_mode: SamplingMode,
     batch_interval_secs: u32,
     max_log_size_kb: u64,
     retained_logs: usize,
+    max_evidence_items: Option<usize>,
 }
 
 #[derive(Debug, Clone)]
@@ -53,6 +57,9 @@ impl ContinuityLogger {
         evidence: &[String],
         next_state: &str,
     ) -> Result<(), HarnessError> {
+        if !self.settings.enabled {
+            return Ok(());
+        }
         self.push_entry(feature, action, evidence, next_state);
         self.flush()
     }
@@ -64,7 +71,7 @@ impl ContinuityLogger {
         evidence: &[String],
         next_state: &str,
     ) -> Result<(), HarnessError> {
-        if !matches!(self.settings.sampling_mode, SamplingMode::All) {
+        if !self.settings.enabled || !matches!(self.settings.sampling_mode, SamplingMode::All) {
             return Ok(());
         }
         self.push_entry(feature, action, evidence, next_state);
@@ -118,7 +125,7 @@ impl ContinuityLogger {
             timestamp: Utc::now().to_rfc3339(),
             feature: feature.to_string(),
             action: action.to_string(),
-            evidence: evidence.to_vec(),
+            evidence: cap_evidence(evidence, self.settings.max_evidence_items),
             next_state: next_state.to_string(),
         });
     }
@@ -182,12 +189,18 @@ impl ContinuityLogger {
     }
 }
 
+pub fn progress_file(root: &Path, cfg: Option<&HarnessConfig>) -> PathBuf {
+    resolve_settings(root, cfg).progress_file
+}
+
 fn resolve_settings(root: &Path, cfg: Option<&HarnessConfig>) -> ContinuitySettings {
     let continuity = cfg.and_then(|value| value.continuity.as_ref());
-    let progress_rel = continuity
-        .and_then(|value| value.progress_file.as_ref())
-        .map_or(".harness/progress.md", String::as_str);
-    let progress_file = resolve_path(root, progress_rel);
+    let enabled = continuity.and_then(|value| value.enabled).unwrap_or(true);
+    let progress_file = progress_file_path(
+        root,
+        std::env::var(PROGRESS_FILE_ENV).ok().as_deref(),
+        continuity.and_then(|value| value.progress_file.as_deref()),
+    );
     let sampling_mode = match continuity.and_then(|value| value.log_sampling.as_ref()) {
         Some(LogSampling::All) => SamplingMode::All,
         Some(LogSampling::None) => SamplingMode::None,
@@ -205,13 +218,42 @@ fn resolve_settings(root: &Path, cfg: Option<&HarnessConfig>) -> ContinuitySetti
         .and_then(|value| value.retained_logs)
         .unwrap_or(3)
         .max(1) as usize;
+    let max_evidence_items = continuity
+        .and_then(|value| value.max_evidence_items)
+        .map(|value| value as usize);
 
     ContinuitySettings {
+        enabled,
         progress_file,
         sampling_mode,
         batch_interval_secs,
         max_log_size_kb,
         retained_logs,
+        max_evidence_items,
+    }
+}
+
+fn progress_file_path(
+    root: &Path,
+    env_override: Option<&str>,
+    configured: Option<&str>,
+) -> PathBuf {
+    let path = env_override
+        .map(str::trim)
+        .filter(|value| !value.is_empty())
+        .or(configured)
+        .unwrap_or(".harness/progress.md");
+    resolve_path(root, path)
+}
+
+fn cap_evidence(evidence: &[String], max_items: Option<usize>) -> Vec<String> {
+    match max_items {
+        Some(limit) if evidence.len() > limit => {
+            let mut capped = evidence[..limit].to_vec();
+            capped.push(format!("+{} more", evidence.len() - limit));
+            capped
+        }
+        _ => evidence.to_vec(),
     }
 }
 
@@ -247,12 +289,7 @@ log_sampling = "none"
         );
         let mut logger = ContinuityLogger::new(dir.path(), Some(&config));
         logger
-            .record_milestone(
-                "analyze",
-                "start",
-                &["path=repo".to_string()],
-                "running",
-            )
+            .record_milestone("analyze", "start", &["path=repo".to_string()], "running")
             .expect("milestone should be logged");
 
         let content = std::fs::read_to_string(dir.path().join(".harness/progress.md"))
@@ -261,6 +298,56 @@ log_sampling = "none"
         assert!(content.contains("action: start"));
     }
 
+    #[test]
+    fn disabled_logger_creates_no_files() {
+        let dir = tempfile::TempDir::new().expect("temp dir should be created");
+        let config = parse_config(
+            r#"
+[project]
+name = "sample"
+profile = "general"
+
+[continuity]
+enabled = false
+log_sampling = "all"
+"#,
+        );
+        let mut logger = ContinuityLogger::new(dir.path(), Some(&config));
+        logger
+            .record_milestone("analyze", "start", &["path=repo".to_string()], "running")
+            .expect("disabled milestone should not fail");
+        logger
+            .record_progress("analyze", "scan", &["signals=ok".to_string()], "running")
+            .expect("disabled progress should not fail");
+        logger.flush().expect("flush should succeed");
+
+        assert!(
+            !dir.path().join(".harness").exists(),
+            "disabled continuity logging should not create the .harness directory"
+        );
+    }
+
+    #[test]
+    fn progress_file_env_override_wins_over_config_and_default() {
+        let root = Path::new("/repo");
+        assert_eq!(
+            progress_file_path(
+                root,
+                Some("/ci/artifacts/progress.md"),
+                Some("logs/progress.md")
+            ),
+            PathBuf::from("/ci/artifacts/progress.md")
+        );
+        assert_eq!(
+            progress_file_path(root, Some("  "), Some("logs/progress.md")),
+            PathBuf::from("/repo/logs/progress.md")
+        );
+        assert_eq!(
+            progress_file_path(root, None, None),
+            PathBuf::from("/repo/.harness/progress.md")
+        );
+    }
+
     #[test]
     fn progress_is_skipped_when_sampling_milestones() {
         let dir = tempfile::TempDir::new().expect("temp dir should be created");
@@ -276,12 +363,7 @@ log_sampling = "milestones"
         );
         let mut logger = ContinuityLogger::new(dir.path(), Some(&config));
         logger
-            .record_progress(
-                "analyze",
-                "scan",
-                &["signals=ok".to_string()],
-                "running",
-            )
+            .record_progress("analyze", "scan", &["signals=ok".to_string()], "running")
             .expect("progress record should not fail");
         logger.flush().expect("flush should succeed");
 
@@ -306,12 +388,7 @@ log_sampling = "all"
         );
         let mut logger = ContinuityLogger::new(dir.path(), Some(&config));
         logger
-            .record_progress(
-                "analyze",
-                "scan",
-                &["signals=ok".to_string()],
-                "running",
-            )
+            .record_progress("analyze", "scan", &["signals=ok".to_string()], "running")
             .expect("progress record should succeed");
         logger.flush().expect("flush should succeed");
 
@@ -340,7 +417,12 @@ retained_logs = 2
         let payload = "x".repeat(1600);
         for _ in 0..4 {
             logger
-                .record_milestone("bench", "checkpoint", std::slice::from_ref(&payload), "running")
+                .record_milestone(
+                    "bench",
+                    "checkpoint",
+                    std::slice::from_ref(&payload),
+                    "running",
+                )
                 .expect("milestone log should succeed");
         }
 
@@ -361,4 +443,38 @@ retained_logs = 2
             "rotated logs should respect retained limit"
         );
     }
+
+    #[test]
+    fn evidence_beyond_cap_is_summarized() {
+        let dir = tempfile::TempDir::new().expect("temp dir should be created");
+        let config = parse_config(
+            r#"
+[project]
+name = "sample"
+profile = "general"
+
+[continuity]
+max_evidence_items = 2
+"#,
+        );
+        let mut logger = ContinuityLogger::new(dir.path(), Some(&config));
+        let evidence = (1..=5)
+            .map(|index| format!("file{index}.rs"))
+            .collect::<Vec<_>>();
+        logger
+            .record_milestone("apply", "complete", &evidence, "done")
+            .expect("milestone should be logged");
+
+        let content = std::fs::read_to_string(dir.path().join(".harness/progress.md"))
+            .expect("progress file should be readable");
+        assert!(content.contains("evidence: file1.rs, file2.rs, +3 more |"));
+        assert!(!content.contains("file3.rs"));
+    }
+
+    #[test]
+    fn evidence_is_unlimited_by_default() {
+        let evidence = vec!["a".to_string(), "b".to_string(), "c".to_string()];
+        assert_eq!(cap_evidence(&evidence, None), evidence);
+        assert_eq!(cap_evidence(&evidence, Some(3)), evidence);
+    }
 }
@@ -6,6 +6,12 @@ pub enum HarnessError {
     #[error("not a git repository: {0}")]
     NotGitRepo(String),
 
+    #[error("git is unavailable: {0}")]
+    GitUnavailable(String),
+
+    #[error("git clone failed: {0}")]
+    GitCloneFailed(String),
+
     #[error("config file not found: {0}")]
     ConfigNotFound(String),
 
@@ -15,6 +21,9 @@ pub enum HarnessError {
     #[error("path does not exist: {0}")]
     PathNotFound(String),
 
+    #[error("scan file limit exceeded: {0}")]
+    ScanLimitExceeded(String),
+
     #[error("invalid profile target: {0}")]
     InvalidProfileTarget(String),
 
@@ -24,6 +33,44 @@ pub enum HarnessError {
     #[error("forbidden tool access attempt: {0}")]
     ForbiddenToolAccess(String),
 
+    #[error(
+        "plan would write {changes} file(s), exceeding --max-changes {max}; \
+         narrow the plan with --plan-ids or --plan-file"
+    )]
+    ApplyCapExceeded { changes: usize, max: usize },
+
+    #[error("refusing to write {path}: {reason}")]
+    InvalidPlannedChange { path: String, reason: String },
+
+    #[error(
+        "plan checksum mismatch (expected {expected}, found {actual}): recommendations were \
+         edited after suggest; re-export the plan or pass --allow-edited-plan"
+    )]
+    PlanChecksumMismatch { expected: String, actual: String },
+
+    #[error(
+        "unrecognized recommendation id(s) in plan: {0}; re-run with --ignore-unknown to skip them"
+    )]
+    UnknownRecommendation(String),
+
+    #[error("revision not found: {0}")]
+    RevisionNotFound(String),
+
+    #[error("no fixture repositories found under {0}")]
+    NoBenchFixtures(String),
+
+    #[error("{path} is not a harness JSON report: {source}")]
+    ReportParse {
+        path: String,
+        source: serde_json::Error,
+    },
+
+    #[error("could not fetch template {url}: {reason}")]
+    TemplateFetchFailed { url: String, reason: String },
+
+    #[error("usage error: {0}")]
+    Usage(String),
+
     #[error("io error: {0}")]
     Io(#[from] std::io::Error),
 
@@ -34,4 +81,101 @@ pub enum HarnessError {
     Json(#[from] serde_json::Error),
 }
 
+/// Stable error codes with a one-line summary, in declaration order. The code
+/// is reported as `data.code` in `--json` error envelopes.
+#[allow(dead_code)]
+pub const ERROR_CATALOG: &[(&str, &str)] = &[
+    ("not_git_repo", "the target is not a git repository"),
+    ("git_unavailable", "git could not be run"),
+    ("git_clone_failed", "cloning a remote repository failed"),
+    (
+        "config_not_found",
+        "a config file or template could not be found",
+    ),
+    (
+        "config_parse",
+        "a config file, flag or input could not be parsed",
+    ),
+    (
+        "path_not_found",
+        "a path given on the command line does not exist",
+    ),
+    ("scan_limit_exceeded", "the scan hit the --max-files limit"),
+    (
+        "invalid_profile_target",
+        "an unknown profile target was requested",
+    ),
+    (
+        "bucket_penalty_exceeded",
+        "a scoring bucket penalty exceeded its maximum",
+    ),
+    (
+        "forbidden_tool_access",
+        "a command was blocked by the tool guardrails",
+    ),
+    (
+        "apply_cap_exceeded",
+        "apply would write more files than --max-changes allows",
+    ),
+    (
+        "usage",
+        "command-line arguments conflict in a way clap cannot detect",
+    ),
+    ("io", "a filesystem operation failed"),
+    ("toml", "TOML could not be deserialized"),
+    ("json", "JSON could not be serialized or deserialized"),
+];
+
+impl HarnessError {
+    /// Stable code for this error, as listed in [`ERROR_CATALOG`].
+    pub fn code(&self) -> &'static str {
+        match self {
+            HarnessError::NotGitRepo(_) => "not_git_repo",
+            HarnessError::GitUnavailable(_) => "git_unavailable",
+            HarnessError::GitCloneFailed(_) => "git_clone_failed",
+            HarnessError::ConfigNotFound(_) => "config_not_found",
+            HarnessError::ConfigParse(_) => "config_parse",
+            HarnessError::PathNotFound(_) => "path_not_found",
+            HarnessError::ScanLimitExceeded(_) => "scan_limit_exceeded",
+            HarnessError::InvalidProfileTarget(_) => "invalid_profile_target",
+            HarnessError::BucketPenaltyExceeded(_) => "bucket_penalty_exceeded",
+            HarnessError::ForbiddenToolAccess(_) => "forbidden_tool_access",
+            HarnessError::ApplyCapExceeded { .. } => "apply_cap_exceeded",
+            HarnessError::InvalidPlannedChange { .. } => "invalid_planned_change",
+            HarnessError::PlanChecksumMismatch { .. } => "plan_checksum_mismatch",
+            HarnessError::UnknownRecommendation(_) => "unknown_recommendation",
+            HarnessError::RevisionNotFound(_) => "revision_not_found",
+            HarnessError::NoBenchFixtures(_) => "no_bench_fixtures",
+            HarnessError::ReportParse { .. } => "report_parse",
+            HarnessError::TemplateFetchFailed { .. } => "template_fetch_failed",
+            HarnessError::Usage(_) => "usage",
+            HarnessError::Io(_) => "io",
+            HarnessError::Toml(_) => "toml",
+            HarnessError::Json(_) => "json",
+        }
+    }
+}
+
 pub type Result<T> = std::result::Result<T, HarnessError>;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn error_catalog_codes_are_unique_and_cover_reported_codes() {
+        let mut codes: Vec<&str> = ERROR_CATALOG.iter().map(|(code, _)| *code).collect();
+        codes.sort_unstable();
+        codes.dedup();
+        assert_eq!(codes.len(), ERROR_CATALOG.len());
+
+        let errors = [
+            HarnessError::ConfigParse("bad".to_string()),
+            HarnessError::ApplyCapExceeded { changes: 3, max: 1 },
+            HarnessError::Io(std::io::Error::other("disk")),
+        ];
+        for error in &errors {
+            assert!(codes.contains(&error.code()), "{}", error.code());
+        }
+    }
+}
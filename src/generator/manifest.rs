@@ -1,5 +1,7 @@
+use crate::artifacts;
 use crate::error::{HarnessError, Result};
-use chrono::Utc;
+use crate::util::hash::sha256_hex;
+use chrono::{DateTime, Utc};
 use serde::Serialize;
 use std::fs;
 use std::path::{Path, PathBuf};
@@ -9,24 +11,55 @@ pub struct SuggestPlan {
     pub version: String,
     pub generated_at: String,
     pub recommendations: Vec<String>,
+    pub checksum: String,
 }
 
 impl SuggestPlan {
-    pub fn new(recommendations: Vec<String>) -> Self {
+    pub fn new(recommendations: Vec<String>, generated_at: DateTime<Utc>) -> Self {
         Self {
             version: env!("CARGO_PKG_VERSION").to_string(),
-            generated_at: Utc::now().to_rfc3339(),
+            generated_at: generated_at.to_rfc3339(),
+            checksum: recommendations_checksum(&recommendations),
             recommendations,
         }
     }
 }
 
-pub fn write_plan(root: &Path, plan: &SuggestPlan) -> Result<PathBuf> {
-    let dir = root.join(".harness/plans");
+pub fn recommendations_checksum(recommendations: &[String]) -> String {
+    sha256_hex(recommendations.join("\n").as_bytes())
+}
+
+pub fn write_plan(
+    root: &Path,
+    output_dir: Option<&Path>,
+    now: DateTime<Utc>,
+    plan: &SuggestPlan,
+) -> Result<PathBuf> {
+    let dir = artifacts::artifact_dir(root, output_dir, "plans");
     fs::create_dir_all(&dir).map_err(HarnessError::Io)?;
-    let stamp = Utc::now().format("%Y%m%dT%H%M%SZ");
+    let stamp = artifacts::file_stamp(now);
     let out_path = dir.join(format!("plan-{stamp}.json"));
     let json = serde_json::to_string_pretty(plan)?;
     fs::write(&out_path, json).map_err(HarnessError::Io)?;
     Ok(out_path)
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn plan_checksum_covers_recommendation_ids_and_order() {
+        let ids = vec![
+            "rec.context.index".to_string(),
+            "rec.repo.scale".to_string(),
+        ];
+        let plan = SuggestPlan::new(ids.clone(), Utc::now());
+        assert_eq!(plan.checksum, recommendations_checksum(&ids));
+        assert_eq!(plan.checksum.len(), 64);
+
+        let reordered = vec![ids[1].clone(), ids[0].clone()];
+        assert_ne!(plan.checksum, recommendations_checksum(&reordered));
+        assert_ne!(plan.checksum, recommendations_checksum(&ids[..1]));
+    }
+}
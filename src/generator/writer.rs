@@ -1,14 +1,17 @@
 use crate::analyze;
+use crate::artifacts;
 use crate::cli::{ApplyCommand, ApplyMode};
 use crate::config;
 use crate::error::{HarnessError, Result};
+use crate::generator::manifest::recommendations_checksum;
 use crate::guardrails;
 use crate::scan;
 use crate::types::report::Risk;
-use chrono::Utc;
+use crate::util::git::{spawn_error, GIT_PROGRAM};
+use crate::util::hash::sha256_hex;
+use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
-use sha2::{Digest, Sha256};
-use std::collections::BTreeSet;
+use std::collections::{BTreeMap, BTreeSet};
 use std::fs;
 use std::io::{self, Write};
 use std::path::{Component, Path, PathBuf};
@@ -41,6 +44,7 @@ struct PlannedChange {
 struct ApplyPlanFile {
     version: String,
     recommendations: Vec<String>,
+    checksum: Option<String>,
 }
 
 #[derive(Debug, Serialize)]
@@ -57,15 +61,90 @@ struct RollbackFile {
     sha256: Option<String>,
 }
 
-pub fn execute_apply(cmd: &ApplyCommand) -> Result<()> {
-    let loaded = config::load_config(&cmd.path)?;
+pub const ASSUME_YES_ENV: &str = "HARNESS_ASSUME_YES";
 
-    if !cmd.allow_dirty {
+pub struct ApplyOptions<'a> {
+    pub max_files: Option<usize>,
+    pub output_dir: Option<&'a Path>,
+    pub now: DateTime<Utc>,
+    pub quiet: bool,
+    pub no_merge: bool,
+    pub json: bool,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ApplyResult {
+    NoOp,
+    Preview,
+    Cancelled,
+    Applied,
+    Checked,
+}
+
+#[derive(Debug, Serialize)]
+pub struct ApplyOutcome {
+    pub result: ApplyResult,
+    pub changes: Vec<ScopeEntry>,
+    pub rollback_manifest: Option<PathBuf>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub plan_check: Option<PlanCheck>,
+}
+
+#[derive(Debug, Default, Serialize)]
+pub struct PlanCheck {
+    pub fresh: Vec<String>,
+    pub stale: Vec<String>,
+    pub conflicts: Vec<String>,
+}
+
+impl PlanCheck {
+    pub fn is_fully_stale(&self) -> bool {
+        self.fresh.is_empty()
+    }
+}
+
+#[derive(Debug, Serialize)]
+pub struct ScopeEntry {
+    pub action: &'static str,
+    pub path: String,
+}
+
+impl ApplyOutcome {
+    fn new(result: ApplyResult, root: &Path, changes: &[PlannedChange]) -> Self {
+        Self {
+            result,
+            changes: changes
+                .iter()
+                .map(|change| ScopeEntry {
+                    action: change.action.as_str(),
+                    path: change
+                        .path
+                        .strip_prefix(root)
+                        .unwrap_or(change.path.as_path())
+                        .display()
+                        .to_string(),
+                })
+                .collect(),
+            rollback_manifest: None,
+            plan_check: None,
+        }
+    }
+}
+
+pub fn execute_apply(cmd: &ApplyCommand, options: &ApplyOptions) -> Result<ApplyOutcome> {
+    let loaded = config::load_config(&cmd.path, options.no_merge)?;
+
+    if !cmd.allow_dirty && !cmd.check {
         check_clean_tree(&cmd.path, loaded.as_ref())?;
     }
 
-    let recommendation_ids = resolve_plan(&cmd.path, cmd, loaded.as_ref())?;
-    let mut changes = build_changes(&cmd.path, &recommendation_ids)?;
+    let scan_options = scan::ScanOptions::resolve(loaded.as_ref(), options.max_files);
+    let recommendation_ids = resolve_plan(&cmd.path, cmd, loaded.as_ref(), &scan_options)?;
+    if cmd.check {
+        return check_plan(&cmd.path, &recommendation_ids, cmd.ignore_unknown, options);
+    }
+    let mut changes = build_changes(&cmd.path, &recommendation_ids, cmd.ignore_unknown)?;
     if let Some(lifecycle_change) = build_disabled_tool_promotion_change(&cmd.path)? {
         changes.push(lifecycle_change);
     }
@@ -75,30 +154,167 @@ pub fn execute_apply(cmd: &ApplyCommand) -> Result<()> {
         vec!["apply_patch"]
     };
     guardrails::validate_with_config(&planned_commands, changes.len() as u32, loaded.as_ref())?;
+    validate_planned_content(&changes)?;
 
-    print_scope_summary(&cmd.path, &changes);
+    if !options.json {
+        print_scope_summary(&cmd.path, &changes);
+    }
     if changes.is_empty() {
-        println!("no-op: no changes required");
-        return Ok(());
+        if !options.quiet {
+            println!("no-op: no changes required");
+        }
+        return Ok(ApplyOutcome::new(ApplyResult::NoOp, &cmd.path, &changes));
     }
 
     if matches!(cmd.apply_mode, ApplyMode::Preview) {
-        println!("preview: no files were written");
-        return Ok(());
+        if !options.quiet {
+            println!("preview: no files were written");
+        }
+        return Ok(ApplyOutcome::new(ApplyResult::Preview, &cmd.path, &changes));
     }
+    check_change_cap(changes.len(), cmd.max_changes)?;
 
-    if !cmd.yes && !confirm_apply()? {
-        println!("apply cancelled");
-        return Ok(());
+    let mut prompt: Box<dyn Write> = if options.json {
+        Box::new(io::stderr())
+    } else {
+        Box::new(io::stdout())
+    };
+    if cmd.interactive {
+        let stdin = io::stdin();
+        changes = select_changes(&cmd.path, changes, &mut stdin.lock(), &mut prompt)?;
+        if changes.is_empty() {
+            if !options.quiet {
+                println!("apply cancelled: no changes selected");
+            }
+            return Ok(ApplyOutcome::new(
+                ApplyResult::Cancelled,
+                &cmd.path,
+                &changes,
+            ));
+        }
+    } else if !assume_yes(cmd.yes, std::env::var(ASSUME_YES_ENV).ok().as_deref())
+        && !confirm_apply(&mut prompt)?
+    {
+        if !options.quiet {
+            println!("apply cancelled");
+        }
+        return Ok(ApplyOutcome::new(
+            ApplyResult::Cancelled,
+            &cmd.path,
+            &changes,
+        ));
     }
 
-    let rollback_path = create_rollback_manifest(&cmd.path, &changes)?;
-    println!("rollback manifest: {}", rollback_path.display());
+    let rollback_path =
+        create_rollback_manifest(&cmd.path, options.output_dir, options.now, &changes)?;
+    if !options.quiet {
+        println!("rollback manifest: {}", rollback_path.display());
+    }
     apply_changes(&changes)?;
-    println!("apply complete: wrote {} file(s)", changes.len());
+    if !options.quiet {
+        println!("apply complete: wrote {} file(s)", changes.len());
+    }
+    Ok(ApplyOutcome {
+        rollback_manifest: Some(rollback_path),
+        ..ApplyOutcome::new(ApplyResult::Applied, &cmd.path, &changes)
+    })
+}
+
+fn check_plan(
+    root: &Path,
+    recommendation_ids: &[String],
+    ignore_unknown: bool,
+    options: &ApplyOptions,
+) -> Result<ApplyOutcome> {
+    let mut check = PlanCheck::default();
+    let mut changes = Vec::new();
+    let mut owners: BTreeMap<PathBuf, Vec<String>> = BTreeMap::new();
+    let mut seen = BTreeSet::new();
+    for id in recommendation_ids {
+        if !seen.insert(id) {
+            continue;
+        }
+        let id_changes = build_changes(root, std::slice::from_ref(id), ignore_unknown)?;
+        if id_changes.is_empty() {
+            check.stale.push(id.clone());
+            continue;
+        }
+        check.fresh.push(id.clone());
+        for change in &id_changes {
+            owners
+                .entry(change.path.clone())
+                .or_default()
+                .push(id.clone());
+        }
+        changes.extend(id_changes);
+    }
+    check.conflicts = owners
+        .into_iter()
+        .filter(|(_, ids)| ids.len() > 1)
+        .map(|(path, ids)| {
+            let display_path = path.strip_prefix(root).unwrap_or(path.as_path()).display();
+            format!("{display_path} ({})", ids.join(", "))
+        })
+        .collect();
+
+    if !options.json {
+        print_scope_summary(root, &changes);
+        for id in &check.fresh {
+            println!("fresh: {id}");
+        }
+        for id in &check.stale {
+            println!("stale: {id} (no changes required)");
+        }
+        for conflict in &check.conflicts {
+            println!("conflict: {conflict}");
+        }
+    }
+    if !options.quiet {
+        let verdict = if check.is_fully_stale() {
+            "stale"
+        } else {
+            "valid"
+        };
+        println!(
+            "plan check: {verdict} ({} fresh, {} stale, {} conflict(s)); no files were written",
+            check.fresh.len(),
+            check.stale.len(),
+            check.conflicts.len()
+        );
+    }
+    Ok(ApplyOutcome {
+        plan_check: Some(check),
+        ..ApplyOutcome::new(ApplyResult::Checked, root, &changes)
+    })
+}
+
+fn validate_planned_content(changes: &[PlannedChange]) -> Result<()> {
+    for change in changes {
+        let issue = if change.content.trim().is_empty() {
+            "content is empty"
+        } else if !change.content.ends_with('\n') {
+            "content does not end with a newline"
+        } else {
+            continue;
+        };
+        return Err(HarnessError::InvalidPlannedChange {
+            path: change.path.display().to_string(),
+            reason: format!("generated {issue} ({})", change.action.as_str()),
+        });
+    }
     Ok(())
 }
 
+fn check_change_cap(change_count: usize, max_changes: Option<usize>) -> Result<()> {
+    match max_changes {
+        Some(max) if change_count > max => Err(HarnessError::ApplyCapExceeded {
+            changes: change_count,
+            max,
+        }),
+        _ => Ok(()),
+    }
+}
+
 pub fn check_clean_tree(
     root: &Path,
     config: Option<&crate::types::config::HarnessConfig>,
@@ -106,12 +322,7 @@ pub fn check_clean_tree(
     let command_line = "git status --porcelain";
     guardrails::validate_with_config(&[command_line], 0, config)?;
 
-    let output = Command::new("git")
-        .args(["status", "--porcelain"])
-        .current_dir(root)
-        .output()
-        .map_err(HarnessError::Io)?;
-
+    let output = git_status_porcelain(GIT_PROGRAM, root)?;
     if !output.status.success() {
         return Err(HarnessError::NotGitRepo(root.display().to_string()));
     }
@@ -125,6 +336,14 @@ pub fn check_clean_tree(
     }
 }
 
+fn git_status_porcelain(program: &str, root: &Path) -> Result<std::process::Output> {
+    Command::new(program)
+        .args(["status", "--porcelain"])
+        .current_dir(root)
+        .output()
+        .map_err(|err| spawn_error(program, err))
+}
+
 pub fn validate_plan_path(path: &str) -> Result<()> {
     let parsed = Path::new(path);
     if parsed.is_absolute() {
@@ -147,20 +366,29 @@ fn resolve_plan(
     root: &Path,
     cmd: &ApplyCommand,
     preloaded_config: Option<&crate::types::config::HarnessConfig>,
+    scan_options: &scan::ScanOptions,
 ) -> Result<Vec<String>> {
     if cmd.plan_all {
-        let model = scan::discover(root, preloaded_config);
-        let report = analyze::analyze(&model, preloaded_config);
-        let ids = report
-            .recommendations
-            .into_iter()
-            .filter(|recommendation| matches!(recommendation.risk, Risk::Safe))
-            .map(|recommendation| recommendation.id)
-            .collect::<Vec<_>>();
+        let ids = safe_recommendation_ids(root, preloaded_config, scan_options)?;
         validate_recommendation_ids(&ids)?;
         return Ok(ids);
     }
 
+    if !cmd.plan_ids.is_empty() {
+        if !cmd.ignore_unknown {
+            validate_recommendation_ids(&cmd.plan_ids)?;
+        }
+        let safe_ids = safe_recommendation_ids(root, preloaded_config, scan_options)?;
+        let (selected, skipped): (Vec<String>, Vec<String>) =
+            drop_excluded_recommendations(cmd.plan_ids.clone(), preloaded_config)
+                .into_iter()
+                .partition(|id| safe_ids.contains(id));
+        for id in skipped {
+            eprintln!("warning: skipping {id}: not a safe recommendation for this repository");
+        }
+        return Ok(selected);
+    }
+
     let plan_file = cmd
         .plan_file
         .as_ref()
@@ -180,41 +408,121 @@ fn resolve_plan(
             parsed.version
         )));
     }
-    validate_recommendation_ids(&parsed.recommendations)?;
+    if !cmd.allow_edited_plan {
+        verify_plan_checksum(&parsed)?;
+    }
+    if !cmd.ignore_unknown {
+        validate_recommendation_ids(&parsed.recommendations)?;
+    }
+
+    Ok(drop_excluded_recommendations(
+        parsed.recommendations,
+        preloaded_config,
+    ))
+}
+
+fn verify_plan_checksum(plan: &ApplyPlanFile) -> Result<()> {
+    let expected = recommendations_checksum(&plan.recommendations);
+    match plan.checksum.as_deref() {
+        Some(checksum) if checksum == expected => Ok(()),
+        Some(checksum) => Err(HarnessError::PlanChecksumMismatch {
+            expected,
+            actual: checksum.to_string(),
+        }),
+        None => {
+            eprintln!(
+                "warning: plan file has no checksum; re-export it with suggest --export-diff \
+                 to detect edits"
+            );
+            Ok(())
+        }
+    }
+}
+
+fn drop_excluded_recommendations(
+    ids: Vec<String>,
+    config: Option<&crate::types::config::HarnessConfig>,
+) -> Vec<String> {
+    let Some(config) = config else {
+        return ids;
+    };
+    ids.into_iter()
+        .filter(|id| {
+            let excluded = config.is_recommendation_excluded(id);
+            if excluded {
+                eprintln!("warning: skipping {id}: excluded by [recommendations] exclude");
+            }
+            !excluded
+        })
+        .collect()
+}
 
-    Ok(parsed.recommendations)
+fn safe_recommendation_ids(
+    root: &Path,
+    preloaded_config: Option<&crate::types::config::HarnessConfig>,
+    scan_options: &scan::ScanOptions,
+) -> Result<Vec<String>> {
+    let model = scan::discover(root, preloaded_config, scan_options)?;
+    let report = analyze::analyze(&model, preloaded_config);
+    Ok(report
+        .recommendations
+        .into_iter()
+        .filter(|recommendation| matches!(recommendation.risk, Risk::Safe))
+        .map(|recommendation| recommendation.id)
+        .collect())
 }
 
 fn validate_recommendation_ids(ids: &[String]) -> Result<()> {
     let unknown = ids
         .iter()
-        .filter(|id| !is_known_recommendation_id(id))
+        .filter(|id| !analyze::registry::is_known_recommendation(id))
         .cloned()
         .collect::<Vec<_>>();
     if unknown.is_empty() {
         Ok(())
     } else {
         Err(HarnessError::ConfigParse(format!(
-            "unknown recommendation id(s) in plan: {}",
+            "unknown recommendation id(s) in plan: {}; re-run with --ignore-unknown to skip them",
             unknown.join(", ")
         )))
     }
 }
 
-fn is_known_recommendation_id(id: &str) -> bool {
-    matches!(
-        id,
-        "rec.context.index" | "rec.verification.gate" | "rec.tools.prune" | "rec.repo.scale"
-    )
+struct ApplyPrecondition {
+    id: &'static str,
+    skip_if_exists: &'static [&'static str],
+}
+
+const APPLY_PRECONDITIONS: [ApplyPrecondition; 1] = [ApplyPrecondition {
+    id: "rec.repo.scale",
+    skip_if_exists: &scan::docs::ARCHITECTURE_DOC_FILES,
+}];
+
+fn already_satisfied(root: &Path, id: &str) -> Option<&'static str> {
+    APPLY_PRECONDITIONS
+        .iter()
+        .filter(|precondition| precondition.id == id)
+        .flat_map(|precondition| precondition.skip_if_exists)
+        .find(|file| root.join(file).exists())
+        .copied()
 }
 
-fn build_changes(root: &Path, recommendation_ids: &[String]) -> Result<Vec<PlannedChange>> {
+fn build_changes(
+    root: &Path,
+    recommendation_ids: &[String],
+    ignore_unknown: bool,
+) -> Result<Vec<PlannedChange>> {
     let mut changes = Vec::new();
     let mut seen = BTreeSet::new();
+    let mut unrecognized = Vec::new();
     for id in recommendation_ids {
         if !seen.insert(id.clone()) {
             continue;
         }
+        if let Some(existing) = already_satisfied(root, id) {
+            eprintln!("warning: skipping {id}: {existing} already exists");
+            continue;
+        }
 
         match id.as_str() {
             "rec.context.index" => {
@@ -224,9 +532,22 @@ fn build_changes(root: &Path, recommendation_ids: &[String]) -> Result<Vec<Plann
             "rec.repo.scale" => {
                 maybe_add_architecture_doc_change(root, &mut changes)?;
             }
-            _ => {}
+            "rec.repo.gitignore_harness" => {
+                maybe_add_gitignore_harness_change(root, &mut changes)?;
+            }
+            _ => unrecognized.push(id.clone()),
         }
     }
+
+    if !unrecognized.is_empty() {
+        if !ignore_unknown {
+            return Err(HarnessError::UnknownRecommendation(unrecognized.join(", ")));
+        }
+        eprintln!(
+            "warning: ignoring unrecognized recommendation id(s): {}",
+            unrecognized.join(", ")
+        );
+    }
     Ok(changes)
 }
 
@@ -237,15 +558,11 @@ fn build_disabled_tool_promotion_change(root: &Path) -> Result<Option<PlannedCha
     }
 
     let raw = fs::read_to_string(&path).map_err(HarnessError::Io)?;
-    let mut parsed: Value = toml::from_str(&raw).map_err(|error| {
-        HarnessError::ConfigParse(format!("{}: {}", path.display(), error))
-    })?;
+    let mut parsed: Value = toml::from_str(&raw)
+        .map_err(|error| HarnessError::ConfigParse(format!("{}: {}", path.display(), error)))?;
 
     let root_table = parsed.as_table_mut().ok_or_else(|| {
-        HarnessError::ConfigParse(format!(
-            "{}: root TOML must be a table",
-            path.display()
-        ))
+        HarnessError::ConfigParse(format!("{}: root TOML must be a table", path.display()))
     })?;
     let tools = root_table
         .entry("tools")
@@ -339,7 +656,11 @@ fn build_disabled_tool_promotion_change(root: &Path) -> Result<Option<PlannedCha
 
     *forbidden = Value::Array(forbidden_tools.into_iter().map(Value::String).collect());
     let content = toml::to_string(&parsed).map_err(|error| {
-        HarnessError::ConfigParse(format!("{}: failed to serialize promoted config: {}", path.display(), error))
+        HarnessError::ConfigParse(format!(
+            "{}: failed to serialize promoted config: {}",
+            path.display(),
+            error
+        ))
     })?;
     if content == raw {
         return Ok(None);
@@ -405,6 +726,35 @@ fn maybe_add_architecture_doc_change(root: &Path, changes: &mut Vec<PlannedChang
     Ok(())
 }
 
+fn maybe_add_gitignore_harness_change(root: &Path, changes: &mut Vec<PlannedChange>) -> Result<()> {
+    let path = root.join(".gitignore");
+    let entry = ".harness/";
+    if path.exists() {
+        let existing = fs::read_to_string(&path).map_err(HarnessError::Io)?;
+        if !scan::ignores_harness_artifacts(&existing) {
+            let mut updated = existing;
+            if !updated.is_empty() && !updated.ends_with('\n') {
+                updated.push('\n');
+            }
+            updated.push_str(entry);
+            updated.push('\n');
+            changes.push(PlannedChange {
+                path,
+                action: ChangeAction::Modify,
+                content: updated,
+            });
+        }
+        return Ok(());
+    }
+
+    changes.push(PlannedChange {
+        path,
+        action: ChangeAction::Create,
+        content: format!("{entry}\n"),
+    });
+    Ok(())
+}
+
 fn print_scope_summary(root: &Path, changes: &[PlannedChange]) {
     let create_count = changes
         .iter()
@@ -426,9 +776,54 @@ fn print_scope_summary(root: &Path, changes: &[PlannedChange]) {
     }
 }
 
-fn confirm_apply() -> Result<bool> {
-    print!("Apply these changes? [y/N]: ");
-    io::stdout().flush().map_err(HarnessError::Io)?;
+fn select_changes(
+    root: &Path,
+    changes: Vec<PlannedChange>,
+    input: &mut impl io::BufRead,
+    output: &mut impl Write,
+) -> Result<Vec<PlannedChange>> {
+    let mut selected = Vec::new();
+    'changes: for change in changes {
+        let display_path = change
+            .path
+            .strip_prefix(root)
+            .unwrap_or(change.path.as_path())
+            .display()
+            .to_string();
+        loop {
+            write!(
+                output,
+                "{} {}? [a]pply/[s]kip/[q]uit: ",
+                change.action.as_str(),
+                display_path
+            )
+            .map_err(HarnessError::Io)?;
+            output.flush().map_err(HarnessError::Io)?;
+            let mut answer = String::new();
+            if input.read_line(&mut answer).map_err(HarnessError::Io)? == 0 {
+                break 'changes;
+            }
+            match answer.trim().to_ascii_lowercase().as_str() {
+                "a" | "apply" => {
+                    selected.push(change);
+                    break;
+                }
+                "s" | "skip" => break,
+                "q" | "quit" => break 'changes,
+                _ => {}
+            }
+        }
+    }
+    Ok(selected)
+}
+
+fn assume_yes(yes_flag: bool, env_value: Option<&str>) -> bool {
+    yes_flag || env_value.is_some_and(|value| value.trim() == "1")
+}
+
+fn confirm_apply(prompt: &mut impl Write) -> Result<bool> {
+    write!(prompt, "Apply these changes? [y/N]: ").map_err(HarnessError::Io)?;
+    prompt.flush().map_err(HarnessError::Io)?;
     let mut input = String::new();
     io::stdin()
         .read_line(&mut input)
@@ -437,11 +832,15 @@ fn confirm_apply() -> Result<bool> {
     Ok(normalized == "y" || normalized == "yes")
 }
 
-fn create_rollback_manifest(root: &Path, changes: &[PlannedChange]) -> Result<PathBuf> {
-    let timestamp = Utc::now();
-    let timestamp_string = timestamp.to_rfc3339();
-    let file_stamp = timestamp.format("%Y%m%dT%H%M%SZ").to_string();
-    let rollback_dir = root.join(".harness/rollback");
+fn create_rollback_manifest(
+    root: &Path,
+    output_dir: Option<&Path>,
+    now: DateTime<Utc>,
+    changes: &[PlannedChange],
+) -> Result<PathBuf> {
+    let timestamp_string = now.to_rfc3339();
+    let file_stamp = artifacts::file_stamp(now);
+    let rollback_dir = artifacts::artifact_dir(root, output_dir, "rollback");
     fs::create_dir_all(&rollback_dir).map_err(HarnessError::Io)?;
 
     let mut files = Vec::new();
@@ -479,11 +878,6 @@ fn create_rollback_manifest(root: &Path, changes: &[PlannedChange]) -> Result<Pa
     Ok(out_path)
 }
 
-fn sha256_hex(bytes: &[u8]) -> String {
-    let digest = Sha256::digest(bytes);
-    format!("{digest:x}")
-}
-
 fn apply_changes(changes: &[PlannedChange]) -> Result<()> {
     for change in changes {
         if let Some(parent) = change.path.parent() {
@@ -510,6 +904,51 @@ mod tests {
         assert!(output.status.success(), "git init should succeed");
     }
 
+    #[test]
+    fn planned_content_must_be_non_empty_and_newline_terminated() {
+        let change = |content: &str| PlannedChange {
+            path: PathBuf::from("AGENTS.md"),
+            action: ChangeAction::Modify,
+            content: content.to_string(),
+        };
+        assert!(validate_planned_content(&[change("# Agents\n")]).is_ok());
+
+        let err = validate_planned_content(&[change("# Agents\n"), change("")])
+            .expect_err("empty content should be rejected");
+        assert!(matches!(
+            &err,
+            HarnessError::InvalidPlannedChange { path, .. } if path == "AGENTS.md"
+        ));
+        assert!(err.to_string().contains("refusing to write AGENTS.md"));
+        assert!(err.to_string().contains("content is empty"));
+
+        let err = validate_planned_content(&[change("# Agents")])
+            .expect_err("missing trailing newline should be rejected");
+        assert!(err.to_string().contains("does not end with a newline"));
+    }
+
+    #[test]
+    fn change_cap_rejects_only_plans_above_the_limit() {
+        assert!(check_change_cap(3, None).is_ok());
+        assert!(check_change_cap(3, Some(3)).is_ok());
+        let err = check_change_cap(4, Some(3)).expect_err("cap should be enforced");
+        assert!(matches!(
+            err,
+            HarnessError::ApplyCapExceeded { changes: 4, max: 3 }
+        ));
+        assert!(err.to_string().contains("would write 4 file(s)"));
+    }
+
+    #[test]
+    fn assume_yes_honors_flag_or_env_value_of_one() {
+        assert!(assume_yes(true, None));
+        assert!(assume_yes(false, Some("1")));
+        assert!(assume_yes(true, Some("0")));
+        assert!(!assume_yes(false, Some("0")));
+        assert!(!assume_yes(false, Some("")));
+        assert!(!assume_yes(false, None));
+    }
+
     #[test]
     fn test_clean_tree_check_passes_on_clean_repo() {
         let tmp = TempDir::new().expect("temp dir should create");
@@ -525,6 +964,15 @@ mod tests {
         assert!(check_clean_tree(tmp.path(), None).is_err());
     }
 
+    #[test]
+    fn test_clean_tree_check_reports_missing_git_binary() {
+        let tmp = TempDir::new().expect("temp dir should create");
+        let err = git_status_porcelain("/nonexistent/harness-git", tmp.path())
+            .expect_err("missing git binary should fail");
+        assert!(matches!(err, HarnessError::GitUnavailable(_)));
+        assert!(err.to_string().contains("install git"));
+    }
+
     #[test]
     fn test_plan_file_rejects_path_traversal() {
         assert!(validate_plan_path("../../etc/passwd").is_err());
@@ -536,7 +984,7 @@ mod tests {
         let tmp = TempDir::new().expect("temp dir should create");
         fs::write(tmp.path().join("AGENTS.md"), "# Agents\n").expect("agents file should write");
 
-        let changes = build_changes(tmp.path(), &[String::from("rec.context.index")])
+        let changes = build_changes(tmp.path(), &[String::from("rec.context.index")], false)
             .expect("build changes should succeed");
 
         assert!(changes.iter().any(|change| {
@@ -548,6 +996,48 @@ mod tests {
         }));
     }
 
+    #[test]
+    fn test_build_changes_skips_architecture_doc_when_docs_variant_exists() {
+        let tmp = TempDir::new().expect("temp dir should create");
+        let ids = [String::from("rec.repo.scale")];
+        let changes = build_changes(tmp.path(), &ids, false).expect("build changes should succeed");
+        assert_eq!(changes.len(), 1);
+        assert_eq!(changes[0].path, tmp.path().join("ARCHITECTURE.md"));
+
+        fs::create_dir_all(tmp.path().join("docs")).expect("docs dir should create");
+        fs::write(tmp.path().join("docs/ARCHITECTURE.md"), "# Architecture\n")
+            .expect("docs architecture should write");
+        let changes = build_changes(tmp.path(), &ids, false).expect("build changes should succeed");
+        assert!(
+            changes.is_empty(),
+            "existing docs/ARCHITECTURE.md should satisfy rec.repo.scale"
+        );
+    }
+
+    #[test]
+    fn test_build_changes_appends_harness_gitignore_entry_once() {
+        let tmp = TempDir::new().expect("temp dir should create");
+        let ids = [String::from("rec.repo.gitignore_harness")];
+        let changes = build_changes(tmp.path(), &ids, false).expect("build changes should succeed");
+        assert_eq!(changes.len(), 1);
+        assert_eq!(changes[0].action, ChangeAction::Create);
+        assert_eq!(changes[0].content, ".harness/\n");
+
+        fs::write(tmp.path().join(".gitignore"), "target/").expect("gitignore should write");
+        let changes = build_changes(tmp.path(), &ids, false).expect("build changes should succeed");
+        assert_eq!(changes.len(), 1);
+        assert_eq!(changes[0].action, ChangeAction::Modify);
+        assert_eq!(changes[0].content, "target/\n.harness/\n");
+
+        fs::write(tmp.path().join(".gitignore"), &changes[0].content)
+            .expect("gitignore should write");
+        let changes = build_changes(tmp.path(), &ids, false).expect("build changes should succeed");
+        assert!(
+            changes.is_empty(),
+            "existing entry should not be duplicated"
+        );
+    }
+
     #[test]
     fn test_resolve_plan_rejects_unknown_recommendation_id() {
         let tmp = TempDir::new().expect("temp dir should create");
@@ -568,15 +1058,209 @@ mod tests {
             path: tmp.path().to_path_buf(),
             plan_file: Some("plan.json".to_string()),
             plan_all: false,
+            plan_ids: Vec::new(),
             apply_mode: ApplyMode::Preview,
             allow_dirty: true,
             yes: true,
+            ignore_unknown: false,
+            interactive: false,
+            max_changes: None,
+            allow_edited_plan: false,
+            check: false,
         };
 
-        let result = resolve_plan(tmp.path(), &cmd, None);
+        let result = resolve_plan(tmp.path(), &cmd, None, &scan::ScanOptions::default());
         assert!(result.is_err(), "unknown recommendation id should fail");
     }
 
+    fn plan_file_command(root: &Path, allow_edited_plan: bool) -> ApplyCommand {
+        ApplyCommand {
+            path: root.to_path_buf(),
+            plan_file: Some("plan.json".to_string()),
+            plan_all: false,
+            plan_ids: Vec::new(),
+            apply_mode: ApplyMode::Preview,
+            allow_dirty: true,
+            yes: true,
+            ignore_unknown: false,
+            interactive: false,
+            max_changes: None,
+            allow_edited_plan,
+            check: false,
+        }
+    }
+
+    #[test]
+    fn test_resolve_plan_accepts_matching_checksum() {
+        let tmp = TempDir::new().expect("temp dir should create");
+        let plan = crate::generator::manifest::SuggestPlan::new(
+            vec!["rec.repo.scale".to_string()],
+            chrono::Utc::now(),
+        );
+        fs::write(
+            tmp.path().join("plan.json"),
+            serde_json::to_string(&plan).expect("plan should serialize"),
+        )
+        .expect("plan should write");
+
+        let ids = resolve_plan(
+            tmp.path(),
+            &plan_file_command(tmp.path(), false),
+            None,
+            &scan::ScanOptions::default(),
+        )
+        .expect("untouched plan should resolve");
+        assert_eq!(ids, vec!["rec.repo.scale".to_string()]);
+    }
+
+    #[test]
+    fn test_resolve_plan_accepts_plan_without_checksum() {
+        let tmp = TempDir::new().expect("temp dir should create");
+        fs::write(
+            tmp.path().join("plan.json"),
+            format!(
+                r#"{{"version": "{}", "recommendations": ["rec.repo.scale"]}}"#,
+                env!("CARGO_PKG_VERSION")
+            ),
+        )
+        .expect("plan should write");
+
+        let ids = resolve_plan(
+            tmp.path(),
+            &plan_file_command(tmp.path(), false),
+            None,
+            &scan::ScanOptions::default(),
+        )
+        .expect("plan without checksum should resolve");
+        assert_eq!(ids, vec!["rec.repo.scale".to_string()]);
+    }
+
+    #[test]
+    fn test_resolve_plan_rejects_edited_plan_unless_allowed() {
+        let tmp = TempDir::new().expect("temp dir should create");
+        let mut plan = crate::generator::manifest::SuggestPlan::new(
+            vec!["rec.repo.scale".to_string()],
+            chrono::Utc::now(),
+        );
+        plan.recommendations.push("rec.context.index".to_string());
+        fs::write(
+            tmp.path().join("plan.json"),
+            serde_json::to_string(&plan).expect("plan should serialize"),
+        )
+        .expect("plan should write");
+
+        let err = resolve_plan(
+            tmp.path(),
+            &plan_file_command(tmp.path(), false),
+            None,
+            &scan::ScanOptions::default(),
+        )
+        .expect_err("edited plan should fail");
+        assert!(matches!(err, HarnessError::PlanChecksumMismatch { .. }));
+        assert!(err.to_string().contains("--allow-edited-plan"));
+
+        let ids = resolve_plan(
+            tmp.path(),
+            &plan_file_command(tmp.path(), true),
+            None,
+            &scan::ScanOptions::default(),
+        )
+        .expect("edited plan should resolve when allowed");
+        assert_eq!(ids.len(), 2);
+    }
+
+    #[test]
+    fn test_resolve_plan_ids_keeps_only_safe_recommendations() {
+        let tmp = TempDir::new().expect("temp dir should be created");
+        let cmd = ApplyCommand {
+            path: tmp.path().to_path_buf(),
+            plan_file: None,
+            plan_all: false,
+            plan_ids: vec![
+                "rec.repo.scale".to_string(),
+                "rec.verification.gate".to_string(),
+            ],
+            apply_mode: ApplyMode::Preview,
+            allow_dirty: true,
+            yes: true,
+            ignore_unknown: false,
+            interactive: false,
+            max_changes: None,
+            allow_edited_plan: false,
+            check: false,
+        };
+
+        let ids = resolve_plan(tmp.path(), &cmd, None, &scan::ScanOptions::default())
+            .expect("plan ids should resolve");
+        assert_eq!(ids, vec!["rec.repo.scale".to_string()]);
+    }
+
+    #[test]
+    fn test_build_changes_rejects_unrecognized_ids_unless_ignored() {
+        let tmp = TempDir::new().expect("temp dir should be created");
+        let ids = vec![
+            String::from("rec.context.index"),
+            String::from("rec.context.indx"),
+        ];
+
+        let err = build_changes(tmp.path(), &ids, false).expect_err("typo should be rejected");
+        assert!(
+            matches!(&err, HarnessError::UnknownRecommendation(ids) if ids == "rec.context.indx")
+        );
+
+        let changes = build_changes(tmp.path(), &ids, true).expect("typo should be skipped");
+        assert!(changes
+            .iter()
+            .any(|change| change.path.ends_with("docs/context/INDEX.md")));
+    }
+
+    fn planned(root: &Path, relative: &str) -> PlannedChange {
+        PlannedChange {
+            path: root.join(relative),
+            action: ChangeAction::Create,
+            content: String::new(),
+        }
+    }
+
+    #[test]
+    fn test_select_changes_applies_and_skips_per_answer() {
+        let root = Path::new("/repo");
+        let changes = vec![
+            planned(root, "A.md"),
+            planned(root, "B.md"),
+            planned(root, "C.md"),
+        ];
+        let mut input = io::Cursor::new("a\nwhat\ns\napply\n");
+        let mut output = Vec::new();
+
+        let selected =
+            select_changes(root, changes, &mut input, &mut output).expect("selection should work");
+        let paths = selected
+            .iter()
+            .map(|change| change.path.clone())
+            .collect::<Vec<_>>();
+        assert_eq!(paths, vec![root.join("A.md"), root.join("C.md")]);
+        let prompts = String::from_utf8(output).expect("prompts should be utf-8");
+        assert_eq!(prompts.matches("[a]pply/[s]kip/[q]uit").count(), 4);
+        assert!(prompts.contains("create B.md?"));
+    }
+
+    #[test]
+    fn test_select_changes_quit_keeps_earlier_choices() {
+        let root = Path::new("/repo");
+        let changes = vec![planned(root, "A.md"), planned(root, "B.md")];
+        let mut input = io::Cursor::new("a\nq\n");
+
+        let selected = select_changes(root, changes, &mut input, &mut Vec::new())
+            .expect("selection should work");
+        assert_eq!(selected.len(), 1);
+
+        let changes = vec![planned(root, "A.md")];
+        let selected = select_changes(root, changes, &mut io::Cursor::new(""), &mut Vec::new())
+            .expect("eof should stop selection");
+        assert!(selected.is_empty());
+    }
+
     #[test]
     fn test_rollback_manifest_files_are_sorted_by_path() {
         let tmp = TempDir::new().expect("temp dir should create");
@@ -596,8 +1280,8 @@ mod tests {
             },
         ];
 
-        let manifest_path =
-            create_rollback_manifest(tmp.path(), &changes).expect("manifest should write");
+        let manifest_path = create_rollback_manifest(tmp.path(), None, Utc::now(), &changes)
+            .expect("manifest should write");
         let manifest_raw =
             fs::read_to_string(manifest_path).expect("manifest content should be readable");
         let parsed: serde_json::Value =
@@ -655,7 +1339,10 @@ disabled = ["grep", "git push --force"]
             .and_then(|deprecated| deprecated.get("disabled"))
             .and_then(toml::Value::as_array)
             .expect("disabled should be an array");
-        assert!(disabled.is_empty(), "disabled should be cleared after promotion");
+        assert!(
+            disabled.is_empty(),
+            "disabled should be cleared after promotion"
+        );
     }
 
     #[test]
@@ -677,7 +1364,11 @@ disabled = []
         )
         .expect("harness.toml should write");
 
-        let change = build_disabled_tool_promotion_change(tmp.path()).expect("promotion should run");
-        assert!(change.is_none(), "empty disabled list should not generate changes");
+        let change =
+            build_disabled_tool_promotion_change(tmp.path()).expect("promotion should run");
+        assert!(
+            change.is_none(),
+            "empty disabled list should not generate changes"
+        );
     }
 }
@@ -0,0 +1,206 @@
+use crate::artifacts;
+use crate::error::HarnessError;
+use crate::types::report::Recommendation;
+use serde::{Deserialize, Serialize};
+use std::path::{Path, PathBuf};
+
+pub const HISTORY_FILE: &str = "history.jsonl";
+const MAX_HISTORY_ENTRIES: usize = 50;
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct HistoryEntry {
+    pub timestamp: String,
+    pub recommendations: Vec<String>,
+}
+
+pub fn history_path(root: &Path, output_dir: Option<&Path>) -> PathBuf {
+    artifacts::artifact_dir(root, output_dir, HISTORY_FILE)
+}
+
+pub fn read_history(
+    root: &Path,
+    output_dir: Option<&Path>,
+) -> Result<Vec<HistoryEntry>, HarnessError> {
+    let path = history_path(root, output_dir);
+    if !path.exists() {
+        return Ok(Vec::new());
+    }
+    let content = std::fs::read_to_string(&path).map_err(HarnessError::Io)?;
+    Ok(content
+        .lines()
+        .enumerate()
+        .filter(|(_, line)| !line.trim().is_empty())
+        .filter_map(|(index, line)| match serde_json::from_str(line) {
+            Ok(entry) => Some(entry),
+            Err(err) => {
+                eprintln!(
+                    "warning: {}:{}: skipping malformed history entry: {err}",
+                    path.display(),
+                    index + 1
+                );
+                None
+            }
+        })
+        .collect())
+}
+
+pub fn append_history(
+    root: &Path,
+    output_dir: Option<&Path>,
+    mut history: Vec<HistoryEntry>,
+    entry: HistoryEntry,
+) -> Result<(), HarnessError> {
+    history.push(entry);
+    let skip = history.len().saturating_sub(MAX_HISTORY_ENTRIES);
+    let mut content = String::new();
+    for entry in &history[skip..] {
+        content.push_str(&serde_json::to_string(entry).map_err(HarnessError::Json)?);
+        content.push('\n');
+    }
+    let path = history_path(root, output_dir);
+    if let Some(parent) = path.parent() {
+        std::fs::create_dir_all(parent).map_err(HarnessError::Io)?;
+    }
+    std::fs::write(path, content).map_err(HarnessError::Io)
+}
+
+pub fn persisted_runs(history: &[HistoryEntry], id: &str) -> usize {
+    history
+        .iter()
+        .rev()
+        .take_while(|entry| entry.recommendations.iter().any(|recorded| recorded == id))
+        .count()
+}
+
+pub fn decayed_confidence(confidence: f32, persisted_runs: usize, stale_decay: f32) -> f32 {
+    let exponent = i32::try_from(persisted_runs).unwrap_or(i32::MAX);
+    confidence * (1.0 - stale_decay).powi(exponent)
+}
+
+pub fn apply_stale_decay(
+    recommendations: &mut [Recommendation],
+    history: &[HistoryEntry],
+    stale_decay: f32,
+) {
+    for recommendation in recommendations {
+        let runs = persisted_runs(history, &recommendation.id);
+        recommendation.confidence =
+            decayed_confidence(recommendation.confidence, runs, stale_decay);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::report::{Effort, Impact, Risk};
+    use tempfile::TempDir;
+
+    fn entry(ids: &[&str]) -> HistoryEntry {
+        HistoryEntry {
+            timestamp: "2026-01-01T00:00:00+00:00".to_string(),
+            recommendations: ids.iter().map(|id| id.to_string()).collect(),
+        }
+    }
+
+    #[test]
+    fn decay_curve_is_geometric_in_persisted_runs() {
+        assert_eq!(decayed_confidence(0.8, 0, 0.5), 0.8);
+        assert_eq!(decayed_confidence(0.8, 1, 0.5), 0.4);
+        assert_eq!(decayed_confidence(0.8, 3, 0.5), 0.1);
+        assert_eq!(decayed_confidence(0.8, 5, 0.0), 0.8);
+        assert_eq!(decayed_confidence(0.8, 2, 1.0), 0.0);
+        assert!((decayed_confidence(0.9, 2, 0.1) - 0.729).abs() < 1e-6);
+    }
+
+    #[test]
+    fn persisted_runs_count_only_the_latest_consecutive_streak() {
+        let history = vec![
+            entry(&["rec.repo.scale"]),
+            entry(&[]),
+            entry(&["rec.repo.scale", "rec.context.index"]),
+            entry(&["rec.repo.scale"]),
+        ];
+        assert_eq!(persisted_runs(&history, "rec.repo.scale"), 2);
+        assert_eq!(persisted_runs(&history, "rec.context.index"), 0);
+        assert_eq!(persisted_runs(&[], "rec.repo.scale"), 0);
+    }
+
+    #[test]
+    fn persisted_runs_reset_when_a_run_omits_the_recommendation() {
+        let mut history = vec![entry(&["rec.repo.scale"]), entry(&["rec.repo.scale"])];
+        assert_eq!(persisted_runs(&history, "rec.repo.scale"), 2);
+
+        history.push(entry(&["rec.context.index"]));
+        assert_eq!(persisted_runs(&history, "rec.repo.scale"), 0);
+
+        history.push(entry(&["rec.repo.scale"]));
+        assert_eq!(persisted_runs(&history, "rec.repo.scale"), 1);
+    }
+
+    #[test]
+    fn apply_stale_decay_lowers_persisting_recommendations_only() {
+        let recommendation = |id: &str, confidence: f32| {
+            Recommendation::new(
+                id,
+                "t",
+                "s",
+                Impact::Low,
+                Effort::Xs,
+                Risk::Safe,
+                confidence,
+            )
+        };
+        let mut recommendations = vec![
+            recommendation("rec.repo.scale", 0.6),
+            recommendation("rec.context.index", 0.9),
+        ];
+        let history = vec![entry(&["rec.repo.scale"]), entry(&["rec.repo.scale"])];
+
+        apply_stale_decay(&mut recommendations, &history, 0.5);
+        assert!((recommendations[0].confidence - 0.15).abs() < 1e-6);
+        assert_eq!(recommendations[1].confidence, 0.9);
+    }
+
+    #[test]
+    fn history_round_trips_skips_malformed_lines_and_is_capped() {
+        let root = TempDir::new().expect("temp dir should be created");
+        assert!(read_history(root.path(), None)
+            .expect("missing history reads")
+            .is_empty());
+
+        let mut history = Vec::new();
+        for _ in 0..MAX_HISTORY_ENTRIES {
+            history.push(entry(&["rec.old"]));
+        }
+        append_history(root.path(), None, history, entry(&["rec.repo.scale"]))
+            .expect("history should write");
+        let path = root.path().join(".harness").join(HISTORY_FILE);
+        let mut content = std::fs::read_to_string(&path).expect("history should read");
+        content.push_str("not json\n");
+        std::fs::write(&path, content).expect("history should rewrite");
+
+        let history = read_history(root.path(), None).expect("history should read");
+        assert_eq!(history.len(), MAX_HISTORY_ENTRIES);
+        assert_eq!(history.last(), Some(&entry(&["rec.repo.scale"])));
+    }
+
+    #[test]
+    fn history_honors_output_dir() {
+        let root = TempDir::new().expect("temp dir should be created");
+        let out = TempDir::new().expect("output dir should be created");
+
+        append_history(
+            root.path(),
+            Some(out.path()),
+            Vec::new(),
+            entry(&["rec.repo.scale"]),
+        )
+        .expect("history should write");
+        assert!(out.path().join(HISTORY_FILE).exists());
+        assert!(!root.path().join(".harness").exists());
+        assert_eq!(
+            read_history(root.path(), Some(out.path())).expect("history should read"),
+            vec![entry(&["rec.repo.scale"])]
+        );
+    }
+}
@@ -0,0 +1,155 @@
+use serde::Deserialize;
+use std::collections::BTreeMap;
+
+pub const DEFAULT_LOCALE: &str = "en";
+pub const LOCALES: [&str; 2] = [DEFAULT_LOCALE, "es"];
+
+const ES_CATALOG: &str = include_str!("locales/es.json");
+
+#[derive(Debug, Clone, Default, Deserialize)]
+pub struct Catalog {
+    #[serde(default)]
+    findings: BTreeMap<String, FindingText>,
+    #[serde(default)]
+    recommendations: BTreeMap<String, RecommendationText>,
+    #[serde(default)]
+    headings: BTreeMap<String, String>,
+}
+
+#[derive(Debug, Clone, Default, Deserialize)]
+struct FindingText {
+    title: Option<String>,
+    body: Option<String>,
+}
+
+#[derive(Debug, Clone, Default, Deserialize)]
+struct RecommendationText {
+    title: Option<String>,
+    summary: Option<String>,
+}
+
+pub fn catalog(locale: &str) -> Catalog {
+    let source = match locale {
+        "es" => ES_CATALOG,
+        _ => return Catalog::default(),
+    };
+    serde_json::from_str(source).expect("embedded locale catalog should be valid JSON")
+}
+
+impl Catalog {
+    pub fn finding_title<'a>(&'a self, id: &str, english: &'a str) -> &'a str {
+        self.findings
+            .get(id)
+            .and_then(|text| text.title.as_deref())
+            .unwrap_or(english)
+    }
+
+    pub fn finding_body<'a>(&'a self, id: &str, english: &'a str) -> &'a str {
+        self.findings
+            .get(id)
+            .and_then(|text| text.body.as_deref())
+            .unwrap_or(english)
+    }
+
+    pub fn recommendation_title<'a>(&'a self, id: &str, english: &'a str) -> &'a str {
+        self.recommendations
+            .get(id)
+            .and_then(|text| text.title.as_deref())
+            .unwrap_or(english)
+    }
+
+    pub fn heading<'a>(&'a self, english: &'a str) -> &'a str {
+        self.headings.get(english).map_or(english, String::as_str)
+    }
+
+    pub fn recommendation_summary<'a>(&'a self, id: &str, english: &'a str) -> &'a str {
+        self.recommendations
+            .get(id)
+            .and_then(|text| text.summary.as_deref())
+            .unwrap_or(english)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::analyze::registry::{FINDINGS, RECOMMENDATIONS};
+    use crate::report::md::HEADINGS;
+    use crate::util::template::placeholders;
+
+    #[test]
+    fn english_catalog_falls_back_to_registry_text() {
+        let english = catalog(DEFAULT_LOCALE);
+        assert_eq!(
+            english.finding_title("tools.disabled", "Disabled"),
+            "Disabled"
+        );
+        assert_eq!(
+            english.recommendation_summary("rec.repo.scale", "Add notes"),
+            "Add notes"
+        );
+    }
+
+    #[test]
+    fn missing_locale_keys_fall_back_to_english() {
+        let partial: Catalog = serde_json::from_str(
+            r#"{"findings": {"tools.disabled": {"title": "Herramientas deshabilitadas"}}}"#,
+        )
+        .expect("catalog should parse");
+        assert_eq!(
+            partial.finding_title("tools.disabled", "Disabled tools"),
+            "Herramientas deshabilitadas"
+        );
+        assert_eq!(
+            partial.finding_body("tools.disabled", "english {tools}"),
+            "english {tools}"
+        );
+        assert_eq!(
+            partial.recommendation_title("rec.tools.prune", "Prune"),
+            "Prune"
+        );
+    }
+
+    #[test]
+    fn spanish_catalog_covers_registry_ids_with_matching_placeholders() {
+        let spanish = catalog("es");
+        for id in spanish.findings.keys() {
+            assert!(
+                FINDINGS.iter().any(|spec| spec.id == id),
+                "unknown finding id: {id}"
+            );
+        }
+        for id in spanish.recommendations.keys() {
+            assert!(
+                RECOMMENDATIONS.iter().any(|spec| spec.id == id),
+                "unknown recommendation id: {id}"
+            );
+        }
+        for spec in &FINDINGS {
+            let translated = spanish.finding_body(spec.id, spec.body_template);
+            assert_ne!(
+                translated, spec.body_template,
+                "untranslated finding: {}",
+                spec.id
+            );
+            let mut expected = placeholders(spec.body_template);
+            let mut found = placeholders(translated);
+            expected.sort_unstable();
+            found.sort_unstable();
+            assert_eq!(found, expected, "placeholder mismatch for {}", spec.id);
+        }
+        for english in spanish.headings.keys() {
+            assert!(
+                HEADINGS.contains(&english.as_str()),
+                "unknown heading: {english}"
+            );
+        }
+        for english in HEADINGS {
+            assert_ne!(
+                spanish.heading(english),
+                english,
+                "untranslated heading: {english}"
+            );
+        }
+    }
+}
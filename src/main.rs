@@ -1,13 +1,17 @@
 mod analyze;
+mod artifacts;
 mod cli;
 mod config;
 mod continuity;
 mod error;
 mod generator;
 mod guardrails;
+mod history;
+mod i18n;
 mod report;
 mod scan;
 mod types;
+mod util;
 // Deferred modules (uncomment when implementing):
 // mod optimization;
 // mod trace;
@@ -16,6 +20,7 @@ use crate::error::HarnessError;
 use clap::Parser;
 use serde::{Deserialize, Serialize};
 use std::collections::{BTreeMap, BTreeSet};
+use std::io::Write;
 
 pub mod exit_code {
     pub const SUCCESS: i32 = 0;
@@ -24,20 +29,78 @@ pub mod exit_code {
     pub const RUNTIME_FAILURE: i32 = 3;
 }
 
+const SCAN_STATS_TOP_DIRECTORIES: usize = 10;
+const RECOMMENDED_TRACE_MULTIPLIER: usize = 3;
+const REGRESSED_TASKS_LIMIT: usize = 5;
+
 fn run() -> Result<i32, HarnessError> {
     let cli = cli::Cli::parse();
-    println!("Harness CLI v{}", env!("CARGO_PKG_VERSION"));
+    let command = cli.command.name();
+    let mut timings = PhaseTimings::new(cli.profile_timing);
+    let mut output = JsonOutput::new(cli.json);
+    let result = run_command(cli, &mut timings, &mut output);
+    timings.report();
+    if output.enabled {
+        let envelope = json_envelope(command, &result, output.data);
+        println!(
+            "{}",
+            serde_json::to_string_pretty(&envelope).map_err(HarnessError::Json)?
+        );
+    }
+    result
+}
+
+fn run_command(
+    cli: cli::Cli,
+    timings: &mut PhaseTimings,
+    output: &mut JsonOutput,
+) -> Result<i32, HarnessError> {
+    let json = output.enabled;
+    let quiet = cli.quiet || json;
+    let threads = cli
+        .threads
+        .map_or_else(util::parallel::default_threads, std::num::NonZeroUsize::get);
+    let max_files = cli.max_files.map(std::num::NonZeroUsize::get);
+    if !quiet && !cli.command.emits_raw_output() {
+        println!("Harness CLI v{}", env!("CARGO_PKG_VERSION"));
+    }
+    let now = || artifacts::resolve_now(cli.frozen_time.as_deref());
     match cli.command {
         cli::Commands::Analyze(cmd) => {
-            if !cmd.path.exists() {
+            let remote_checkout = if scan::remote::is_remote_url(&cmd.path) {
+                if cmd.offline {
+                    return Err(HarnessError::Usage(
+                        "--offline cannot analyze a remote URL; clone it locally first".to_string(),
+                    ));
+                }
+                let url = cmd.path.to_string_lossy();
+                let checkout = scan::remote::shallow_clone(&url, cmd.keep_clone)?;
+                if checkout.is_kept() {
+                    eprintln!("kept clone: {}", checkout.path().display());
+                }
+                Some(checkout)
+            } else {
+                None
+            };
+            let repo_path = remote_checkout
+                .as_ref()
+                .map_or(cmd.path.as_path(), |checkout| checkout.path());
+
+            if !repo_path.exists() {
                 return Err(HarnessError::PathNotFound(cmd.path.display().to_string()));
             }
-            if !cmd.path.join(".git").exists() {
-                return Err(HarnessError::NotGitRepo(cmd.path.display().to_string()));
-            }
+            let has_git = require_git_repo(repo_path, &cmd.path, cli.no_git)?;
 
-            let loaded = config::load_config(&cmd.path)?;
-            let mut continuity_logger = continuity::ContinuityLogger::new(&cmd.path, loaded.as_ref());
+            let loaded = timings.time("config", || {
+                if cmd.stdin_config {
+                    let piped = std::io::read_to_string(std::io::stdin())?;
+                    config::load_config_with_repo_layer(repo_path, &piped, cli.no_merge).map(Some)
+                } else {
+                    config::load_config(repo_path, cli.no_merge)
+                }
+            })?;
+            let mut continuity_logger =
+                continuity::ContinuityLogger::new(repo_path, loaded.as_ref());
             continuity_milestone(
                 &mut continuity_logger,
                 "analyze",
@@ -45,22 +108,102 @@ fn run() -> Result<i32, HarnessError> {
                 &[format!("path={}", cmd.path.display())],
                 "running",
             );
-            let model = scan::discover(&cmd.path, loaded.as_ref());
-            let mut harness_report = analyze::analyze(&model, loaded.as_ref());
+            let scan_options = scan::ScanOptions::resolve(loaded.as_ref(), max_files)
+                .with_threads(threads)
+                .with_git_metadata(has_git && !cmd.offline);
+            let model = timings.time("scan", || {
+                scan::discover(repo_path, loaded.as_ref(), &scan_options)
+            })?;
+            let mut harness_report =
+                timings.time("analyze", || analyze::analyze(&model, loaded.as_ref()));
+            if let Some(stale_decay) = loaded.as_ref().and_then(|cfg| cfg.stale_decay()) {
+                let recorded = history::read_history(repo_path, cli.output_dir.as_deref())?;
+                history::apply_stale_decay(
+                    &mut harness_report.recommendations,
+                    &recorded,
+                    stale_decay,
+                );
+                harness_report.sort_recommendations();
+                let entry = history::HistoryEntry {
+                    timestamp: now()?.to_rfc3339(),
+                    recommendations: harness_report
+                        .recommendations
+                        .iter()
+                        .map(|recommendation| recommendation.id.clone())
+                        .collect(),
+                };
+                history::append_history(repo_path, cli.output_dir.as_deref(), recorded, entry)?;
+            }
+            if cmd.explain_score {
+                harness_report.score_breakdown =
+                    Some(analyze::score_breakdown(&model, loaded.as_ref()));
+            }
+            if cmd.scan_stats {
+                harness_report.scan_stats = Some(model.scan_stats(SCAN_STATS_TOP_DIRECTORIES));
+            }
+            if let Some(path) = &cmd.compare_report {
+                let baseline = read_json_report(path)?;
+                harness_report.baseline_changes = Some(report::compare::baseline_changes(
+                    &baseline,
+                    &harness_report,
+                ));
+            }
 
+            let mut min_risk = cmd.min_risk.clone();
             if matches!(cmd.min_impact, cli::MinImpact::Safe) {
+                eprintln!("warning: --min-impact safe is deprecated; use --min-risk safe");
+                min_risk = cli::MinRisk::Safe;
+            }
+            if matches!(min_risk, cli::MinRisk::Safe) {
                 harness_report.recommendations.retain(|recommendation| {
                     matches!(recommendation.risk, types::report::Risk::Safe)
                 });
             }
+            let min_impact = match cmd.min_impact {
+                cli::MinImpact::Medium => Some(types::report::Impact::Medium),
+                cli::MinImpact::High => Some(types::report::Impact::High),
+                cli::MinImpact::Low | cli::MinImpact::All | cli::MinImpact::Safe => None,
+            };
+            if let Some(min_impact) = min_impact {
+                harness_report
+                    .recommendations
+                    .retain(|recommendation| recommendation.impact.at_least(min_impact));
+            }
 
             let output_format = match cmd.format {
                 cli::ReportFormat::Json => report::OutputFormat::Json,
                 cli::ReportFormat::Md => report::OutputFormat::Md,
                 cli::ReportFormat::Sarif => report::OutputFormat::Sarif,
+                cli::ReportFormat::Summary => report::OutputFormat::Summary,
+                cli::ReportFormat::GithubActions => report::OutputFormat::GithubActions,
+                cli::ReportFormat::Count => report::OutputFormat::Count,
+                cli::ReportFormat::Ndjson => report::OutputFormat::Ndjson,
             };
-            let rendered = report::render(&harness_report, output_format)?;
-            println!("{rendered}");
+            if json {
+                output.set(&harness_report)?;
+            } else {
+                let max_findings = loaded
+                    .as_ref()
+                    .and_then(types::config::HarnessConfig::max_findings);
+                let catalog = i18n::catalog(
+                    loaded
+                        .as_ref()
+                        .map_or(i18n::DEFAULT_LOCALE, types::config::HarnessConfig::locale),
+                );
+                timings.time("render", || {
+                    let mut stdout = std::io::BufWriter::new(std::io::stdout().lock());
+                    report::render_to(
+                        &mut stdout,
+                        &harness_report,
+                        output_format,
+                        max_findings,
+                        &catalog,
+                    )?;
+                    writeln!(stdout)
+                        .and_then(|()| stdout.flush())
+                        .map_err(HarnessError::Io)
+                })?;
+            }
             continuity_progress(
                 &mut continuity_logger,
                 "analyze",
@@ -103,12 +246,11 @@ fn run() -> Result<i32, HarnessError> {
             if !cmd.path.exists() {
                 return Err(HarnessError::PathNotFound(cmd.path.display().to_string()));
             }
-            if !cmd.path.join(".git").exists() {
-                return Err(HarnessError::NotGitRepo(cmd.path.display().to_string()));
-            }
+            let has_git = require_git_repo(&cmd.path, &cmd.path, cli.no_git)?;
 
-            let loaded = config::load_config(&cmd.path)?;
-            let mut continuity_logger = continuity::ContinuityLogger::new(&cmd.path, loaded.as_ref());
+            let loaded = timings.time("config", || config::load_config(&cmd.path, cli.no_merge))?;
+            let mut continuity_logger =
+                continuity::ContinuityLogger::new(&cmd.path, loaded.as_ref());
             continuity_milestone(
                 &mut continuity_logger,
                 "suggest",
@@ -116,11 +258,22 @@ fn run() -> Result<i32, HarnessError> {
                 &[format!("path={}", cmd.path.display())],
                 "running",
             );
-            let model = scan::discover(&cmd.path, loaded.as_ref());
-            let report = analyze::analyze(&model, loaded.as_ref());
+            let scan_options = scan::ScanOptions::resolve(loaded.as_ref(), max_files)
+                .with_threads(threads)
+                .with_git_metadata(has_git);
+            let model = timings.time("scan", || {
+                scan::discover(&cmd.path, loaded.as_ref(), &scan_options)
+            })?;
+            let report = timings.time("analyze", || analyze::analyze(&model, loaded.as_ref()));
 
             if report.recommendations.is_empty() {
-                println!("suggest: no recommendations");
+                if !json {
+                    println!("suggest: no recommendations");
+                }
+                output.set(&serde_json::json!({
+                    "recommendations": [],
+                    "plan_file": null,
+                }))?;
                 continuity_milestone(
                     &mut continuity_logger,
                     "suggest",
@@ -131,17 +284,20 @@ fn run() -> Result<i32, HarnessError> {
                 return Ok(exit_code::SUCCESS);
             }
 
-            println!("suggestions:");
-            for recommendation in &report.recommendations {
-                println!(
-                    "- {} [{} {:?}/{:?}]",
-                    recommendation.id,
-                    recommendation.title,
-                    recommendation.impact,
-                    recommendation.risk
-                );
+            if !json {
+                println!("suggestions:");
+                for recommendation in &report.recommendations {
+                    println!(
+                        "- {} [{} {:?}/{:?}]",
+                        recommendation.id,
+                        recommendation.title,
+                        recommendation.impact,
+                        recommendation.risk
+                    );
+                }
             }
 
+            let mut plan_file = None;
             if cmd.export_diff {
                 let ids = report
                     .recommendations
@@ -151,9 +307,17 @@ fn run() -> Result<i32, HarnessError> {
                     })
                     .map(|recommendation| recommendation.id.clone())
                     .collect::<Vec<_>>();
-                let plan = generator::manifest::SuggestPlan::new(ids);
-                let path = generator::manifest::write_plan(&cmd.path, &plan)?;
-                println!("plan file: {}", path.display());
+                let now = now()?;
+                let plan = generator::manifest::SuggestPlan::new(ids, now);
+                let path = generator::manifest::write_plan(
+                    &cmd.path,
+                    cli.output_dir.as_deref(),
+                    now,
+                    &plan,
+                )?;
+                if !quiet {
+                    println!("plan file: {}", path.display());
+                }
                 continuity_progress(
                     &mut continuity_logger,
                     "suggest",
@@ -161,7 +325,12 @@ fn run() -> Result<i32, HarnessError> {
                     &[format!("plan={}", path.display())],
                     "running",
                 );
+                plan_file = Some(path);
             }
+            output.set(&serde_json::json!({
+                "recommendations": report.recommendations,
+                "plan_file": plan_file,
+            }))?;
 
             continuity_milestone(
                 &mut continuity_logger,
@@ -176,9 +345,16 @@ fn run() -> Result<i32, HarnessError> {
             Ok(exit_code::SUCCESS)
         }
         cli::Commands::Init(cmd) => {
+            let template = cmd
+                .from
+                .as_deref()
+                .map(|source| scan::remote_template::fetch_config_template(source, cmd.offline))
+                .transpose()?;
             if !cmd.path.exists() {
                 if cmd.dry_run {
-                    println!("init target would be created: {}", cmd.path.display());
+                    if !json {
+                        println!("init target would be created: {}", cmd.path.display());
+                    }
                 } else {
                     std::fs::create_dir_all(&cmd.path).map_err(HarnessError::Io)?;
                 }
@@ -198,11 +374,9 @@ fn run() -> Result<i32, HarnessError> {
                 cli::Profile::Agent => "agent",
             };
 
+            let harness_toml = template.unwrap_or_else(|| init_harness_toml(profile).to_string());
             let files = vec![
-                (
-                    cmd.path.join("harness.toml"),
-                    init_harness_toml(profile).to_string(),
-                ),
+                (cmd.path.join("harness.toml"), harness_toml),
                 (cmd.path.join("AGENTS.md"), init_agents_md().to_string()),
                 (
                     cmd.path.join("docs/context/INDEX.md"),
@@ -210,13 +384,27 @@ fn run() -> Result<i32, HarnessError> {
                 ),
             ];
 
-            println!("init plan:");
-            for (path, _) in &files {
-                println!("- {}", path.display());
+            if !json && (cmd.dry_run || !quiet) {
+                println!("init plan:");
+                for (path, _) in &files {
+                    println!("- {}", path.display());
+                }
             }
 
+            let planned = files
+                .iter()
+                .map(|(path, _)| path.display().to_string())
+                .collect::<Vec<_>>();
             if cmd.dry_run {
-                println!("dry-run: no files were written");
+                if !quiet {
+                    println!("dry-run: no files were written");
+                }
+                output.set(&serde_json::json!({
+                    "dry_run": true,
+                    "files": planned,
+                    "written": [],
+                    "skipped": [],
+                }))?;
                 continuity_milestone(
                     &mut continuity_logger,
                     "init",
@@ -230,9 +418,14 @@ fn run() -> Result<i32, HarnessError> {
                 return Ok(exit_code::SUCCESS);
             }
 
+            let mut written = Vec::new();
+            let mut skipped = Vec::new();
             for (path, content) in files {
                 if path.exists() && cmd.no_overwrite {
-                    println!("skip existing: {}", path.display());
+                    if !quiet {
+                        println!("skip existing: {}", path.display());
+                    }
+                    skipped.push(path.display().to_string());
                     continuity_progress(
                         &mut continuity_logger,
                         "init",
@@ -246,6 +439,7 @@ fn run() -> Result<i32, HarnessError> {
                     std::fs::create_dir_all(parent).map_err(HarnessError::Io)?;
                 }
                 std::fs::write(&path, content).map_err(HarnessError::Io)?;
+                written.push(path.display().to_string());
                 continuity_progress(
                     &mut continuity_logger,
                     "init",
@@ -254,7 +448,15 @@ fn run() -> Result<i32, HarnessError> {
                     "running",
                 );
             }
-            println!("init complete");
+            if !quiet {
+                println!("init complete");
+            }
+            output.set(&serde_json::json!({
+                "dry_run": false,
+                "files": planned,
+                "written": written,
+                "skipped": skipped,
+            }))?;
             continuity_milestone(
                 &mut continuity_logger,
                 "init",
@@ -271,17 +473,35 @@ fn run() -> Result<i32, HarnessError> {
             if !cmd.path.join(".git").exists() {
                 return Err(HarnessError::NotGitRepo(cmd.path.display().to_string()));
             }
-            match generator::writer::execute_apply(&cmd) {
-                Ok(()) => {
+            let apply_options = generator::writer::ApplyOptions {
+                max_files,
+                output_dir: cli.output_dir.as_deref(),
+                now: now()?,
+                quiet,
+                no_merge: cli.no_merge,
+                json,
+            };
+            match generator::writer::execute_apply(&cmd, &apply_options) {
+                Ok(outcome) => {
+                    output.set(&outcome)?;
+                    let code = if outcome
+                        .plan_check
+                        .as_ref()
+                        .is_some_and(generator::writer::PlanCheck::is_fully_stale)
+                    {
+                        exit_code::WARNINGS
+                    } else {
+                        exit_code::SUCCESS
+                    };
                     let mut continuity_logger = continuity::ContinuityLogger::new(&cmd.path, None);
                     continuity_milestone(
                         &mut continuity_logger,
                         "apply",
                         "complete",
-                        &[format!("exit_code={}", exit_code::SUCCESS)],
+                        &[format!("exit_code={code}")],
                         "done",
                     );
-                    Ok(exit_code::SUCCESS)
+                    Ok(code)
                 }
                 Err(error) => {
                     let mut continuity_logger = continuity::ContinuityLogger::new(&cmd.path, None);
@@ -300,12 +520,11 @@ fn run() -> Result<i32, HarnessError> {
             if !cmd.path.exists() {
                 return Err(HarnessError::PathNotFound(cmd.path.display().to_string()));
             }
-            if !cmd.path.join(".git").exists() {
-                return Err(HarnessError::NotGitRepo(cmd.path.display().to_string()));
-            }
+            let has_git = require_git_repo(&cmd.path, &cmd.path, cli.no_git)?;
 
-            let loaded = config::load_config(&cmd.path)?;
-            let mut continuity_logger = continuity::ContinuityLogger::new(&cmd.path, loaded.as_ref());
+            let loaded = timings.time("config", || config::load_config(&cmd.path, cli.no_merge))?;
+            let mut continuity_logger =
+                continuity::ContinuityLogger::new(&cmd.path, loaded.as_ref());
             continuity_milestone(
                 &mut continuity_logger,
                 "optimize",
@@ -317,12 +536,34 @@ fn run() -> Result<i32, HarnessError> {
                 .as_ref()
                 .map(types::config::HarnessConfig::optimization_thresholds)
                 .unwrap_or_default();
+            let success_outcomes = loaded
+                .as_ref()
+                .map(types::config::HarnessConfig::success_outcomes)
+                .unwrap_or_else(types::config::default_success_outcomes);
 
-            let trace_dir = cmd
-                .trace_dir
-                .clone()
-                .unwrap_or_else(|| cmd.path.join(".harness/traces"));
-            let trace_data = scan_traces(&trace_dir, thresholds.trace_staleness_days)?;
+            let trace_dir = if cmd.trace_stdin {
+                std::path::PathBuf::from("<stdin>")
+            } else {
+                cmd.trace_dir
+                    .clone()
+                    .unwrap_or_else(|| cmd.path.join(".harness/traces"))
+            };
+            let trace_options = TraceScanOptions {
+                max_age_days: thresholds.trace_staleness_days,
+                dedup: loaded
+                    .as_ref()
+                    .is_some_and(types::config::HarnessConfig::dedup_traces),
+                field_map: loaded
+                    .as_ref()
+                    .map(types::config::HarnessConfig::trace_field_map)
+                    .unwrap_or_default(),
+                threads,
+            };
+            let trace_data = if cmd.trace_stdin {
+                read_traces(std::io::stdin().lock(), &trace_options)?
+            } else {
+                scan_traces(&trace_dir, &trace_options)?
+            };
             continuity_progress(
                 &mut continuity_logger,
                 "optimize",
@@ -331,27 +572,75 @@ fn run() -> Result<i32, HarnessError> {
                     format!("recent={}", trace_data.stats.recent),
                     format!("stale={}", trace_data.stats.stale),
                     format!("malformed={}", trace_data.stats.malformed),
+                    format!("duplicates={}", trace_data.stats.duplicates),
                 ],
                 "running",
             );
-            let optimize_delta = compute_optimize_delta(&trace_data.recent, thresholds);
+            let pinned_revisions = match (&cmd.baseline_rev, &cmd.current_rev) {
+                (Some(baseline), Some(current)) => {
+                    validate_pinned_revisions(
+                        &trace_data.recent,
+                        baseline,
+                        current,
+                        thresholds.trace_staleness_days,
+                    )?;
+                    Some((baseline.as_str(), current.as_str()))
+                }
+                _ => None,
+            };
+            if cmd.verbose_traces {
+                eprintln!(
+                    "{}",
+                    render_trace_diagnostics(&trace_data.recent, &success_outcomes)
+                );
+            }
+            let optimize_delta = compute_optimize_delta(
+                &trace_data.recent,
+                thresholds,
+                pinned_revisions,
+                &success_outcomes,
+            );
 
-            let model = scan::discover(&cmd.path, loaded.as_ref());
-            let report = analyze::analyze(&model, loaded.as_ref());
+            let scan_options = scan::ScanOptions::resolve(loaded.as_ref(), max_files)
+                .with_threads(threads)
+                .with_git_metadata(has_git);
+            let model = timings.time("scan", || {
+                scan::discover(&cmd.path, loaded.as_ref(), &scan_options)
+            })?;
+            let report = timings.time("analyze", || analyze::analyze(&model, loaded.as_ref()));
 
-            let out_dir = cmd.path.join(".harness/optimize");
+            let out_dir = artifacts::artifact_dir(&cmd.path, cli.output_dir.as_deref(), "optimize");
             std::fs::create_dir_all(&out_dir).map_err(HarnessError::Io)?;
-            let stamp = chrono::Utc::now().format("%Y%m%dT%H%M%SZ");
+            let stamp = artifacts::file_stamp(now()?);
             let out_path = out_dir.join(format!("optimize-{stamp}.md"));
-            let content = render_optimize_report(
-                &report,
-                trace_data.stats,
-                thresholds,
-                &trace_dir,
-                &optimize_delta,
-            );
+            let content = timings.time("render", || {
+                if cmd.summary_only {
+                    render_optimize_summary(trace_data.stats, thresholds, &optimize_delta)
+                } else {
+                    render_optimize_report(
+                        &report,
+                        trace_data.stats,
+                        thresholds,
+                        &trace_dir,
+                        &optimize_delta,
+                    )
+                }
+            });
             std::fs::write(&out_path, content).map_err(HarnessError::Io)?;
-            println!("optimize report: {}", out_path.display());
+            if cmd.summary_only && !json {
+                println!(
+                    "{}",
+                    optimize_status_line(trace_data.stats, thresholds, &optimize_delta)
+                );
+            }
+            if !quiet {
+                println!("optimize report: {}", out_path.display());
+            }
+            output.set(&serde_json::json!({
+                "report_path": out_path,
+                "traces": trace_data.stats,
+                "delta": optimize_delta,
+            }))?;
             continuity_milestone(
                 &mut continuity_logger,
                 "optimize",
@@ -368,12 +657,12 @@ fn run() -> Result<i32, HarnessError> {
             if !cmd.path.exists() {
                 return Err(HarnessError::PathNotFound(cmd.path.display().to_string()));
             }
-            if !cmd.path.join(".git").exists() {
-                return Err(HarnessError::NotGitRepo(cmd.path.display().to_string()));
-            }
+            let now = now()?;
+            let has_git = require_git_repo(&cmd.path, &cmd.path, cli.no_git)?;
 
-            let loaded = config::load_config(&cmd.path)?;
-            let mut continuity_logger = continuity::ContinuityLogger::new(&cmd.path, loaded.as_ref());
+            let loaded = timings.time("config", || config::load_config(&cmd.path, cli.no_merge))?;
+            let mut continuity_logger =
+                continuity::ContinuityLogger::new(&cmd.path, loaded.as_ref());
             continuity_milestone(
                 &mut continuity_logger,
                 "bench",
@@ -381,15 +670,59 @@ fn run() -> Result<i32, HarnessError> {
                 &[format!("path={}", cmd.path.display())],
                 "running",
             );
-            let model = scan::discover(&cmd.path, loaded.as_ref());
-            let mut run_results = Vec::new();
-            for run_index in 0..cmd.runs {
-                let report = analyze::analyze(&model, loaded.as_ref());
-                run_results.push(BenchRunResult {
-                    run: run_index + 1,
-                    overall_score: report.overall_score,
-                });
-            }
+            let (run_results, fixture_results, config_hash) = if let Some(fixtures_dir) =
+                &cmd.fixtures
+            {
+                let mut fixture_results = Vec::new();
+                for fixture in list_bench_fixtures(fixtures_dir)? {
+                    let fixture_config = config::load_config(&fixture, cli.no_merge)?;
+                    let config_hash = config::config_hash(&fixture, cli.no_merge)?;
+                    // Fixtures need not be git repositories; those that are get the
+                    // same git-derived signals as single-repository mode.
+                    let scan_options =
+                        scan::ScanOptions::resolve(fixture_config.as_ref(), max_files)
+                            .with_threads(threads)
+                            .with_git_metadata(fixture.join(".git").exists());
+                    let model = scan::discover(&fixture, fixture_config.as_ref(), &scan_options)?;
+                    let runs = run_bench(&model, fixture_config.as_ref(), cmd.runs, cmd.warmup);
+                    let name = fixture.file_name().map_or_else(
+                        || fixture.display().to_string(),
+                        |name| name.to_string_lossy().to_string(),
+                    );
+                    if !json {
+                        println!(
+                            "bench fixture {}: avg={:.3}",
+                            name,
+                            average_overall_score(&runs)
+                        );
+                    }
+                    fixture_results.push(BenchFixtureResult {
+                        name,
+                        config_hash,
+                        runs,
+                    });
+                }
+                let aggregate = aggregate_fixture_runs(&fixture_results);
+                if !json {
+                    println!(
+                        "bench aggregate: fixtures={}, avg={:.3}",
+                        fixture_results.len(),
+                        average_overall_score(&aggregate)
+                    );
+                }
+                let config_hash = fixtures_config_hash(&fixture_results);
+                (aggregate, fixture_results, config_hash)
+            } else {
+                let scan_options = scan::ScanOptions::resolve(loaded.as_ref(), max_files)
+                    .with_threads(threads)
+                    .with_git_metadata(has_git);
+                let model = scan::discover(&cmd.path, loaded.as_ref(), &scan_options)?;
+                (
+                    run_bench(&model, loaded.as_ref(), cmd.runs, cmd.warmup),
+                    Vec::new(),
+                    config::config_hash(&cmd.path, cli.no_merge)?,
+                )
+            };
             continuity_progress(
                 &mut continuity_logger,
                 "bench",
@@ -405,14 +738,18 @@ fn run() -> Result<i32, HarnessError> {
                 repo_dirty: detect_repo_dirty(&cmd.path),
                 harness_version: env!("CARGO_PKG_VERSION").to_string(),
                 suite: cmd.suite.clone().unwrap_or_else(|| "default".to_string()),
-                timestamp: chrono::Utc::now().to_rfc3339(),
+                config_hash,
+                timestamp: now.to_rfc3339(),
             };
 
             let report = BenchReport {
                 bench_context: context,
                 runs: run_results,
+                fixtures: fixture_results,
             };
 
+            let mut exit = exit_code::SUCCESS;
+            let mut bench_comparison = None;
             if let Some(compare_path) = &cmd.compare {
                 let baseline = load_bench_report(compare_path)?;
                 validate_bench_compare_compatibility(
@@ -420,40 +757,207 @@ fn run() -> Result<i32, HarnessError> {
                     &baseline.bench_context,
                     cmd.force_compare,
                 )?;
-                let current_avg = average_overall_score(&report.runs);
-                let baseline_avg = average_overall_score(&baseline.runs);
-                println!(
-                    "bench compare: baseline={:.3}, current={:.3}, delta={:.3}",
-                    baseline_avg,
-                    current_avg,
-                    current_avg - baseline_avg
-                );
+                let comparison = compare_bench_runs(&baseline.runs, &report.runs);
+                if comparison.baseline_runs != comparison.current_runs {
+                    eprintln!(
+                        "warning: bench run count mismatch (baseline={}, current={}); comparing first {} runs",
+                        comparison.baseline_runs,
+                        comparison.current_runs,
+                        comparison.deltas.len()
+                    );
+                }
+                if !json {
+                    println!(
+                        "bench compare: baseline={:.3}, current={:.3}, delta={:.3}",
+                        comparison.baseline_avg,
+                        comparison.current_avg,
+                        comparison.current_avg - comparison.baseline_avg
+                    );
+                    for delta in &comparison.deltas {
+                        println!(
+                            "bench run {}: baseline={:.3}, current={:.3}, delta={:.3}",
+                            delta.run,
+                            delta.baseline_score,
+                            delta.current_score,
+                            delta.current_score - delta.baseline_score
+                        );
+                    }
+                }
+                if let Some(threshold) = cmd.regression_threshold {
+                    if is_bench_regression(&comparison, threshold) {
+                        eprintln!(
+                            "bench regression: delta={:.3} exceeds threshold {:.3}",
+                            comparison.current_avg - comparison.baseline_avg,
+                            threshold
+                        );
+                        exit = exit_code::WARNINGS;
+                    }
+                }
+                bench_comparison = Some(comparison);
             }
 
-            let report_path = write_bench_report(&cmd.path, &report)?;
-            println!("bench report: {}", report_path.display());
+            let report_path =
+                write_bench_report(&cmd.path, cli.output_dir.as_deref(), now, &report)?;
+            if !quiet {
+                println!("bench report: {}", report_path.display());
+            }
+            output.set(&serde_json::json!({
+                "report_path": report_path,
+                "report": report,
+                "comparison": bench_comparison,
+            }))?;
             continuity_milestone(
                 &mut continuity_logger,
                 "bench",
                 "complete",
                 &[
                     format!("report={}", report_path.display()),
-                    format!("exit_code={}", exit_code::SUCCESS),
+                    format!("exit_code={exit}"),
                 ],
                 "done",
             );
+            Ok(exit)
+        }
+        cli::Commands::Completions(cmd) => {
+            if json {
+                let mut script = Vec::new();
+                cli::write_completions(cmd.shell, &mut script);
+                output.set(&serde_json::json!({
+                    "shell": cmd.shell.to_string(),
+                    "script": String::from_utf8_lossy(&script),
+                }))?;
+            } else {
+                cli::write_completions(cmd.shell, &mut std::io::stdout());
+            }
+            Ok(exit_code::SUCCESS)
+        }
+        cli::Commands::ListChecks => {
+            if json {
+                output.set(&analyze::registry::catalog_json())?;
+            } else {
+                print!("{}", analyze::registry::render_catalog());
+            }
+            Ok(exit_code::SUCCESS)
+        }
+        cli::Commands::Budget(cmd) => {
+            if !cmd.path.exists() {
+                return Err(HarnessError::PathNotFound(cmd.path.display().to_string()));
+            }
+            let loaded = config::load_config(&cmd.path, cli.no_merge)?;
+            let budget = build_token_budget(
+                scan::docs::context_file_tokens(&cmd.path),
+                loaded
+                    .as_ref()
+                    .and_then(types::config::HarnessConfig::max_context_tokens),
+            );
+            match cmd.format {
+                _ if json => output.set(&budget)?,
+                cli::BudgetFormat::Text => print!("{}", render_token_budget(&budget)),
+                cli::BudgetFormat::Json => println!(
+                    "{}",
+                    serde_json::to_string_pretty(&budget).map_err(HarnessError::Json)?
+                ),
+            }
+            if budget.over_budget {
+                Ok(exit_code::WARNINGS)
+            } else {
+                Ok(exit_code::SUCCESS)
+            }
+        }
+        cli::Commands::CompareReports(cmd) => {
+            let baseline = read_json_report(&cmd.baseline)?;
+            let current = read_json_report(&cmd.current)?;
+            if json {
+                output.set(&serde_json::json!({
+                    "baseline_score": baseline.overall_score,
+                    "current_score": current.overall_score,
+                    "changes": report::compare::baseline_changes(&baseline, &current),
+                }))?;
+            } else {
+                print!("{}", report::compare::to_markdown_diff(&baseline, &current));
+            }
             Ok(exit_code::SUCCESS)
         }
-        cli::Commands::Lint(cmd) => {
+        cli::Commands::Man(cmd) => {
+            match &cmd.out {
+                Some(path) => {
+                    let mut content = Vec::new();
+                    cli::render_man_page(&mut content).map_err(HarnessError::Io)?;
+                    std::fs::write(path, content).map_err(HarnessError::Io)?;
+                    if !quiet {
+                        println!("man page: {}", path.display());
+                    }
+                    output.set(&serde_json::json!({ "path": path }))?;
+                }
+                None if json => {
+                    let mut content = Vec::new();
+                    cli::render_man_page(&mut content).map_err(HarnessError::Io)?;
+                    output.set(&serde_json::json!({
+                        "content": String::from_utf8_lossy(&content),
+                    }))?;
+                }
+                None => cli::render_man_page(&mut std::io::stdout()).map_err(HarnessError::Io)?,
+            }
+            Ok(exit_code::SUCCESS)
+        }
+        cli::Commands::Scrub(cmd) => {
             if !cmd.path.exists() {
                 return Err(HarnessError::PathNotFound(cmd.path.display().to_string()));
             }
-            if !cmd.path.join(".git").exists() {
-                return Err(HarnessError::NotGitRepo(cmd.path.display().to_string()));
+            let loaded = config::load_config(&cmd.path, cli.no_merge)?;
+            let log_path = continuity::progress_file(&cmd.path, loaded.as_ref());
+            let Some(content) = scan::filesystem::read_to_string_if_exists(&log_path) else {
+                if !quiet {
+                    println!("scrub: no progress log at {}", log_path.display());
+                }
+                output.set(&serde_json::json!({
+                    "path": log_path,
+                    "redacted": 0,
+                    "backup": null,
+                }))?;
+                return Ok(exit_code::SUCCESS);
+            };
+            let (scrubbed, redacted) = scan::secrets::redact_secrets(&content);
+            let backup = if redacted > 0 {
+                let mut backup = log_path.clone().into_os_string();
+                backup.push(".bak");
+                let backup = std::path::PathBuf::from(backup);
+                std::fs::copy(&log_path, &backup).map_err(HarnessError::Io)?;
+                std::fs::write(&log_path, scrubbed).map_err(HarnessError::Io)?;
+                eprintln!(
+                    "warning: backup {} still contains the original secrets; delete it once the scrubbed log is verified",
+                    backup.display()
+                );
+                Some(backup)
+            } else {
+                None
+            };
+            if !quiet {
+                match &backup {
+                    Some(backup) => println!(
+                        "scrub: redacted {redacted} secret(s) in {} (backup: {})",
+                        log_path.display(),
+                        backup.display()
+                    ),
+                    None => println!("scrub: no secrets found in {}", log_path.display()),
+                }
             }
+            output.set(&serde_json::json!({
+                "path": log_path,
+                "redacted": redacted,
+                "backup": backup,
+            }))?;
+            Ok(exit_code::SUCCESS)
+        }
+        cli::Commands::Lint(cmd) => {
+            if !cmd.path.exists() {
+                return Err(HarnessError::PathNotFound(cmd.path.display().to_string()));
+            }
+            let has_git = require_git_repo(&cmd.path, &cmd.path, cli.no_git)?;
 
-            let loaded = config::load_config(&cmd.path)?;
-            let mut continuity_logger = continuity::ContinuityLogger::new(&cmd.path, loaded.as_ref());
+            let loaded = timings.time("config", || config::load_config(&cmd.path, cli.no_merge))?;
+            let mut continuity_logger =
+                continuity::ContinuityLogger::new(&cmd.path, loaded.as_ref());
             continuity_milestone(
                 &mut continuity_logger,
                 "lint",
@@ -461,11 +965,32 @@ fn run() -> Result<i32, HarnessError> {
                 &[format!("path={}", cmd.path.display())],
                 "running",
             );
-            let model = scan::discover(&cmd.path, loaded.as_ref());
-            let findings = analyze::lint::lint_findings(&model, loaded.as_ref());
+            let scan_options = scan::ScanOptions::resolve(loaded.as_ref(), max_files)
+                .with_threads(threads)
+                .with_git_metadata(has_git);
+            let model = timings.time("scan", || {
+                scan::discover(&cmd.path, loaded.as_ref(), &scan_options)
+            })?;
+            let mut findings = timings.time("analyze", || {
+                analyze::lint::lint_findings(&model, loaded.as_ref(), cmd.fail_fast)
+            });
+            if cmd.errors_only {
+                findings.retain(|finding| finding.blocking);
+            }
+            if cmd.warnings_as_errors {
+                for finding in &mut findings {
+                    finding.blocking = true;
+                }
+                if cmd.fail_fast {
+                    truncate_after_first_blocking(&mut findings);
+                }
+            }
 
+            output.set(&serde_json::json!({ "findings": findings }))?;
             if findings.is_empty() {
-                println!("lint: no findings");
+                if !quiet {
+                    println!("lint: no findings");
+                }
                 continuity_milestone(
                     &mut continuity_logger,
                     "lint",
@@ -476,10 +1001,12 @@ fn run() -> Result<i32, HarnessError> {
                 return Ok(exit_code::SUCCESS);
             }
 
-            for finding in &findings {
-                let level = if finding.blocking { "BLOCKING" } else { "WARN" };
-                println!("[{}] {}: {}", level, finding.id, finding.title);
-                println!("  {}", finding.body);
+            if !json {
+                for finding in &findings {
+                    let level = if finding.blocking { "BLOCKING" } else { "WARN" };
+                    println!("[{}] {}: {}", level, finding.id, finding.title);
+                    println!("  {}", finding.body);
+                }
             }
 
             let exit = if findings.iter().any(|finding| finding.blocking) {
@@ -506,6 +1033,155 @@ fn run() -> Result<i32, HarnessError> {
     }
 }
 
+#[derive(Debug, Serialize)]
+struct TokenBudgetEntry {
+    path: String,
+    tokens: usize,
+    running_total: usize,
+}
+
+#[derive(Debug, Serialize)]
+struct TokenBudget {
+    files: Vec<TokenBudgetEntry>,
+    total_tokens: usize,
+    budget: Option<usize>,
+    over_budget: bool,
+}
+
+fn build_token_budget(files: Vec<(&str, usize)>, budget: Option<usize>) -> TokenBudget {
+    let mut running_total = 0;
+    let files = files
+        .into_iter()
+        .map(|(path, tokens)| {
+            running_total += tokens;
+            TokenBudgetEntry {
+                path: path.to_string(),
+                tokens,
+                running_total,
+            }
+        })
+        .collect::<Vec<_>>();
+    TokenBudget {
+        files,
+        total_tokens: running_total,
+        budget,
+        over_budget: budget.is_some_and(|budget| running_total > budget),
+    }
+}
+
+fn render_token_budget(budget: &TokenBudget) -> String {
+    let mut lines = budget
+        .files
+        .iter()
+        .map(|entry| {
+            format!(
+                "{}: {} tokens (running total {})",
+                entry.path, entry.tokens, entry.running_total
+            )
+        })
+        .collect::<Vec<_>>();
+    if lines.is_empty() {
+        lines.push("no agent context files found".to_string());
+    }
+    match budget.budget {
+        Some(limit) => {
+            lines.push(format!("total: {} / {} tokens", budget.total_tokens, limit));
+            if budget.over_budget {
+                lines.push(format!(
+                    "over budget by {} tokens",
+                    budget.total_tokens - limit
+                ));
+            }
+        }
+        None => lines.push(format!(
+            "total: {} tokens (no [context] max_context_tokens budget configured)",
+            budget.total_tokens
+        )),
+    }
+    lines.push(String::new());
+    lines.join("\n")
+}
+
+struct PhaseTimings {
+    enabled: bool,
+    phases: Vec<(&'static str, std::time::Duration)>,
+}
+
+impl PhaseTimings {
+    fn new(enabled: bool) -> Self {
+        Self {
+            enabled,
+            phases: Vec::new(),
+        }
+    }
+
+    fn time<T>(&mut self, phase: &'static str, run: impl FnOnce() -> T) -> T {
+        if !self.enabled {
+            return run();
+        }
+        let start = std::time::Instant::now();
+        let value = run();
+        self.phases.push((phase, start.elapsed()));
+        value
+    }
+
+    fn render(&self) -> String {
+        let mut lines = self
+            .phases
+            .iter()
+            .map(|(phase, elapsed)| format!("timing: {phase} {:.3}ms", millis(*elapsed)))
+            .collect::<Vec<_>>();
+        let total = self.phases.iter().map(|(_, elapsed)| *elapsed).sum();
+        lines.push(format!("timing: total {:.3}ms", millis(total)));
+        lines.join("\n")
+    }
+
+    fn report(&self) {
+        if self.enabled {
+            eprintln!("{}", self.render());
+        }
+    }
+}
+
+fn millis(duration: std::time::Duration) -> f64 {
+    duration.as_secs_f64() * 1000.0
+}
+
+fn truncate_after_first_blocking(findings: &mut Vec<types::report::Finding>) {
+    if let Some(index) = findings.iter().position(|finding| finding.blocking) {
+        findings.truncate(index + 1);
+    }
+}
+
+fn read_json_report(path: &std::path::Path) -> Result<types::report::HarnessReport, HarnessError> {
+    if !path.exists() {
+        return Err(HarnessError::PathNotFound(path.display().to_string()));
+    }
+    let content = std::fs::read_to_string(path).map_err(HarnessError::Io)?;
+    report::json::from_json(&content).map_err(|source| HarnessError::ReportParse {
+        path: path.display().to_string(),
+        source,
+    })
+}
+
+fn require_git_repo(
+    root: &std::path::Path,
+    display_path: &std::path::Path,
+    no_git: bool,
+) -> Result<bool, HarnessError> {
+    if root.join(".git").exists() {
+        return Ok(true);
+    }
+    if !no_git {
+        return Err(HarnessError::NotGitRepo(display_path.display().to_string()));
+    }
+    eprintln!(
+        "warning: {} is not a git repository; git-derived signals (doc age) are unavailable",
+        display_path.display()
+    );
+    Ok(false)
+}
+
 fn continuity_milestone(
     logger: &mut continuity::ContinuityLogger,
     feature: &str,
@@ -604,19 +1280,94 @@ struct BenchContext {
     repo_dirty: bool,
     harness_version: String,
     suite: String,
+    #[serde(default)]
+    config_hash: String,
     timestamp: String,
 }
 
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 struct BenchRunResult {
     run: u32,
     overall_score: f32,
 }
 
+#[derive(Debug, Serialize, Deserialize)]
+struct BenchFixtureResult {
+    name: String,
+    #[serde(default)]
+    config_hash: String,
+    runs: Vec<BenchRunResult>,
+}
+
 #[derive(Debug, Serialize, Deserialize)]
 struct BenchReport {
     bench_context: BenchContext,
     runs: Vec<BenchRunResult>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    fixtures: Vec<BenchFixtureResult>,
+}
+
+fn run_bench(
+    model: &scan::RepoModel,
+    config: Option<&types::config::HarnessConfig>,
+    runs: u32,
+    warmup: u32,
+) -> Vec<BenchRunResult> {
+    for _ in 0..warmup {
+        std::hint::black_box(analyze::analyze(model, config));
+    }
+    (0..runs)
+        .map(|run_index| BenchRunResult {
+            run: run_index + 1,
+            overall_score: analyze::analyze(model, config).overall_score,
+        })
+        .collect()
+}
+
+fn list_bench_fixtures(dir: &std::path::Path) -> Result<Vec<std::path::PathBuf>, HarnessError> {
+    if !dir.is_dir() {
+        return Err(HarnessError::PathNotFound(dir.display().to_string()));
+    }
+    let mut fixtures = std::fs::read_dir(dir)
+        .map_err(HarnessError::Io)?
+        .filter_map(|entry| entry.ok())
+        .map(|entry| entry.path())
+        .filter(|path| path.is_dir())
+        .collect::<Vec<_>>();
+    fixtures.sort();
+    if fixtures.is_empty() {
+        return Err(HarnessError::NoBenchFixtures(dir.display().to_string()));
+    }
+    Ok(fixtures)
+}
+
+fn fixtures_config_hash(fixtures: &[BenchFixtureResult]) -> String {
+    let combined: String = fixtures
+        .iter()
+        .map(|fixture| format!("{}={}\n", fixture.name, fixture.config_hash))
+        .collect();
+    util::hash::sha256_hex(combined.as_bytes())
+}
+
+fn aggregate_fixture_runs(fixtures: &[BenchFixtureResult]) -> Vec<BenchRunResult> {
+    let run_count = fixtures
+        .iter()
+        .map(|fixture| fixture.runs.len())
+        .max()
+        .unwrap_or(0);
+    (0..run_count)
+        .map(|index| {
+            let scores = fixtures
+                .iter()
+                .filter_map(|fixture| fixture.runs.get(index))
+                .cloned()
+                .collect::<Vec<_>>();
+            BenchRunResult {
+                run: index as u32 + 1,
+                overall_score: average_overall_score(&scores),
+            }
+        })
+        .collect()
 }
 
 fn detect_toolchain() -> String {
@@ -659,11 +1410,13 @@ fn detect_repo_dirty(root: &std::path::Path) -> bool {
 
 fn write_bench_report(
     root: &std::path::Path,
+    output_dir: Option<&std::path::Path>,
+    now: chrono::DateTime<chrono::Utc>,
     report: &BenchReport,
 ) -> Result<std::path::PathBuf, HarnessError> {
-    let dir = root.join(".harness/bench");
+    let dir = artifacts::artifact_dir(root, output_dir, "bench");
     std::fs::create_dir_all(&dir).map_err(HarnessError::Io)?;
-    let stamp = chrono::Utc::now().format("%Y%m%dT%H%M%SZ");
+    let stamp = artifacts::file_stamp(now);
     let out = dir.join(format!("bench-{stamp}.json"));
     let payload = serde_json::to_string_pretty(report)?;
     std::fs::write(&out, payload).map_err(HarnessError::Io)?;
@@ -683,6 +1436,51 @@ fn average_overall_score(runs: &[BenchRunResult]) -> f32 {
     sum / runs.len() as f32
 }
 
+#[derive(Debug, Serialize)]
+struct BenchRunDelta {
+    run: u32,
+    baseline_score: f32,
+    current_score: f32,
+}
+
+#[derive(Debug, Serialize)]
+struct BenchComparison {
+    baseline_runs: usize,
+    current_runs: usize,
+    baseline_avg: f32,
+    current_avg: f32,
+    deltas: Vec<BenchRunDelta>,
+}
+
+fn compare_bench_runs(baseline: &[BenchRunResult], current: &[BenchRunResult]) -> BenchComparison {
+    let mut baseline = baseline.to_vec();
+    let mut current = current.to_vec();
+    baseline.sort_by_key(|run| run.run);
+    current.sort_by_key(|run| run.run);
+    let overlap = baseline.len().min(current.len());
+    let deltas = baseline
+        .iter()
+        .zip(current.iter())
+        .enumerate()
+        .map(|(index, (baseline_run, current_run))| BenchRunDelta {
+            run: index as u32 + 1,
+            baseline_score: baseline_run.overall_score,
+            current_score: current_run.overall_score,
+        })
+        .collect();
+    BenchComparison {
+        baseline_runs: baseline.len(),
+        current_runs: current.len(),
+        baseline_avg: average_overall_score(&baseline[..overlap]),
+        current_avg: average_overall_score(&current[..overlap]),
+        deltas,
+    }
+}
+
+fn is_bench_regression(comparison: &BenchComparison, threshold: f32) -> bool {
+    comparison.current_avg - comparison.baseline_avg < -threshold
+}
+
 fn validate_bench_compare_compatibility(
     current: &BenchContext,
     baseline: &BenchContext,
@@ -690,7 +1488,10 @@ fn validate_bench_compare_compatibility(
 ) -> Result<(), HarnessError> {
     let mut mismatches = Vec::new();
     if current.os != baseline.os {
-        mismatches.push(format!("os (baseline={}, current={})", baseline.os, current.os));
+        mismatches.push(format!(
+            "os (baseline={}, current={})",
+            baseline.os, current.os
+        ));
     }
     if current.toolchain != baseline.toolchain {
         mismatches.push(format!(
@@ -704,6 +1505,12 @@ fn validate_bench_compare_compatibility(
             baseline.repo_dirty, current.repo_dirty
         ));
     }
+    if !baseline.config_hash.is_empty() && current.config_hash != baseline.config_hash {
+        mismatches.push(format!(
+            "config_hash (baseline={}, current={})",
+            baseline.config_hash, current.config_hash
+        ));
+    }
 
     if !mismatches.is_empty() && !force_compare {
         return Err(HarnessError::ConfigParse(format!(
@@ -727,11 +1534,12 @@ struct TraceRecord {
     wall_ms: Option<u64>,
 }
 
-#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Serialize)]
 struct TraceScanStats {
     recent: usize,
     stale: usize,
     malformed: usize,
+    duplicates: usize,
 }
 
 #[derive(Debug, Clone)]
@@ -750,7 +1558,8 @@ struct TraceData {
     recent: Vec<RecentTraceRecord>,
 }
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
 enum OptimizeDeltaStatus {
     Improvement,
     Regression,
@@ -758,7 +1567,7 @@ enum OptimizeDeltaStatus {
     InsufficientData,
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize)]
 struct OptimizeDelta {
     status: OptimizeDeltaStatus,
     baseline_revision: Option<String>,
@@ -768,6 +1577,22 @@ struct OptimizeDelta {
     step_delta_rel: f32,
     task_overlap: f32,
     reason: Option<String>,
+    low_sample_warning: bool,
+    p_value: Option<f32>,
+    regressed_tasks: Vec<TaskCompletionDelta>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize)]
+struct TaskCompletionDelta {
+    task_id: String,
+    baseline_rate: f32,
+    current_rate: f32,
+}
+
+impl TaskCompletionDelta {
+    fn delta(&self) -> f32 {
+        self.current_rate - self.baseline_rate
+    }
 }
 
 #[derive(Debug, Default)]
@@ -779,24 +1604,46 @@ struct RevisionAccumulator {
     tokens_sum: f64,
     tokens_count: usize,
     tasks: BTreeSet<String>,
+    task_outcomes: BTreeMap<String, TaskOutcomes>,
+    earliest_ts: Option<chrono::DateTime<chrono::Utc>>,
     latest_ts: Option<chrono::DateTime<chrono::Utc>>,
 }
 
+#[derive(Debug, Default, Clone, Copy)]
+struct TaskOutcomes {
+    success: usize,
+    total: usize,
+}
+
+impl TaskOutcomes {
+    fn completion_rate(self) -> f32 {
+        if self.total == 0 {
+            0.0
+        } else {
+            self.success as f32 / self.total as f32
+        }
+    }
+}
+
 #[derive(Debug)]
 struct RevisionMetrics {
     revision: String,
     total: usize,
+    success: usize,
     completion_rate: f32,
     avg_steps: f32,
     avg_tokens: f32,
     tasks: BTreeSet<String>,
+    task_outcomes: BTreeMap<String, TaskOutcomes>,
+    earliest_ts: chrono::DateTime<chrono::Utc>,
     latest_ts: chrono::DateTime<chrono::Utc>,
 }
 
 impl RevisionAccumulator {
-    fn add(&mut self, trace: &RecentTraceRecord) {
+    fn add(&mut self, trace: &RecentTraceRecord, success_outcomes: &[String]) {
+        let succeeded = success_outcomes.contains(&trace.outcome);
         self.total += 1;
-        if trace.outcome == "success" {
+        if succeeded {
             self.success += 1;
         }
         if let Some(steps) = trace.steps {
@@ -808,6 +1655,15 @@ impl RevisionAccumulator {
             self.tokens_count += 1;
         }
         self.tasks.insert(trace.task_id.clone());
+        let task_outcomes = self.task_outcomes.entry(trace.task_id.clone()).or_default();
+        task_outcomes.total += 1;
+        if succeeded {
+            task_outcomes.success += 1;
+        }
+        self.earliest_ts = Some(
+            self.earliest_ts
+                .map_or(trace.timestamp, |current| current.min(trace.timestamp)),
+        );
         self.latest_ts = Some(self.latest_ts.map_or(trace.timestamp, |current| {
             if trace.timestamp > current {
                 trace.timestamp
@@ -819,6 +1675,7 @@ impl RevisionAccumulator {
 
     fn into_metrics(self, revision: String) -> Option<RevisionMetrics> {
         let latest_ts = self.latest_ts?;
+        let earliest_ts = self.earliest_ts.unwrap_or(latest_ts);
         let completion_rate = if self.total == 0 {
             0.0
         } else {
@@ -837,29 +1694,163 @@ impl RevisionAccumulator {
         Some(RevisionMetrics {
             revision,
             total: self.total,
+            success: self.success,
             completion_rate,
             avg_steps,
             avg_tokens,
             tasks: self.tasks,
+            task_outcomes: self.task_outcomes,
+            earliest_ts,
             latest_ts,
         })
     }
 }
 
-fn scan_traces(trace_dir: &std::path::Path, max_age_days: u32) -> Result<TraceData, HarnessError> {
-    if !trace_dir.exists() {
-        return Ok(TraceData {
-            stats: TraceScanStats::default(),
-            recent: Vec::new(),
-        });
-    }
+#[derive(Debug, Clone, Default)]
+struct TraceScanOptions {
+    max_age_days: u32,
+    dedup: bool,
+    field_map: BTreeMap<String, String>,
+    threads: usize,
+}
 
-    let now = chrono::Utc::now();
-    let max_age = i64::from(max_age_days);
-    let mut stats = TraceScanStats::default();
-    let mut recent = Vec::new();
+#[derive(Debug, Clone)]
+struct PreparedTraceLine {
+    dedup_key: Option<String>,
+    outcome: TraceLineOutcome,
+}
 
-    for entry_result in std::fs::read_dir(trace_dir).map_err(HarnessError::Io)? {
+fn prepare_trace_line(
+    line: &str,
+    options: &TraceScanOptions,
+    now: chrono::DateTime<chrono::Utc>,
+) -> Option<PreparedTraceLine> {
+    let line = line.trim();
+    if line.is_empty() {
+        return None;
+    }
+    let normalized: String;
+    let mut dedup_key = None;
+    let line = if options.dedup || !options.field_map.is_empty() {
+        let Ok(value) = serde_json::from_str::<serde_json::Value>(line) else {
+            return Some(PreparedTraceLine {
+                dedup_key: None,
+                outcome: TraceLineOutcome::Malformed,
+            });
+        };
+        normalized = remap_trace_fields(value, &options.field_map).to_string();
+        dedup_key = options
+            .dedup
+            .then(|| util::hash::sha256_hex(normalized.as_bytes()));
+        normalized.as_str()
+    } else {
+        line
+    };
+
+    let outcome = classify_trace_line(line, now, options.max_age_days);
+    if matches!(outcome, TraceLineOutcome::Malformed) {
+        dedup_key = None;
+    }
+    Some(PreparedTraceLine { dedup_key, outcome })
+}
+
+#[derive(Debug, Clone)]
+enum TraceLineOutcome {
+    Recent(Option<RecentTraceRecord>),
+    Stale,
+    Malformed,
+}
+
+fn classify_trace_line(
+    line: &str,
+    now: chrono::DateTime<chrono::Utc>,
+    max_age_days: u32,
+) -> TraceLineOutcome {
+    let Ok(record) = serde_json::from_str::<TraceRecord>(line) else {
+        return TraceLineOutcome::Malformed;
+    };
+    let Ok(timestamp) = chrono::DateTime::parse_from_rfc3339(&record.timestamp) else {
+        return TraceLineOutcome::Malformed;
+    };
+    let timestamp = timestamp.with_timezone(&chrono::Utc);
+    if now.signed_duration_since(timestamp).num_days() > i64::from(max_age_days) {
+        return TraceLineOutcome::Stale;
+    }
+    let recent = match (record.task_id, record.revision, record.outcome) {
+        (Some(task_id), Some(revision), Some(outcome)) => Some(RecentTraceRecord {
+            timestamp,
+            task_id,
+            revision,
+            outcome,
+            steps: record.steps,
+            token_est: record.token_est,
+        }),
+        _ => None,
+    };
+    TraceLineOutcome::Recent(recent)
+}
+
+struct TraceCollector<'a> {
+    options: &'a TraceScanOptions,
+    now: chrono::DateTime<chrono::Utc>,
+    stats: TraceScanStats,
+    recent: Vec<RecentTraceRecord>,
+    seen: std::collections::HashSet<String>,
+}
+
+impl<'a> TraceCollector<'a> {
+    fn new(options: &'a TraceScanOptions) -> Self {
+        Self {
+            options,
+            now: chrono::Utc::now(),
+            stats: TraceScanStats::default(),
+            recent: Vec::new(),
+            seen: std::collections::HashSet::new(),
+        }
+    }
+
+    fn ingest_line(&mut self, line: &str) {
+        if let Some(prepared) = prepare_trace_line(line, self.options, self.now) {
+            self.record(prepared);
+        }
+    }
+
+    fn record(&mut self, prepared: PreparedTraceLine) {
+        if let Some(key) = prepared.dedup_key {
+            if !self.seen.insert(key) {
+                self.stats.duplicates += 1;
+                return;
+            }
+        }
+        match prepared.outcome {
+            TraceLineOutcome::Recent(record) => {
+                self.stats.recent += 1;
+                self.recent.extend(record);
+            }
+            TraceLineOutcome::Stale => self.stats.stale += 1,
+            TraceLineOutcome::Malformed => self.stats.malformed += 1,
+        }
+    }
+
+    fn finish(self) -> TraceData {
+        TraceData {
+            stats: self.stats,
+            recent: self.recent,
+        }
+    }
+}
+
+fn scan_traces(
+    trace_dir: &std::path::Path,
+    options: &TraceScanOptions,
+) -> Result<TraceData, HarnessError> {
+    let mut collector = TraceCollector::new(options);
+    if !trace_dir.exists() {
+        return Ok(collector.finish());
+    }
+
+    let mut trace_files = Vec::new();
+    for entry_result in std::fs::read_dir(trace_dir).map_err(HarnessError::Io)? {
         let entry = entry_result.map_err(HarnessError::Io)?;
         let path = entry.path();
         if !path.is_file() {
@@ -867,47 +1858,54 @@ fn scan_traces(trace_dir: &std::path::Path, max_age_days: u32) -> Result<TraceDa
         }
 
         let extension = path.extension().and_then(|value| value.to_str());
-        if !matches!(extension, Some("jsonl" | "json")) {
-            continue;
+        if matches!(extension, Some("jsonl" | "json")) {
+            trace_files.push(path);
         }
+    }
 
-        let content = std::fs::read_to_string(&path).map_err(HarnessError::Io)?;
-        for line in content.lines().map(str::trim).filter(|line| !line.is_empty()) {
-            let record = match serde_json::from_str::<TraceRecord>(line) {
-                Ok(record) => record,
-                Err(_) => {
-                    stats.malformed += 1;
-                    continue;
-                }
-            };
-            let timestamp = match chrono::DateTime::parse_from_rfc3339(&record.timestamp) {
-                Ok(value) => value.with_timezone(&chrono::Utc),
-                Err(_) => {
-                    stats.malformed += 1;
-                    continue;
-                }
-            };
-            let age_days = now.signed_duration_since(timestamp).num_days();
-            if age_days <= max_age {
-                stats.recent += 1;
-                if let (Some(task_id), Some(revision), Some(outcome)) =
-                    (record.task_id, record.revision, record.outcome)
-                {
-                    recent.push(RecentTraceRecord {
-                        timestamp,
-                        task_id,
-                        revision,
-                        outcome,
-                        steps: record.steps,
-                        token_est: record.token_est,
-                    });
+    let now = collector.now;
+    let prepared_files = util::parallel::map_ordered(&trace_files, options.threads, |path| {
+        std::fs::read_to_string(path).map(|content| {
+            content
+                .lines()
+                .filter_map(|line| prepare_trace_line(line, options, now))
+                .collect::<Vec<_>>()
+        })
+    });
+    for prepared_lines in prepared_files {
+        for prepared in prepared_lines.map_err(HarnessError::Io)? {
+            collector.record(prepared);
+        }
+    }
+    Ok(collector.finish())
+}
+
+fn read_traces(
+    reader: impl std::io::BufRead,
+    options: &TraceScanOptions,
+) -> Result<TraceData, HarnessError> {
+    let mut collector = TraceCollector::new(options);
+    for line in reader.lines() {
+        collector.ingest_line(&line.map_err(HarnessError::Io)?);
+    }
+    Ok(collector.finish())
+}
+
+fn remap_trace_fields(
+    value: serde_json::Value,
+    field_map: &BTreeMap<String, String>,
+) -> serde_json::Value {
+    match value {
+        serde_json::Value::Object(mut object) if !field_map.is_empty() => {
+            for (from, to) in field_map {
+                if let Some(field) = object.remove(from) {
+                    object.insert(to.clone(), field);
                 }
-            } else {
-                stats.stale += 1;
             }
+            serde_json::Value::Object(object)
         }
+        other => other,
     }
-    Ok(TraceData { stats, recent })
 }
 
 #[cfg_attr(not(test), allow(dead_code))]
@@ -915,7 +1913,11 @@ fn count_recent_traces(
     trace_dir: &std::path::Path,
     max_age_days: u32,
 ) -> Result<TraceScanStats, HarnessError> {
-    scan_traces(trace_dir, max_age_days).map(|data| data.stats)
+    let options = TraceScanOptions {
+        max_age_days,
+        ..TraceScanOptions::default()
+    };
+    scan_traces(trace_dir, &options).map(|data| data.stats)
 }
 
 fn compute_task_overlap(a: &BTreeSet<String>, b: &BTreeSet<String>) -> f32 {
@@ -939,22 +1941,79 @@ fn relative_delta(baseline: f32, current: f32) -> f32 {
     }
 }
 
-fn compute_optimize_delta(
+fn recommended_traces_per_revision(thresholds: types::config::OptimizationThresholds) -> usize {
+    thresholds.min_traces as usize * RECOMMENDED_TRACE_MULTIPLIER
+}
+
+fn validate_pinned_revisions(
     traces: &[RecentTraceRecord],
-    thresholds: types::config::OptimizationThresholds,
-) -> OptimizeDelta {
+    baseline: &str,
+    current: &str,
+    max_age_days: u32,
+) -> Result<(), HarnessError> {
+    if baseline == current {
+        return Err(HarnessError::ConfigParse(format!(
+            "--baseline-rev and --current-rev must differ (both are `{baseline}`)"
+        )));
+    }
+    for revision in [baseline, current] {
+        if !traces.iter().any(|trace| trace.revision == revision) {
+            return Err(HarnessError::RevisionNotFound(format!(
+                "`{revision}` has no traces in the last {max_age_days} days"
+            )));
+        }
+    }
+    Ok(())
+}
+
+fn revision_metrics(
+    traces: &[RecentTraceRecord],
+    success_outcomes: &[String],
+) -> Vec<RevisionMetrics> {
     let mut per_revision: BTreeMap<String, RevisionAccumulator> = BTreeMap::new();
     for trace in traces {
         per_revision
             .entry(trace.revision.clone())
             .or_default()
-            .add(trace);
+            .add(trace, success_outcomes);
     }
 
     let mut revisions = per_revision
         .into_iter()
         .filter_map(|(revision, accumulator)| accumulator.into_metrics(revision))
         .collect::<Vec<_>>();
+    revisions.sort_by_key(|revision| revision.latest_ts);
+    revisions
+}
+
+fn render_trace_diagnostics(traces: &[RecentTraceRecord], success_outcomes: &[String]) -> String {
+    let revisions = revision_metrics(traces, success_outcomes);
+    let mut lines = vec![format!(
+        "traces: {} recent trace(s) across {} revision(s)",
+        traces.len(),
+        revisions.len()
+    )];
+    lines.extend(revisions.iter().map(|revision| {
+        format!(
+            "traces: {} total={} success={} tasks={} first={} latest={}",
+            revision.revision,
+            revision.total,
+            revision.success,
+            revision.tasks.len(),
+            revision.earliest_ts.to_rfc3339(),
+            revision.latest_ts.to_rfc3339()
+        )
+    }));
+    lines.join("\n")
+}
+
+fn compute_optimize_delta(
+    traces: &[RecentTraceRecord],
+    thresholds: types::config::OptimizationThresholds,
+    pinned_revisions: Option<(&str, &str)>,
+    success_outcomes: &[String],
+) -> OptimizeDelta {
+    let revisions = revision_metrics(traces, success_outcomes);
 
     if revisions.len() < 2 {
         return OptimizeDelta {
@@ -966,14 +2025,24 @@ fn compute_optimize_delta(
             step_delta_rel: 0.0,
             task_overlap: 0.0,
             reason: Some("need traces from at least two revisions".to_string()),
+            low_sample_warning: false,
+            p_value: None,
+            regressed_tasks: Vec::new(),
         };
     }
 
-    revisions.sort_by(|a, b| a.latest_ts.cmp(&b.latest_ts));
-    let baseline = &revisions[revisions.len() - 2];
-    let current = &revisions[revisions.len() - 1];
+    let pinned = pinned_revisions.and_then(|(baseline, current)| {
+        let find = |name: &str| revisions.iter().find(|revision| revision.revision == name);
+        Some((find(baseline)?, find(current)?))
+    });
+    let (baseline, current) = pinned.unwrap_or((
+        &revisions[revisions.len() - 2],
+        &revisions[revisions.len() - 1],
+    ));
 
-    if baseline.total < thresholds.min_traces as usize || current.total < thresholds.min_traces as usize {
+    if baseline.total < thresholds.min_traces as usize
+        || current.total < thresholds.min_traces as usize
+    {
         return OptimizeDelta {
             status: OptimizeDeltaStatus::InsufficientData,
             baseline_revision: Some(baseline.revision.clone()),
@@ -986,6 +2055,9 @@ fn compute_optimize_delta(
                 "need at least {} traces per revision (baseline={}, current={})",
                 thresholds.min_traces, baseline.total, current.total
             )),
+            low_sample_warning: false,
+            p_value: None,
+            regressed_tasks: Vec::new(),
         };
     }
 
@@ -1003,6 +2075,9 @@ fn compute_optimize_delta(
                 "task overlap {:.2} is below threshold {:.2}",
                 overlap, thresholds.task_overlap_threshold
             )),
+            low_sample_warning: false,
+            p_value: None,
+            regressed_tasks: Vec::new(),
         };
     }
 
@@ -1033,7 +2108,7 @@ fn compute_optimize_delta(
     };
     let total_signal = completion_signal + token_signal + step_signal;
 
-    let (status, reason) = if total_signal > 0 {
+    let (mut status, mut reason) = if total_signal > 0 {
         (OptimizeDeltaStatus::Improvement, None)
     } else if total_signal < 0 {
         (OptimizeDeltaStatus::Regression, None)
@@ -1044,6 +2119,30 @@ fn compute_optimize_delta(
         )
     };
 
+    let p_value = thresholds.significance_alpha.map(|_| {
+        two_proportion_p_value(
+            baseline.success,
+            baseline.total,
+            current.success,
+            current.total,
+        )
+    });
+    if let (Some(alpha), Some(p_value)) = (thresholds.significance_alpha, p_value) {
+        if p_value >= alpha && status != OptimizeDeltaStatus::Neutral {
+            status = OptimizeDeltaStatus::Neutral;
+            reason = Some(format!(
+                "completion difference is not significant (p={p_value:.4}, alpha={alpha:.4})"
+            ));
+        }
+    }
+
+    let regressed_tasks = if status == OptimizeDeltaStatus::Regression {
+        regressed_tasks(baseline, current)
+    } else {
+        Vec::new()
+    };
+
+    let recommended_traces = recommended_traces_per_revision(thresholds);
     OptimizeDelta {
         status,
         baseline_revision: Some(baseline.revision.clone()),
@@ -1053,9 +2152,125 @@ fn compute_optimize_delta(
         step_delta_rel,
         task_overlap: overlap,
         reason,
+        low_sample_warning: baseline.total < recommended_traces
+            || current.total < recommended_traces,
+        p_value,
+        regressed_tasks,
     }
 }
 
+fn regressed_tasks(
+    baseline: &RevisionMetrics,
+    current: &RevisionMetrics,
+) -> Vec<TaskCompletionDelta> {
+    let mut deltas = baseline
+        .task_outcomes
+        .iter()
+        .filter_map(|(task_id, baseline_outcomes)| {
+            let current_outcomes = current.task_outcomes.get(task_id)?;
+            Some(TaskCompletionDelta {
+                task_id: task_id.clone(),
+                baseline_rate: baseline_outcomes.completion_rate(),
+                current_rate: current_outcomes.completion_rate(),
+            })
+        })
+        .filter(|delta| delta.delta() < 0.0)
+        .collect::<Vec<_>>();
+    deltas.sort_by(|left, right| {
+        left.delta()
+            .total_cmp(&right.delta())
+            .then_with(|| left.task_id.cmp(&right.task_id))
+    });
+    deltas.truncate(REGRESSED_TASKS_LIMIT);
+    deltas
+}
+
+fn two_proportion_p_value(
+    baseline_success: usize,
+    baseline_total: usize,
+    current_success: usize,
+    current_total: usize,
+) -> f32 {
+    if baseline_total == 0 || current_total == 0 {
+        return 1.0;
+    }
+    let n1 = baseline_total as f64;
+    let n2 = current_total as f64;
+    let pooled = (baseline_success + current_success) as f64 / (n1 + n2);
+    let standard_error = (pooled * (1.0 - pooled) * (1.0 / n1 + 1.0 / n2)).sqrt();
+    if standard_error == 0.0 {
+        return 1.0;
+    }
+    let z = (current_success as f64 / n2 - baseline_success as f64 / n1) / standard_error;
+    erfc(z.abs() / std::f64::consts::SQRT_2) as f32
+}
+
+fn erfc(x: f64) -> f64 {
+    // Numerical Recipes Chebyshev approximation; fractional error below 1.2e-7.
+    let t = 1.0 / (1.0 + 0.5 * x.abs());
+    let poly = -x * x - 1.265_512_23
+        + t * (1.000_023_68
+            + t * (0.374_091_96
+                + t * (0.096_784_18
+                    + t * (-0.186_288_06
+                        + t * (0.278_868_07
+                            + t * (-1.135_203_98
+                                + t * (1.488_515_87 + t * (-0.822_152_23 + t * 0.170_872_77))))))));
+    let value = t * poly.exp();
+    if x >= 0.0 {
+        value
+    } else {
+        2.0 - value
+    }
+}
+
+fn optimize_status_line(
+    trace_scan: TraceScanStats,
+    thresholds: types::config::OptimizationThresholds,
+    delta: &OptimizeDelta,
+) -> &'static str {
+    if trace_scan.recent < thresholds.min_traces as usize {
+        return "Status: insufficient data for optimization recommendations.";
+    }
+    match delta.status {
+        OptimizeDeltaStatus::Improvement => "Status: improvement detected.",
+        OptimizeDeltaStatus::Regression => "Status: regression warning.",
+        OptimizeDeltaStatus::Neutral => "Status: stable; changes are below uplift thresholds.",
+        OptimizeDeltaStatus::InsufficientData => {
+            "Status: insufficient comparative data for optimize deltas."
+        }
+    }
+}
+
+fn insufficient_traces_reason(thresholds: types::config::OptimizationThresholds) -> String {
+    format!(
+        "Need at least {} recent traces before computing optimize deltas.",
+        thresholds.min_traces
+    )
+}
+
+fn render_optimize_summary(
+    trace_scan: TraceScanStats,
+    thresholds: types::config::OptimizationThresholds,
+    delta: &OptimizeDelta,
+) -> String {
+    let mut lines = vec![
+        "# Harness Optimize Summary".to_string(),
+        String::new(),
+        optimize_status_line(trace_scan, thresholds, delta).to_string(),
+    ];
+    if trace_scan.recent < thresholds.min_traces as usize {
+        lines.push(format!(
+            "Reason: {}",
+            insufficient_traces_reason(thresholds)
+        ));
+    } else if let Some(reason) = &delta.reason {
+        lines.push(format!("Reason: {}", reason));
+    }
+    lines.push(String::new());
+    lines.join("\n")
+}
+
 fn render_optimize_report(
     report: &types::report::HarnessReport,
     trace_scan: TraceScanStats,
@@ -1088,15 +2303,16 @@ fn render_optimize_report(
             trace_scan.malformed
         ));
     }
-
-    if trace_scan.recent < thresholds.min_traces as usize {
-        lines.push(
-            "Status: insufficient data for optimization recommendations.".to_string(),
-        );
+    if trace_scan.duplicates > 0 {
         lines.push(format!(
-            "Need at least {} recent traces before computing optimize deltas.",
-            thresholds.min_traces
+            "Collapsed duplicate trace records: {}",
+            trace_scan.duplicates
         ));
+    }
+
+    if trace_scan.recent < thresholds.min_traces as usize {
+        lines.push(optimize_status_line(trace_scan, thresholds, delta).to_string());
+        lines.push(insufficient_traces_reason(thresholds));
         lines.push(String::new());
         return lines.join("\n");
     }
@@ -1113,29 +2329,39 @@ fn render_optimize_report(
         "- completion delta: {:+.3}, token delta (rel): {:+.3}, step delta (rel): {:+.3}",
         delta.completion_delta, delta.token_delta_rel, delta.step_delta_rel
     ));
-    match delta.status {
-        OptimizeDeltaStatus::Improvement => {
-            lines.push("Status: improvement detected.".to_string());
-        }
-        OptimizeDeltaStatus::Regression => {
-            lines.push("Status: regression warning.".to_string());
-        }
-        OptimizeDeltaStatus::Neutral => {
-            lines.push("Status: stable; changes are below uplift thresholds.".to_string());
-        }
-        OptimizeDeltaStatus::InsufficientData => {
-            lines.push("Status: insufficient comparative data for optimize deltas.".to_string());
-        }
-    }
+    lines.push(optimize_status_line(trace_scan, thresholds, delta).to_string());
     if let Some(reason) = &delta.reason {
         lines.push(format!("Reason: {}", reason));
     }
+    if let Some(p_value) = delta.p_value {
+        lines.push(format!("- completion p-value: {:.4}", p_value));
+    }
+    if delta.low_sample_warning {
+        lines.push(format!(
+            "Caution: fewer than {} traces in a compared revision; treat this delta as low confidence.",
+            recommended_traces_per_revision(thresholds)
+        ));
+    }
     lines.push(String::new());
 
     if matches!(delta.status, OptimizeDeltaStatus::InsufficientData) {
         return lines.join("\n");
     }
 
+    if !delta.regressed_tasks.is_empty() {
+        lines.push("## Regressed Tasks".to_string());
+        for task in &delta.regressed_tasks {
+            lines.push(format!(
+                "- `{}`: completion {:.2} -> {:.2} ({:+.2})",
+                task.task_id,
+                task.baseline_rate,
+                task.current_rate,
+                task.delta()
+            ));
+        }
+        lines.push(String::new());
+    }
+
     lines.push("## Top Recommendations".to_string());
 
     if ordered_report.recommendations.is_empty() {
@@ -1157,6 +2383,90 @@ fn render_optimize_report(
     lines.join("\n")
 }
 
+struct JsonOutput {
+    enabled: bool,
+    data: Option<serde_json::Value>,
+}
+
+impl JsonOutput {
+    fn new(enabled: bool) -> Self {
+        Self {
+            enabled,
+            data: None,
+        }
+    }
+
+    fn set(&mut self, data: &impl Serialize) -> Result<(), HarnessError> {
+        if self.enabled {
+            self.data = Some(serde_json::to_value(data).map_err(HarnessError::Json)?);
+        }
+        Ok(())
+    }
+}
+
+#[derive(Debug, Serialize)]
+struct JsonEnvelope {
+    command: &'static str,
+    status: &'static str,
+    data: serde_json::Value,
+    exit_code: i32,
+}
+
+fn json_envelope(
+    command: &'static str,
+    result: &Result<i32, HarnessError>,
+    data: Option<serde_json::Value>,
+) -> JsonEnvelope {
+    match result {
+        Ok(code) => JsonEnvelope {
+            command,
+            status: match *code {
+                exit_code::SUCCESS => "success",
+                exit_code::WARNINGS => "warnings",
+                exit_code::BLOCKING => "blocking",
+                _ => "error",
+            },
+            data: data.unwrap_or(serde_json::Value::Null),
+            exit_code: *code,
+        },
+        Err(error) => JsonEnvelope {
+            command,
+            status: "error",
+            data: serde_json::json!({ "error": error.to_string(), "code": error.code() }),
+            exit_code: error_exit_code(error),
+        },
+    }
+}
+
+/// Exit code for a runtime error; every variant is mapped explicitly so new
+/// variants have to pick one.
+fn error_exit_code(error: &HarnessError) -> i32 {
+    match error {
+        HarnessError::NotGitRepo(_)
+        | HarnessError::GitUnavailable(_)
+        | HarnessError::GitCloneFailed(_)
+        | HarnessError::ConfigNotFound(_)
+        | HarnessError::ConfigParse(_)
+        | HarnessError::PathNotFound(_)
+        | HarnessError::ScanLimitExceeded(_)
+        | HarnessError::InvalidProfileTarget(_)
+        | HarnessError::BucketPenaltyExceeded(_)
+        | HarnessError::ForbiddenToolAccess(_)
+        | HarnessError::Io(_)
+        | HarnessError::Toml(_)
+        | HarnessError::Json(_) => exit_code::RUNTIME_FAILURE,
+        HarnessError::ApplyCapExceeded { .. } => exit_code::RUNTIME_FAILURE,
+        HarnessError::InvalidPlannedChange { .. } => exit_code::RUNTIME_FAILURE,
+        HarnessError::PlanChecksumMismatch { .. } => exit_code::RUNTIME_FAILURE,
+        HarnessError::UnknownRecommendation(_) => exit_code::RUNTIME_FAILURE,
+        HarnessError::RevisionNotFound(_) => exit_code::RUNTIME_FAILURE,
+        HarnessError::NoBenchFixtures(_) => exit_code::RUNTIME_FAILURE,
+        HarnessError::ReportParse { .. } => exit_code::RUNTIME_FAILURE,
+        HarnessError::TemplateFetchFailed { .. } => exit_code::RUNTIME_FAILURE,
+        HarnessError::Usage(_) => exit_code::RUNTIME_FAILURE,
+    }
+}
+
 fn main() {
     match run() {
         Ok(code) => {
@@ -1166,7 +2476,7 @@ fn main() {
         }
         Err(e) => {
             eprintln!("error: {}", e);
-            std::process::exit(exit_code::RUNTIME_FAILURE);
+            std::process::exit(error_exit_code(&e));
         }
     }
 }
@@ -1174,7 +2484,7 @@ fn main() {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::types::report::{Effort, HarnessReport, Impact, Recommendation, Risk};
+    use crate::types::report::{Effort, Finding, HarnessReport, Impact, Recommendation, Risk};
     use crate::types::scoring::ScoreCard;
 
     fn make_bench_context(os: &str, toolchain: &str, repo_dirty: bool) -> BenchContext {
@@ -1185,6 +2495,7 @@ mod tests {
             repo_dirty,
             harness_version: "0.1.0".to_string(),
             suite: "default".to_string(),
+            config_hash: "cfg".to_string(),
             timestamp: "2026-02-27T00:00:00Z".to_string(),
         }
     }
@@ -1203,6 +2514,9 @@ mod tests {
             step_delta_rel: 0.0,
             task_overlap: 1.0,
             reason: Some("changes are below configured uplift thresholds".to_string()),
+            low_sample_warning: false,
+            p_value: None,
+            regressed_tasks: Vec::new(),
         }
     }
 
@@ -1228,7 +2542,6 @@ mod tests {
         let report = HarnessReport {
             overall_score: 0.5,
             category_scores: ScoreCard::new(0.5, 0.5, 0.5, 0.5, 0.5),
-            findings: vec![],
             recommendations: vec![
                 Recommendation::new(
                     "low",
@@ -1249,6 +2562,7 @@ mod tests {
                     0.9,
                 ),
             ],
+            ..HarnessReport::fixture()
         };
 
         let rendered = render_optimize_report(
@@ -1257,6 +2571,7 @@ mod tests {
                 recent: 30,
                 stale: 0,
                 malformed: 0,
+                duplicates: 0,
             },
             default_thresholds(),
             std::path::Path::new(".harness/traces"),
@@ -1279,7 +2594,6 @@ mod tests {
         let report = HarnessReport {
             overall_score: 0.5,
             category_scores: ScoreCard::new(0.5, 0.5, 0.5, 0.5, 0.5),
-            findings: vec![],
             recommendations: vec![Recommendation::new(
                 "high",
                 "High",
@@ -1289,6 +2603,7 @@ mod tests {
                 Risk::Safe,
                 0.9,
             )],
+            ..HarnessReport::fixture()
         };
 
         let rendered = render_optimize_report(
@@ -1297,6 +2612,7 @@ mod tests {
                 recent: 2,
                 stale: 0,
                 malformed: 0,
+                duplicates: 0,
             },
             default_thresholds(),
             std::path::Path::new(".harness/traces"),
@@ -1306,13 +2622,98 @@ mod tests {
         assert!(!rendered.contains("## Top Recommendations"));
     }
 
+    #[test]
+    fn build_token_budget_accumulates_running_totals_against_budget() {
+        let budget = build_token_budget(
+            vec![("AGENTS.md", 300), ("ARCHITECTURE.md", 250)],
+            Some(500),
+        );
+        assert_eq!(budget.files[0].running_total, 300);
+        assert_eq!(budget.files[1].running_total, 550);
+        assert_eq!(budget.total_tokens, 550);
+        assert!(budget.over_budget);
+
+        let rendered = render_token_budget(&budget);
+        assert!(rendered.contains("ARCHITECTURE.md: 250 tokens (running total 550)"));
+        assert!(rendered.contains("total: 550 / 500 tokens"));
+        assert!(rendered.contains("over budget by 50 tokens"));
+
+        let unbounded = build_token_budget(vec![("AGENTS.md", 300)], None);
+        assert!(!unbounded.over_budget);
+        assert!(render_token_budget(&unbounded).contains("no [context] max_context_tokens"));
+    }
+
+    #[test]
+    fn phase_timings_record_only_when_enabled() {
+        let mut disabled = PhaseTimings::new(false);
+        assert_eq!(disabled.time("scan", || 7), 7);
+        assert!(disabled.phases.is_empty());
+
+        let mut enabled = PhaseTimings::new(true);
+        enabled.time("config", || ());
+        enabled.time("scan", || ());
+        let rendered = enabled.render();
+        let phases = rendered
+            .lines()
+            .map(|line| line.split_whitespace().nth(1).unwrap_or_default())
+            .collect::<Vec<_>>();
+        assert_eq!(phases, vec!["config", "scan", "total"]);
+        assert!(rendered.lines().all(|line| line.ends_with("ms")));
+    }
+
+    #[test]
+    fn truncate_after_first_blocking_keeps_findings_through_first_blocker() {
+        let finding = |id: &str, blocking: bool| Finding {
+            id: id.to_string(),
+            title: id.to_string(),
+            body: id.to_string(),
+            blocking,
+            file: None,
+        };
+        let mut findings = vec![
+            finding("warn.a", false),
+            finding("block.b", true),
+            finding("warn.c", false),
+            finding("block.d", true),
+        ];
+        truncate_after_first_blocking(&mut findings);
+        let ids = findings
+            .iter()
+            .map(|finding| finding.id.as_str())
+            .collect::<Vec<_>>();
+        assert_eq!(ids, vec!["warn.a", "block.b"]);
+
+        let mut warnings = vec![finding("warn.a", false), finding("warn.c", false)];
+        truncate_after_first_blocking(&mut warnings);
+        assert_eq!(warnings.len(), 2);
+    }
+
+    #[test]
+    fn render_optimize_summary_contains_only_status_and_reason() {
+        let stats = TraceScanStats {
+            recent: default_thresholds().min_traces as usize,
+            stale: 0,
+            malformed: 0,
+            duplicates: 0,
+        };
+        let rendered = render_optimize_summary(stats, default_thresholds(), &neutral_delta());
+        assert!(rendered.contains("Status: stable; changes are below uplift thresholds."));
+        assert!(rendered.contains("Reason: changes are below configured uplift thresholds"));
+        assert!(!rendered.contains("## Optimization Delta"));
+        assert!(!rendered.contains("## Top Recommendations"));
+
+        let sparse = TraceScanStats { recent: 2, ..stats };
+        let rendered = render_optimize_summary(sparse, default_thresholds(), &neutral_delta());
+        assert!(rendered.contains("Status: insufficient data for optimization recommendations."));
+        assert!(rendered.contains("Reason: Need at least"));
+    }
+
     #[test]
     fn render_optimize_report_surfaces_malformed_trace_warning() {
         let report = HarnessReport {
             overall_score: 0.5,
             category_scores: ScoreCard::new(0.5, 0.5, 0.5, 0.5, 0.5),
-            findings: vec![],
-            recommendations: vec![],
+            ..HarnessReport::fixture()
         };
 
         let rendered = render_optimize_report(
@@ -1321,6 +2722,7 @@ mod tests {
                 recent: 30,
                 stale: 1,
                 malformed: 2,
+                duplicates: 0,
             },
             default_thresholds(),
             std::path::Path::new(".harness/traces"),
@@ -1330,6 +2732,69 @@ mod tests {
         assert!(rendered.contains("ignored malformed trace records: 2"));
     }
 
+    #[test]
+    fn classify_trace_line_returns_recent_record_when_fields_are_complete() {
+        let now = chrono::Utc::now();
+        let line = format!(
+            "{{\"timestamp\":\"{}\",\"task_id\":\"t1\",\"revision\":\"r1\",\"outcome\":\"success\",\"steps\":4}}",
+            now.to_rfc3339()
+        );
+        match classify_trace_line(&line, now, 90) {
+            TraceLineOutcome::Recent(Some(record)) => {
+                assert_eq!(record.task_id, "t1");
+                assert_eq!(record.revision, "r1");
+                assert_eq!(record.steps, Some(4));
+            }
+            other => panic!("expected recent record, got {other:?}"),
+        }
+    }
+
+    #[test]
+    fn classify_trace_line_counts_recent_without_record_when_fields_are_missing() {
+        let now = chrono::Utc::now();
+        let line = format!("{{\"timestamp\":\"{}\"}}", now.to_rfc3339());
+        assert!(matches!(
+            classify_trace_line(&line, now, 90),
+            TraceLineOutcome::Recent(None)
+        ));
+    }
+
+    #[test]
+    fn classify_trace_line_marks_old_records_stale() {
+        let now = chrono::Utc::now();
+        let line = format!(
+            "{{\"timestamp\":\"{}\"}}",
+            (now - chrono::Duration::days(91)).to_rfc3339()
+        );
+        assert!(matches!(
+            classify_trace_line(&line, now, 90),
+            TraceLineOutcome::Stale
+        ));
+        assert!(matches!(
+            classify_trace_line(&line, now, 91),
+            TraceLineOutcome::Recent(None)
+        ));
+    }
+
+    #[test]
+    fn classify_trace_line_marks_unparseable_input_malformed() {
+        let now = chrono::Utc::now();
+        for line in [
+            "not-json",
+            "{\"task_id\":\"t1\"}",
+            "{\"timestamp\":\"invalid\"}",
+            "[]",
+        ] {
+            assert!(
+                matches!(
+                    classify_trace_line(line, now, 90),
+                    TraceLineOutcome::Malformed
+                ),
+                "line should be malformed: {line}"
+            );
+        }
+    }
+
     #[test]
     fn count_recent_traces_reports_recent_stale_and_malformed_records() {
         let dir = tempfile::TempDir::new().expect("temp dir should be created");
@@ -1348,10 +2813,123 @@ mod tests {
                 recent: 1,
                 stale: 1,
                 malformed: 2,
+                duplicates: 0,
             }
         );
     }
 
+    #[test]
+    fn scan_traces_collapses_exact_duplicates_only_when_enabled() {
+        let dir = tempfile::TempDir::new().expect("temp dir should be created");
+        let now = chrono::Utc::now().to_rfc3339();
+        let record = format!(
+            "{{\"timestamp\":\"{now}\",\"task_id\":\"t1\",\"revision\":\"r1\",\"outcome\":\"success\"}}"
+        );
+        let content = format!("{record}\n{record}\n{record}\n");
+        std::fs::write(dir.path().join("traces.jsonl"), content).expect("trace file should write");
+
+        let mut options = TraceScanOptions {
+            max_age_days: 90,
+            ..TraceScanOptions::default()
+        };
+        let kept = scan_traces(dir.path(), &options).expect("trace scan should succeed");
+        assert_eq!(kept.stats.recent, 3);
+        assert_eq!(kept.stats.duplicates, 0);
+
+        options.dedup = true;
+        let deduped = scan_traces(dir.path(), &options).expect("trace scan should succeed");
+        assert_eq!(deduped.stats.recent, 1);
+        assert_eq!(deduped.stats.duplicates, 2);
+        assert_eq!(deduped.recent.len(), 1);
+    }
+
+    #[test]
+    fn scan_traces_results_do_not_depend_on_thread_count() {
+        let dir = tempfile::TempDir::new().expect("temp dir should be created");
+        let now = chrono::Utc::now().to_rfc3339();
+        let record = |task: usize| {
+            format!(
+                "{{\"timestamp\":\"{now}\",\"task_id\":\"t{task}\",\"revision\":\"r1\",\"outcome\":\"success\"}}"
+            )
+        };
+        for file in 0..6 {
+            let content = format!("{}\n{}\nnot json\n", record(file), record(0));
+            std::fs::write(dir.path().join(format!("traces-{file}.jsonl")), content)
+                .expect("trace file should write");
+        }
+
+        let scan = |threads: usize| {
+            let options = TraceScanOptions {
+                max_age_days: 90,
+                dedup: true,
+                threads,
+                ..TraceScanOptions::default()
+            };
+            scan_traces(dir.path(), &options).expect("trace scan should succeed")
+        };
+        let sequential = scan(1);
+        let parallel = scan(4);
+        assert_eq!(sequential.stats.recent, 6);
+        assert_eq!(sequential.stats.duplicates, 6);
+        assert_eq!(sequential.stats.malformed, 6);
+        assert_eq!(parallel.stats.recent, sequential.stats.recent);
+        assert_eq!(parallel.stats.duplicates, sequential.stats.duplicates);
+        assert_eq!(parallel.stats.malformed, sequential.stats.malformed);
+        assert_eq!(parallel.recent.len(), sequential.recent.len());
+    }
+
+    #[test]
+    fn read_traces_matches_directory_scan_including_malformed_lines() {
+        let dir = tempfile::TempDir::new().expect("temp dir should be created");
+        let now = chrono::Utc::now().to_rfc3339();
+        let content = format!(
+            "{{\"timestamp\":\"{now}\",\"task_id\":\"t1\",\"revision\":\"r1\",\"outcome\":\"success\"}}\n\
+             not json\n\
+             \n\
+             {{\"timestamp\":\"not-a-date\"}}\n\
+             {{\"timestamp\":\"2000-01-01T00:00:00Z\"}}\n"
+        );
+        std::fs::write(dir.path().join("traces.jsonl"), &content).expect("trace file should write");
+        let options = TraceScanOptions {
+            max_age_days: 90,
+            ..TraceScanOptions::default()
+        };
+
+        let scanned = scan_traces(dir.path(), &options).expect("trace scan should succeed");
+        let streamed =
+            read_traces(content.as_bytes(), &options).expect("trace stream should be readable");
+        assert_eq!(streamed.stats, scanned.stats);
+        assert_eq!(streamed.stats.recent, 1);
+        assert_eq!(streamed.stats.malformed, 2);
+        assert_eq!(streamed.stats.stale, 1);
+        assert_eq!(streamed.recent.len(), scanned.recent.len());
+    }
+
+    #[test]
+    fn scan_traces_applies_field_map_before_deserializing() {
+        let dir = tempfile::TempDir::new().expect("temp dir should be created");
+        let now = chrono::Utc::now().to_rfc3339();
+        let content = format!(
+            "{{\"timestamp\":\"{now}\",\"task_id\":\"t1\",\"rev\":\"r1\",\"outcome\":\"success\",\"tokens\":120}}\n{{\"timestamp\":\"{now}\",\"tokens\":\"many\"}}\n"
+        );
+        std::fs::write(dir.path().join("traces.jsonl"), content).expect("trace file should write");
+
+        let options = TraceScanOptions {
+            max_age_days: 90,
+            dedup: false,
+            field_map: BTreeMap::from([
+                ("rev".to_string(), "revision".to_string()),
+                ("tokens".to_string(), "token_est".to_string()),
+            ]),
+            ..TraceScanOptions::default()
+        };
+        let data = scan_traces(dir.path(), &options).expect("trace scan should succeed");
+        assert_eq!(data.stats.recent, 1);
+        assert_eq!(data.stats.malformed, 1);
+        assert_eq!(data.recent[0].revision, "r1");
+        assert_eq!(data.recent[0].token_est, Some(120));
+    }
+
     #[test]
     fn compute_optimize_delta_detects_improvement() {
         let thresholds = types::config::OptimizationThresholds {
@@ -1360,6 +2938,7 @@ mod tests {
             min_uplift_rel: 0.10,
             trace_staleness_days: 90,
             task_overlap_threshold: 0.50,
+            significance_alpha: None,
         };
         let traces = vec![
             make_recent_trace("rev-a", "task-1", "failure", 20, 200),
@@ -1367,13 +2946,200 @@ mod tests {
             make_recent_trace("rev-b", "task-1", "success", 10, 100),
             make_recent_trace("rev-b", "task-2", "success", 10, 100),
         ];
-        let delta = compute_optimize_delta(&traces, thresholds);
+        let delta = compute_optimize_delta(
+            &traces,
+            thresholds,
+            None,
+            &types::config::default_success_outcomes(),
+        );
         assert_eq!(delta.status, OptimizeDeltaStatus::Improvement);
         assert!(delta.completion_delta > 0.0);
         assert!(delta.token_delta_rel < 0.0);
         assert!(delta.step_delta_rel < 0.0);
     }
 
+    #[test]
+    fn trace_diagnostics_list_per_revision_counts_and_timestamps() {
+        let at = |rfc3339: &str| {
+            chrono::DateTime::parse_from_rfc3339(rfc3339)
+                .expect("timestamp should parse")
+                .with_timezone(&chrono::Utc)
+        };
+        let traces = vec![
+            RecentTraceRecord {
+                timestamp: at("2026-03-02T00:00:00Z"),
+                ..make_recent_trace("rev-b", "task-1", "success", 10, 100)
+            },
+            RecentTraceRecord {
+                timestamp: at("2026-01-05T00:00:00Z"),
+                ..make_recent_trace("rev-a", "task-1", "failure", 10, 100)
+            },
+            RecentTraceRecord {
+                timestamp: at("2026-01-01T00:00:00Z"),
+                ..make_recent_trace("rev-a", "task-2", "success", 10, 100)
+            },
+        ];
+
+        let rendered =
+            render_trace_diagnostics(&traces, &types::config::default_success_outcomes());
+        assert_eq!(
+            rendered,
+            "traces: 3 recent trace(s) across 2 revision(s)\n\
+             traces: rev-a total=2 success=1 tasks=2 \
+             first=2026-01-01T00:00:00+00:00 latest=2026-01-05T00:00:00+00:00\n\
+             traces: rev-b total=1 success=1 tasks=1 \
+             first=2026-03-02T00:00:00+00:00 latest=2026-03-02T00:00:00+00:00"
+        );
+    }
+
+    #[test]
+    fn compute_optimize_delta_uses_configured_success_outcomes() {
+        let thresholds = types::config::OptimizationThresholds {
+            min_traces: 1,
+            ..types::config::OptimizationThresholds::default()
+        };
+        let traces = vec![
+            make_recent_trace("rev-a", "task-1", "failed", 10, 100),
+            make_recent_trace("rev-a", "task-2", "failed", 10, 100),
+            make_recent_trace("rev-b", "task-1", "passed", 10, 100),
+            make_recent_trace("rev-b", "task-2", "done", 10, 100),
+        ];
+
+        let default = compute_optimize_delta(
+            &traces,
+            thresholds,
+            None,
+            &types::config::default_success_outcomes(),
+        );
+        assert_eq!(default.completion_delta, 0.0);
+
+        let vocabulary = vec!["passed".to_string(), "done".to_string()];
+        let custom = compute_optimize_delta(&traces, thresholds, None, &vocabulary);
+        assert_eq!(custom.completion_delta, 1.0);
+        assert_eq!(custom.status, OptimizeDeltaStatus::Improvement);
+    }
+
+    fn make_revision_traces(revision: &str, count: usize, outcome: &str) -> Vec<RecentTraceRecord> {
+        (0..count)
+            .map(|index| {
+                let task_id = format!("task-{}", index % 2);
+                make_recent_trace(revision, &task_id, outcome, 10, 100)
+            })
+            .collect()
+    }
+
+    #[test]
+    fn compute_optimize_delta_flags_low_sample_below_recommended_multiple() {
+        let thresholds = types::config::OptimizationThresholds {
+            min_traces: 2,
+            ..types::config::OptimizationThresholds::default()
+        };
+        let mut traces = make_revision_traces("rev-a", 6, "failure");
+        traces.extend(make_revision_traces("rev-b", 5, "success"));
+        let delta = compute_optimize_delta(
+            &traces,
+            thresholds,
+            None,
+            &types::config::default_success_outcomes(),
+        );
+        assert_eq!(delta.status, OptimizeDeltaStatus::Improvement);
+        assert!(delta.low_sample_warning);
+
+        let rendered = render_optimize_report(
+            &HarnessReport {
+                overall_score: 0.5,
+                category_scores: ScoreCard::new(0.5, 0.5, 0.5, 0.5, 0.5),
+                ..HarnessReport::fixture()
+            },
+            TraceScanStats {
+                recent: 11,
+                ..TraceScanStats::default()
+            },
+            thresholds,
+            std::path::Path::new(".harness/traces"),
+            &delta,
+        );
+        assert!(rendered.contains("Caution: fewer than 6 traces"));
+    }
+
+    #[test]
+    fn compute_optimize_delta_skips_low_sample_warning_at_recommended_multiple() {
+        let thresholds = types::config::OptimizationThresholds {
+            min_traces: 2,
+            ..types::config::OptimizationThresholds::default()
+        };
+        let mut traces = make_revision_traces("rev-a", 6, "failure");
+        traces.extend(make_revision_traces("rev-b", 6, "success"));
+        let delta = compute_optimize_delta(
+            &traces,
+            thresholds,
+            None,
+            &types::config::default_success_outcomes(),
+        );
+        assert_eq!(delta.status, OptimizeDeltaStatus::Improvement);
+        assert!(!delta.low_sample_warning);
+    }
+
+    #[test]
+    fn two_proportion_p_value_matches_reference_values() {
+        let p_value = two_proportion_p_value(50, 100, 65, 100);
+        assert!((p_value - 0.0316).abs() < 0.001, "p-value was {p_value}");
+        assert!((two_proportion_p_value(5, 10, 5, 10) - 1.0).abs() < 0.001);
+        assert!((two_proportion_p_value(10, 10, 10, 10) - 1.0).abs() < f32::EPSILON);
+    }
+
+    #[test]
+    fn compute_optimize_delta_requires_significance_when_alpha_set() {
+        let thresholds = types::config::OptimizationThresholds {
+            min_traces: 1,
+            significance_alpha: Some(0.05),
+            ..types::config::OptimizationThresholds::default()
+        };
+        let mut traces = make_revision_traces("rev-a", 1, "failure");
+        traces.extend(make_revision_traces("rev-b", 1, "success"));
+        let thin = compute_optimize_delta(
+            &traces,
+            thresholds,
+            None,
+            &types::config::default_success_outcomes(),
+        );
+        assert_eq!(thin.status, OptimizeDeltaStatus::Neutral);
+        assert!(thin.p_value.is_some_and(|p_value| p_value > 0.05));
+        assert!(thin
+            .reason
+            .as_deref()
+            .is_some_and(|reason| reason.contains("not significant")));
+
+        let mut traces = make_revision_traces("rev-a", 40, "failure");
+        traces.extend(make_revision_traces("rev-b", 40, "success"));
+        let strong = compute_optimize_delta(
+            &traces,
+            thresholds,
+            None,
+            &types::config::default_success_outcomes(),
+        );
+        assert_eq!(strong.status, OptimizeDeltaStatus::Improvement);
+        assert!(strong.p_value.is_some_and(|p_value| p_value < 0.05));
+    }
+
+    #[test]
+    fn compute_optimize_delta_omits_p_value_when_alpha_disabled() {
+        let thresholds = types::config::OptimizationThresholds {
+            min_traces: 1,
+            ..types::config::OptimizationThresholds::default()
+        };
+        let mut traces = make_revision_traces("rev-a", 2, "failure");
+        traces.extend(make_revision_traces("rev-b", 2, "success"));
+        let delta = compute_optimize_delta(
+            &traces,
+            thresholds,
+            None,
+            &types::config::default_success_outcomes(),
+        );
+        assert_eq!(delta.status, OptimizeDeltaStatus::Improvement);
+        assert!(delta.p_value.is_none());
+    }
+
     #[test]
     fn compute_optimize_delta_detects_regression() {
         let thresholds = types::config::OptimizationThresholds {
@@ -1382,6 +3148,7 @@ mod tests {
             min_uplift_rel: 0.10,
             trace_staleness_days: 90,
             task_overlap_threshold: 0.50,
+            significance_alpha: None,
         };
         let traces = vec![
             make_recent_trace("rev-a", "task-1", "success", 10, 100),
@@ -1389,13 +3156,109 @@ mod tests {
             make_recent_trace("rev-b", "task-1", "failure", 20, 220),
             make_recent_trace("rev-b", "task-2", "success", 20, 220),
         ];
-        let delta = compute_optimize_delta(&traces, thresholds);
+        let delta = compute_optimize_delta(
+            &traces,
+            thresholds,
+            None,
+            &types::config::default_success_outcomes(),
+        );
         assert_eq!(delta.status, OptimizeDeltaStatus::Regression);
         assert!(delta.completion_delta < 0.0);
         assert!(delta.token_delta_rel > 0.0);
         assert!(delta.step_delta_rel > 0.0);
     }
 
+    #[test]
+    fn compute_optimize_delta_surfaces_worst_regressed_tasks() {
+        let thresholds = types::config::OptimizationThresholds {
+            min_traces: 1,
+            ..types::config::OptimizationThresholds::default()
+        };
+        let traces = vec![
+            make_recent_trace("rev-a", "task-1", "success", 10, 100),
+            make_recent_trace("rev-a", "task-1", "success", 10, 100),
+            make_recent_trace("rev-a", "task-2", "success", 10, 100),
+            make_recent_trace("rev-a", "task-2", "success", 10, 100),
+            make_recent_trace("rev-a", "task-3", "failure", 10, 100),
+            make_recent_trace("rev-b", "task-1", "success", 10, 100),
+            make_recent_trace("rev-b", "task-1", "failure", 10, 100),
+            make_recent_trace("rev-b", "task-2", "failure", 10, 100),
+            make_recent_trace("rev-b", "task-2", "failure", 10, 100),
+            make_recent_trace("rev-b", "task-3", "success", 10, 100),
+        ];
+        let delta = compute_optimize_delta(
+            &traces,
+            thresholds,
+            None,
+            &types::config::default_success_outcomes(),
+        );
+        assert_eq!(delta.status, OptimizeDeltaStatus::Regression);
+        let ids = delta
+            .regressed_tasks
+            .iter()
+            .map(|task| task.task_id.as_str())
+            .collect::<Vec<_>>();
+        assert_eq!(ids, vec!["task-2", "task-1"]);
+
+        let rendered = render_optimize_report(
+            &HarnessReport {
+                overall_score: 0.5,
+                category_scores: ScoreCard::new(0.5, 0.5, 0.5, 0.5, 0.5),
+                ..HarnessReport::fixture()
+            },
+            TraceScanStats {
+                recent: 10,
+                ..TraceScanStats::default()
+            },
+            thresholds,
+            std::path::Path::new(".harness/traces"),
+            &delta,
+        );
+        assert!(rendered.contains("## Regressed Tasks"));
+        assert!(rendered.contains("- `task-2`: completion 1.00 -> 0.00 (-1.00)"));
+    }
+
+    #[test]
+    fn compute_optimize_delta_compares_pinned_revisions_regardless_of_recency() {
+        let thresholds = types::config::OptimizationThresholds {
+            min_traces: 1,
+            ..types::config::OptimizationThresholds::default()
+        };
+        let traces = vec![
+            make_recent_trace("rev-a", "task-1", "failure", 10, 100),
+            make_recent_trace("rev-b", "task-1", "success", 10, 100),
+            make_recent_trace("rev-c", "task-1", "failure", 10, 100),
+        ];
+        let latest = compute_optimize_delta(
+            &traces,
+            thresholds,
+            None,
+            &types::config::default_success_outcomes(),
+        );
+        assert_eq!(latest.baseline_revision.as_deref(), Some("rev-b"));
+        assert_eq!(latest.current_revision.as_deref(), Some("rev-c"));
+
+        let pinned = compute_optimize_delta(
+            &traces,
+            thresholds,
+            Some(("rev-a", "rev-b")),
+            &types::config::default_success_outcomes(),
+        );
+        assert_eq!(pinned.baseline_revision.as_deref(), Some("rev-a"));
+        assert_eq!(pinned.current_revision.as_deref(), Some("rev-b"));
+        assert_eq!(pinned.status, OptimizeDeltaStatus::Improvement);
+    }
+
+    #[test]
+    fn validate_pinned_revisions_rejects_revisions_without_traces() {
+        let traces = vec![make_recent_trace("rev-a", "task-1", "success", 10, 100)];
+        let err = validate_pinned_revisions(&traces, "rev-a", "rev-z", 90)
+            .expect_err("missing revision should be rejected");
+        assert!(matches!(err, HarnessError::RevisionNotFound(_)));
+        assert!(err.to_string().contains("`rev-z` has no traces"));
+        assert!(validate_pinned_revisions(&traces, "rev-a", "rev-a", 90).is_err());
+    }
+
     #[test]
     fn compute_optimize_delta_rejects_low_task_overlap() {
         let thresholds = types::config::OptimizationThresholds {
@@ -1404,6 +3267,7 @@ mod tests {
             min_uplift_rel: 0.10,
             trace_staleness_days: 90,
             task_overlap_threshold: 0.80,
+            significance_alpha: None,
         };
         let traces = vec![
             make_recent_trace("rev-a", "task-1", "success", 10, 100),
@@ -1411,7 +3275,12 @@ mod tests {
             make_recent_trace("rev-b", "task-3", "success", 10, 100),
             make_recent_trace("rev-b", "task-4", "success", 10, 100),
         ];
-        let delta = compute_optimize_delta(&traces, thresholds);
+        let delta = compute_optimize_delta(
+            &traces,
+            thresholds,
+            None,
+            &types::config::default_success_outcomes(),
+        );
         assert_eq!(delta.status, OptimizeDeltaStatus::InsufficientData);
         let reason = delta.reason.expect("reason should exist");
         assert!(reason.contains("task overlap"));
@@ -1437,6 +3306,23 @@ mod tests {
         assert!(result.is_ok());
     }
 
+    #[test]
+    fn bench_compare_rejects_config_hash_mismatch_without_force() {
+        let current = make_bench_context("linux-x86_64", "rustc 1.77.0", false);
+        let mut baseline = make_bench_context("linux-x86_64", "rustc 1.77.0", false);
+        baseline.config_hash = "other".to_string();
+
+        let err = validate_bench_compare_compatibility(&current, &baseline, false)
+            .expect_err("compare should be blocked");
+        assert!(err.to_string().contains("config_hash"));
+
+        baseline.config_hash = String::new();
+        assert!(
+            validate_bench_compare_compatibility(&current, &baseline, false).is_ok(),
+            "baselines without a config hash should stay comparable"
+        );
+    }
+
     #[test]
     fn bench_average_overall_score_handles_empty_and_non_empty_runs() {
         assert!((average_overall_score(&[]) - 0.0).abs() < 0.001);
@@ -1452,4 +3338,131 @@ mod tests {
         ];
         assert!((average_overall_score(&runs) - 0.7).abs() < 0.001);
     }
+
+    fn make_bench_runs(scores: &[f32]) -> Vec<BenchRunResult> {
+        scores
+            .iter()
+            .enumerate()
+            .map(|(index, score)| BenchRunResult {
+                run: index as u32 + 1,
+                overall_score: *score,
+            })
+            .collect()
+    }
+
+    #[test]
+    fn bench_compare_runs_reports_per_run_deltas() {
+        let comparison =
+            compare_bench_runs(&make_bench_runs(&[0.5, 0.5]), &make_bench_runs(&[0.5, 0.9]));
+        let deltas = comparison
+            .deltas
+            .iter()
+            .map(|delta| delta.current_score - delta.baseline_score)
+            .collect::<Vec<_>>();
+        assert_eq!(deltas.len(), 2);
+        assert!(deltas[0].abs() < 0.001);
+        assert!((deltas[1] - 0.4).abs() < 0.001);
+        assert!((comparison.current_avg - 0.7).abs() < 0.001);
+    }
+
+    #[test]
+    fn bench_compare_runs_uses_overlapping_prefix_for_unequal_counts() {
+        let comparison = compare_bench_runs(
+            &make_bench_runs(&[0.4, 0.6, 0.2]),
+            &make_bench_runs(&[0.5, 0.7]),
+        );
+        assert_eq!(comparison.baseline_runs, 3);
+        assert_eq!(comparison.current_runs, 2);
+        assert_eq!(comparison.deltas.len(), 2);
+        assert_eq!(comparison.deltas[1].run, 2);
+        assert!((comparison.baseline_avg - 0.5).abs() < 0.001);
+        assert!((comparison.current_avg - 0.6).abs() < 0.001);
+    }
+
+    #[test]
+    fn bench_regression_gate_flags_drops_beyond_threshold() {
+        let comparison =
+            compare_bench_runs(&make_bench_runs(&[0.8, 0.8]), &make_bench_runs(&[0.6, 0.6]));
+        assert!(is_bench_regression(&comparison, 0.1));
+        assert!(!is_bench_regression(&comparison, 0.25));
+    }
+
+    #[test]
+    fn bench_regression_gate_ignores_improvements() {
+        let comparison = compare_bench_runs(&make_bench_runs(&[0.5]), &make_bench_runs(&[0.9]));
+        assert!(!is_bench_regression(&comparison, 0.0));
+    }
+
+    #[test]
+    fn bench_aggregate_fixture_runs_averages_by_run_index() {
+        let fixtures = vec![
+            BenchFixtureResult {
+                name: "alpha".to_string(),
+                config_hash: "alpha-cfg".to_string(),
+                runs: vec![
+                    BenchRunResult {
+                        run: 1,
+                        overall_score: 0.4,
+                    },
+                    BenchRunResult {
+                        run: 2,
+                        overall_score: 0.6,
+                    },
+                ],
+            },
+            BenchFixtureResult {
+                name: "beta".to_string(),
+                config_hash: "beta-cfg".to_string(),
+                runs: vec![
+                    BenchRunResult {
+                        run: 1,
+                        overall_score: 0.8,
+                    },
+                    BenchRunResult {
+                        run: 2,
+                        overall_score: 1.0,
+                    },
+                ],
+            },
+        ];
+        let aggregate = aggregate_fixture_runs(&fixtures);
+        assert_eq!(aggregate.len(), 2);
+        assert!((aggregate[0].overall_score - 0.6).abs() < 0.001);
+        assert!((aggregate[1].overall_score - 0.8).abs() < 0.001);
+        assert!(aggregate_fixture_runs(&[]).is_empty());
+    }
+
+    #[test]
+    fn bench_fixtures_config_hash_tracks_each_fixture_config() {
+        let fixture = |name: &str, config_hash: &str| BenchFixtureResult {
+            name: name.to_string(),
+            config_hash: config_hash.to_string(),
+            runs: Vec::new(),
+        };
+        let baseline = fixtures_config_hash(&[fixture("alpha", "a"), fixture("beta", "b")]);
+        assert_eq!(
+            baseline,
+            fixtures_config_hash(&[fixture("alpha", "a"), fixture("beta", "b")])
+        );
+        assert_ne!(
+            baseline,
+            fixtures_config_hash(&[fixture("alpha", "a"), fixture("beta", "changed")])
+        );
+    }
+
+    #[test]
+    fn list_bench_fixtures_distinguishes_missing_and_empty_directories() {
+        let dir = tempfile::TempDir::new().expect("temp dir should be created");
+        let missing = list_bench_fixtures(&dir.path().join("missing"))
+            .expect_err("missing directory should be rejected");
+        assert!(matches!(missing, HarnessError::PathNotFound(_)));
+
+        let empty =
+            list_bench_fixtures(dir.path()).expect_err("empty directory should be rejected");
+        assert!(matches!(empty, HarnessError::NoBenchFixtures(_)));
+
+        std::fs::create_dir(dir.path().join("repo")).expect("fixture should create");
+        let fixtures = list_bench_fixtures(dir.path()).expect("fixture should be listed");
+        assert_eq!(fixtures, vec![dir.path().join("repo")]);
+    }
 }
@@ -0,0 +1,221 @@
+use crate::report::format_score;
+use crate::types::report::{BaselineChanges, Finding, HarnessReport};
+use crate::types::scoring::Score;
+
+pub fn to_markdown_diff(baseline: &HarnessReport, current: &HarnessReport) -> String {
+    let percent = current.overall_score_percent.is_some();
+    let mut output = String::new();
+    output.push_str("# Harness Report Comparison\n\n");
+    output.push_str(&format!(
+        "**Verdict: {} -> {}**\n\n",
+        baseline.verdict.as_str().to_uppercase(),
+        current.verdict.as_str().to_uppercase()
+    ));
+    output.push_str(&format!(
+        "Overall score: {} -> {} ({})\n\n",
+        format_score(baseline.overall_score, percent, 3),
+        format_score(current.overall_score, percent, 3),
+        format_delta(current.overall_score - baseline.overall_score, percent)
+    ));
+
+    output.push_str("## Category Scores\n\n");
+    output.push_str("| Category | Baseline | Current | Change |\n");
+    output.push_str("| --- | ---: | ---: | ---: |\n");
+    for ((category, before), (_, after)) in baseline
+        .category_scores
+        .categories()
+        .into_iter()
+        .zip(current.category_scores.categories())
+    {
+        output.push_str(&format!(
+            "| {category} | {} | {} | {} |\n",
+            format_score(before, percent, 3),
+            format_score(after, percent, 3),
+            format_delta(after - before, percent)
+        ));
+    }
+    output.push('\n');
+
+    push_findings(
+        &mut output,
+        "New Findings",
+        &findings_missing_from(&current.findings, &baseline.findings),
+    );
+    push_findings(
+        &mut output,
+        "Resolved Findings",
+        &findings_missing_from(&baseline.findings, &current.findings),
+    );
+    output
+}
+
+pub fn baseline_changes(baseline: &HarnessReport, current: &HarnessReport) -> BaselineChanges {
+    let ids = |findings: Vec<&Finding>| {
+        let mut ids = findings
+            .into_iter()
+            .map(|finding| finding.id.clone())
+            .collect::<Vec<_>>();
+        ids.dedup();
+        ids
+    };
+    let mut persisting = current
+        .findings
+        .iter()
+        .filter(|finding| {
+            baseline
+                .findings
+                .iter()
+                .any(|candidate| candidate.id == finding.id)
+        })
+        .collect::<Vec<_>>();
+    persisting.sort_by(|a, b| a.id.cmp(&b.id));
+    BaselineChanges {
+        new: ids(findings_missing_from(&current.findings, &baseline.findings)),
+        persisting: ids(persisting),
+        resolved: ids(findings_missing_from(&baseline.findings, &current.findings)),
+    }
+}
+
+fn format_delta(delta: Score, percent: bool) -> String {
+    if percent {
+        format!("{:+}", (delta * 100.0).round() as i32)
+    } else {
+        format!("{delta:+.3}")
+    }
+}
+
+fn findings_missing_from<'a>(findings: &'a [Finding], other: &[Finding]) -> Vec<&'a Finding> {
+    let mut missing = findings
+        .iter()
+        .filter(|finding| !other.iter().any(|candidate| candidate.id == finding.id))
+        .collect::<Vec<_>>();
+    missing.sort_by(|a, b| a.id.cmp(&b.id));
+    missing
+}
+
+fn push_findings(output: &mut String, heading: &str, findings: &[&Finding]) {
+    output.push_str(&format!("## {heading}\n\n"));
+    if findings.is_empty() {
+        output.push_str("- none\n");
+    } else {
+        for finding in findings {
+            let severity = if finding.blocking {
+                "blocking"
+            } else {
+                "warning"
+            };
+            output.push_str(&format!(
+                "- `{}` ({severity}): {}\n",
+                finding.id, finding.title
+            ));
+        }
+    }
+    output.push('\n');
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::report::Verdict;
+    use crate::types::scoring::ScoreCard;
+
+    fn finding(id: &str, blocking: bool) -> Finding {
+        Finding {
+            id: id.to_string(),
+            title: format!("{id} title"),
+            body: id.to_string(),
+            blocking,
+            file: None,
+        }
+    }
+
+    fn report(overall: f32, context: f32, findings: Vec<Finding>) -> HarnessReport {
+        HarnessReport {
+            verdict: Verdict::from_findings(&findings),
+            overall_score: overall,
+            category_scores: ScoreCard::new(context, 0.5, 0.5, 0.5, 0.5),
+            findings,
+            ..HarnessReport::fixture()
+        }
+    }
+
+    #[test]
+    fn markdown_diff_tabulates_category_changes() {
+        let baseline = report(0.60, 0.40, vec![]);
+        let current = report(0.70, 0.90, vec![]);
+
+        let rendered = to_markdown_diff(&baseline, &current);
+        assert!(rendered.contains("Overall score: 0.600 -> 0.700 (+0.100)"));
+        assert!(rendered.contains("| context | 0.400 | 0.900 | +0.500 |"));
+        assert!(rendered.contains("| tools | 0.500 | 0.500 | +0.000 |"));
+    }
+
+    #[test]
+    fn markdown_diff_lists_new_and_resolved_findings_by_id() {
+        let baseline = report(
+            0.6,
+            0.5,
+            vec![
+                finding("context.missing_agents", false),
+                finding("tools.disabled", true),
+            ],
+        );
+        let current = report(
+            0.6,
+            0.5,
+            vec![
+                finding("tools.disabled", true),
+                finding("verification.incomplete", true),
+            ],
+        );
+
+        let rendered = to_markdown_diff(&baseline, &current);
+        let (new_section, resolved_section) = rendered
+            .split_once("## Resolved Findings")
+            .expect("resolved section should render");
+        assert!(new_section.contains("- `verification.incomplete` (blocking)"));
+        assert!(!new_section.contains("tools.disabled"));
+        assert!(resolved_section.contains("- `context.missing_agents` (warning)"));
+        assert!(!resolved_section.contains("tools.disabled"));
+    }
+
+    #[test]
+    fn baseline_changes_classify_findings_by_id() {
+        let baseline = report(
+            0.6,
+            0.5,
+            vec![
+                finding("context.missing_agents", false),
+                finding("tools.disabled", true),
+            ],
+        );
+        let current = report(
+            0.6,
+            0.5,
+            vec![
+                finding("verification.incomplete", true),
+                finding("tools.disabled", true),
+                finding("context.missing_index", false),
+            ],
+        );
+
+        assert_eq!(
+            baseline_changes(&baseline, &current),
+            BaselineChanges {
+                new: vec![
+                    "context.missing_index".to_string(),
+                    "verification.incomplete".to_string(),
+                ],
+                persisting: vec!["tools.disabled".to_string()],
+                resolved: vec!["context.missing_agents".to_string()],
+            }
+        );
+    }
+
+    #[test]
+    fn markdown_diff_reports_none_when_findings_are_unchanged() {
+        let baseline = report(0.6, 0.5, vec![finding("tools.disabled", true)]);
+        let rendered = to_markdown_diff(&baseline, &baseline.clone());
+        assert_eq!(rendered.matches("- none").count(), 2);
+    }
+}
@@ -0,0 +1,41 @@
+use crate::report::format_score;
+use crate::types::report::HarnessReport;
+
+pub fn to_count(report: &HarnessReport) -> String {
+    format!(
+        "blocking={} warning={} score={}",
+        report.blocking_count,
+        report.warning_count,
+        format_score(
+            report.overall_score,
+            report.overall_score_percent.is_some(),
+            2
+        )
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn report(overall_score_percent: Option<u8>) -> HarnessReport {
+        HarnessReport {
+            overall_score: 0.734,
+            overall_score_percent,
+            blocking_count: 1,
+            warning_count: 1,
+            ..HarnessReport::fixture()
+        }
+    }
+
+    #[test]
+    fn count_is_one_line_of_counts_and_score() {
+        let rendered = to_count(&report(None));
+        assert_eq!(rendered, "blocking=1 warning=1 score=0.73");
+    }
+
+    #[test]
+    fn count_uses_percent_score_when_enabled() {
+        assert_eq!(to_count(&report(Some(73))), "blocking=1 warning=1 score=73");
+    }
+}
@@ -0,0 +1,135 @@
+use crate::report::hidden_findings;
+use crate::types::report::HarnessReport;
+use crate::util::path::repo_relative_uri;
+
+pub fn to_github_actions(report: &HarnessReport, max_findings: Option<usize>) -> String {
+    let mut commands = report
+        .findings
+        .iter()
+        .take(max_findings.unwrap_or(usize::MAX))
+        .map(|finding| {
+            let command = if finding.blocking { "error" } else { "warning" };
+            let mut properties = Vec::new();
+            if let Some(file) = &finding.file {
+                properties.push(format!(
+                    "file={}",
+                    escape_property(&repo_relative_uri(file))
+                ));
+            }
+            properties.push(format!(
+                "title={}",
+                escape_property(&format!("{} ({})", finding.title, finding.id))
+            ));
+            format!(
+                "::{command} {}::{}",
+                properties.join(","),
+                escape_data(&finding.body)
+            )
+        })
+        .collect::<Vec<_>>();
+    if let Some(hidden) = hidden_findings(report, max_findings) {
+        commands.push(format!(
+            "::notice::+{hidden} more harness finding(s) not shown"
+        ));
+    }
+    commands.join("\n")
+}
+
+fn escape_data(value: &str) -> String {
+    value
+        .replace('%', "%25")
+        .replace('\r', "%0D")
+        .replace('\n', "%0A")
+}
+
+fn escape_property(value: &str) -> String {
+    escape_data(value).replace(':', "%3A").replace(',', "%2C")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::report::Finding;
+
+    fn report(findings: Vec<Finding>) -> HarnessReport {
+        HarnessReport {
+            findings,
+            ..HarnessReport::fixture()
+        }
+    }
+
+    #[test]
+    fn blocking_findings_emit_error_commands_and_warnings_emit_warning_commands() {
+        let rendered = to_github_actions(
+            &report(vec![
+                Finding {
+                    id: "tools.disabled".to_string(),
+                    title: "Disabled tools are configured".to_string(),
+                    body: "rm is disabled".to_string(),
+                    blocking: true,
+                    file: Some("harness.toml".to_string()),
+                },
+                Finding {
+                    id: "context.missing_agents".to_string(),
+                    title: "Missing AGENTS.md".to_string(),
+                    body: "add it".to_string(),
+                    blocking: false,
+                    file: None,
+                },
+            ]),
+            None,
+        );
+
+        let lines = rendered.lines().collect::<Vec<_>>();
+        assert_eq!(
+            lines,
+            vec![
+                "::error file=harness.toml,title=Disabled tools are configured (tools.disabled)::rm is disabled",
+                "::warning title=Missing AGENTS.md (context.missing_agents)::add it",
+            ]
+        );
+    }
+
+    #[test]
+    fn workflow_command_values_are_escaped() {
+        let rendered = to_github_actions(
+            &report(vec![Finding {
+                id: "a.b".to_string(),
+                title: "x: y, z".to_string(),
+                body: "100%\nnext line".to_string(),
+                blocking: true,
+                file: Some("docs\\context\\INDEX.md".to_string()),
+            }]),
+            None,
+        );
+
+        assert_eq!(
+            rendered,
+            "::error file=docs/context/INDEX.md,title=x%3A y%2C z (a.b)::100%25%0Anext line"
+        );
+    }
+
+    #[test]
+    fn no_findings_emit_no_commands() {
+        assert!(to_github_actions(&report(vec![]), None).is_empty());
+    }
+
+    #[test]
+    fn findings_beyond_cap_are_summarized_in_a_notice() {
+        let findings = (0..4)
+            .map(|index| Finding {
+                id: format!("a.f{index}"),
+                title: format!("F{index}"),
+                body: "body".to_string(),
+                blocking: false,
+                file: None,
+            })
+            .collect();
+        let rendered = to_github_actions(&report(findings), Some(2));
+
+        let lines = rendered.lines().collect::<Vec<_>>();
+        assert_eq!(lines.len(), 3);
+        assert!(lines[1].contains("(a.f1)"));
+        assert_eq!(lines[2], "::notice::+2 more harness finding(s) not shown");
+    }
+}
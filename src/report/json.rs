@@ -1,14 +1,23 @@
 use crate::types::report::HarnessReport;
+use std::io::Write;
 
 pub fn to_json(report: &HarnessReport) -> Result<String, serde_json::Error> {
     serde_json::to_string_pretty(report)
 }
 
+pub fn write_json(out: &mut dyn Write, report: &HarnessReport) -> Result<(), serde_json::Error> {
+    serde_json::to_writer_pretty(out, report)
+}
+
+pub fn from_json(content: &str) -> Result<HarnessReport, serde_json::Error> {
+    serde_json::from_str(content)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
     use crate::types::report::{Effort, Impact, Risk};
-    use crate::types::report::{HarnessReport, Recommendation};
+    use crate::types::report::{Grade, HarnessReport, Recommendation, Verdict};
     use crate::types::scoring::ScoreCard;
 
     #[test]
@@ -16,7 +25,6 @@ mod tests {
         let report = HarnessReport {
             overall_score: 0.8,
             category_scores: ScoreCard::new(0.8, 0.7, 0.6, 0.9, 0.7),
-            findings: vec![],
             recommendations: vec![Recommendation::new(
                 "id",
                 "title",
@@ -26,9 +34,19 @@ mod tests {
                 Risk::Safe,
                 0.9,
             )],
+            ..HarnessReport::fixture()
         };
 
         let rendered = to_json(&report).expect("json should serialize");
         assert!(rendered.contains("\"overall_score\": 0.8"));
+        assert!(rendered.contains("\"verdict\": \"pass\""));
+        assert!(rendered.contains("\"blocking_count\": 0"));
+        assert!(rendered.contains("\"warning_count\": 0"));
+
+        let parsed = from_json(&rendered).expect("rendered json should parse back");
+        assert_eq!(parsed.verdict, Verdict::Pass);
+        assert_eq!(parsed.grade, Grade::F);
+        assert_eq!(parsed.recommendations[0].id, "id");
+        assert!((parsed.category_scores.tools - 0.7).abs() < 0.001);
     }
 }
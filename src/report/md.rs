@@ -1,69 +1,220 @@
+use crate::i18n::Catalog;
+use crate::report::{format_score, hidden_findings};
 use crate::types::report::HarnessReport;
+use std::io::{self, Write};
 
-pub fn to_markdown(report: &HarnessReport) -> String {
-    let mut output = String::new();
-    output.push_str("# Harness Report\n\n");
-    output.push_str(&format!("Overall score: {:.3}\n\n", report.overall_score));
-    output.push_str("## Category Scores\n\n");
-    output.push_str(&format!(
-        "- context: {:.3}\n- tools: {:.3}\n- continuity: {:.3}\n- verification: {:.3}\n- repository_quality: {:.3}\n\n",
-        report.category_scores.context,
-        report.category_scores.tools,
-        report.category_scores.continuity,
-        report.category_scores.verification,
-        report.category_scores.repository_quality
-    ));
-
-    output.push_str("## Findings\n\n");
+#[cfg(test)]
+pub(crate) const HEADINGS: [&str; 10] = [
+    "Harness Report",
+    "Contents",
+    "Category Scores",
+    "Score Breakdown",
+    "Scan Stats",
+    "Changes Since Baseline",
+    "Findings",
+    "Blocking",
+    "Warnings",
+    "Recommendations",
+];
+
+pub fn to_markdown(
+    report: &HarnessReport,
+    max_findings: Option<usize>,
+    catalog: &Catalog,
+) -> String {
+    let mut output = Vec::new();
+    write_markdown(&mut output, report, max_findings, catalog)
+        .expect("writing markdown to memory should not fail");
+    String::from_utf8(output).expect("markdown output should be valid utf-8")
+}
+
+pub fn write_markdown(
+    out: &mut dyn Write,
+    report: &HarnessReport,
+    max_findings: Option<usize>,
+    catalog: &Catalog,
+) -> io::Result<()> {
+    let heading = |english: &'static str| catalog.heading(english);
+    writeln!(out, "# {}\n", heading("Harness Report"))?;
+    writeln!(
+        out,
+        "**Verdict: {}**\n",
+        report.verdict.as_str().to_uppercase()
+    )?;
+    writeln!(out, "**Grade: {}**\n", report.grade.as_str())?;
+    let percent = report.overall_score_percent.is_some();
+    writeln!(
+        out,
+        "Overall score: {}\n",
+        format_score(report.overall_score, percent, 3)
+    )?;
+    writeln!(
+        out,
+        "Context token estimate: ~{}\n",
+        report.context_token_estimate
+    )?;
+
+    let mut sections = vec![heading("Category Scores")];
+    if report.score_breakdown.is_some() {
+        sections.push(heading("Score Breakdown"));
+    }
+    if report.scan_stats.is_some() {
+        sections.push(heading("Scan Stats"));
+    }
+    if report.baseline_changes.is_some() {
+        sections.push(heading("Changes Since Baseline"));
+    }
+    sections.extend([heading("Findings"), heading("Recommendations")]);
+    writeln!(out, "## {}\n", heading("Contents"))?;
+    for section in sections {
+        writeln!(out, "- [{section}](#{})", anchor_slug(section))?;
+    }
+    writeln!(out)?;
+
+    writeln!(out, "## {}\n", heading("Category Scores"))?;
+    for (category, score) in report.category_scores.categories() {
+        writeln!(out, "- {category}: {}", format_score(score, percent, 3))?;
+    }
+    writeln!(out)?;
+
+    if let Some(breakdowns) = &report.score_breakdown {
+        writeln!(out, "## {}\n", heading("Score Breakdown"))?;
+        for (category, _) in report.category_scores.categories() {
+            let Some(breakdown) = breakdowns.get(category) else {
+                continue;
+            };
+            writeln!(
+                out,
+                "### {category}: {}\n",
+                format_score(breakdown.total, percent, 3)
+            )?;
+            if breakdown.base != 0.0 {
+                writeln!(out, "- base: {:.2}", breakdown.base)?;
+            }
+            for contribution in &breakdown.contributions {
+                writeln!(out, "- {}: {:+.2}", contribution.signal, contribution.delta)?;
+            }
+            writeln!(out)?;
+        }
+    }
+
+    if let Some(stats) = &report.scan_stats {
+        writeln!(out, "## {}\n", heading("Scan Stats"))?;
+        writeln!(out, "- files scanned: {}", stats.file_count)?;
+        for directory in &stats.top_directories {
+            writeln!(
+                out,
+                "- {}: {} file(s)",
+                directory.path, directory.file_count
+            )?;
+        }
+        writeln!(out)?;
+    }
+
+    if let Some(changes) = &report.baseline_changes {
+        writeln!(out, "## {}\n", heading("Changes Since Baseline"))?;
+        for (label, ids) in [
+            ("new", &changes.new),
+            ("persisting", &changes.persisting),
+            ("resolved", &changes.resolved),
+        ] {
+            let listed = if ids.is_empty() {
+                "none".to_string()
+            } else {
+                ids.iter()
+                    .map(|id| format!("`{id}`"))
+                    .collect::<Vec<_>>()
+                    .join(", ")
+            };
+            writeln!(out, "- {label}: {listed}")?;
+        }
+        writeln!(out)?;
+    }
+
+    writeln!(out, "## {}\n", heading("Findings"))?;
     if report.findings.is_empty() {
-        output.push_str("- none\n\n");
+        writeln!(out, "- none\n")?;
     } else {
-        for finding in &report.findings {
-            output.push_str(&format!(
-                "- [{}] {}: {}\n",
-                if finding.blocking {
-                    "blocking"
-                } else {
-                    "warning"
-                },
-                finding.title,
-                finding.body
-            ));
+        let mut remaining = max_findings.unwrap_or(usize::MAX);
+        for (group_heading, blocking) in [("Blocking", true), ("Warnings", false)] {
+            let mut group = report
+                .findings
+                .iter()
+                .filter(|finding| finding.blocking == blocking)
+                .collect::<Vec<_>>();
+            group.sort_by(|a, b| a.id.cmp(&b.id));
+            group.truncate(remaining);
+            if group.is_empty() {
+                continue;
+            }
+            remaining -= group.len();
+            writeln!(out, "### {}\n", heading(group_heading))?;
+            for finding in group {
+                let state = report
+                    .baseline_changes
+                    .as_ref()
+                    .and_then(|changes| changes.state_of(&finding.id))
+                    .map(|state| format!(" [{state}]"))
+                    .unwrap_or_default();
+                writeln!(out, "- {}{state}: {}", finding.title, finding.body)?;
+            }
+            writeln!(out)?;
+        }
+        if let Some(hidden) = hidden_findings(report, max_findings) {
+            writeln!(out, "- +{hidden} more finding(s) not shown\n")?;
         }
-        output.push('\n');
     }
 
-    output.push_str("## Recommendations\n\n");
+    writeln!(out, "## {}\n", heading("Recommendations"))?;
     if report.recommendations.is_empty() {
-        output.push_str("- none\n");
+        writeln!(out, "- none")?;
     } else {
         for recommendation in &report.recommendations {
-            output.push_str(&format!(
-                "- {} ({:?}/{:?}, confidence {:.2}): {}\n",
+            let (low_hours, high_hours) = recommendation.effort.estimated_hours();
+            writeln!(
+                out,
+                "- {} ({:?}/{:?}, ~{}-{}h, confidence {:.2}): {}",
                 recommendation.title,
                 recommendation.impact,
                 recommendation.effort,
+                low_hours,
+                high_hours,
                 recommendation.confidence,
                 recommendation.summary
-            ));
+            )?;
         }
     }
 
-    output
+    Ok(())
+}
+
+fn anchor_slug(heading: &str) -> String {
+    heading
+        .trim()
+        .to_lowercase()
+        .chars()
+        .filter_map(|ch| match ch {
+            ' ' => Some('-'),
+            '-' | '_' => Some(ch),
+            _ if ch.is_alphanumeric() => Some(ch),
+            _ => None,
+        })
+        .collect()
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::types::report::{Effort, Impact, Recommendation, Risk};
-    use crate::types::scoring::ScoreCard;
+    use crate::types::report::{
+        BaselineChanges, DirectoryFileCount, Effort, Finding, Impact, Recommendation, Risk,
+        ScanStats, Verdict,
+    };
+    use crate::types::scoring::{ScoreBreakdown, ScoreCard, ScoreContribution};
+    use std::collections::BTreeMap;
 
     #[test]
     fn markdown_report_contains_sections() {
         let report = HarnessReport {
-            overall_score: 0.5,
-            category_scores: ScoreCard::new(0.1, 0.2, 0.3, 0.4, 0.5),
-            findings: vec![],
             recommendations: vec![Recommendation::new(
                 "id",
                 "Title",
@@ -73,11 +224,246 @@ mod tests {
                 Risk::Medium,
                 0.7,
             )],
+            ..HarnessReport::fixture()
         };
 
-        let rendered = to_markdown(&report);
+        let rendered = to_markdown(&report, None, &Catalog::default());
         assert!(rendered.contains("# Harness Report"));
+        assert!(rendered.contains("**Verdict: PASS**"));
+        assert!(rendered.contains("**Grade: F**"));
         assert!(rendered.contains("## Category Scores"));
         assert!(rendered.contains("## Recommendations"));
+        assert!(rendered.contains("(Medium/M, ~4-16h, confidence 0.70)"));
+        assert!(!rendered.contains("## Scan Stats"));
+    }
+
+    #[test]
+    fn markdown_renders_integer_scores_in_percent_scale() {
+        let report = HarnessReport {
+            overall_score: 0.876,
+            category_scores: ScoreCard::new(0.1, 0.25, 0.3, 0.4, 0.5),
+            overall_score_percent: Some(88),
+            ..HarnessReport::fixture()
+        };
+
+        let rendered = to_markdown(&report, None, &Catalog::default());
+        assert!(rendered.contains("Overall score: 88\n"));
+        assert!(rendered.contains("- tools: 25\n"));
+        assert!(!rendered.contains("0.876"));
+    }
+
+    #[test]
+    fn markdown_truncates_findings_beyond_cap_and_keeps_counts() {
+        let finding = |id: &str, blocking: bool| Finding {
+            id: id.to_string(),
+            title: id.to_string(),
+            body: "body".to_string(),
+            blocking,
+            file: None,
+        };
+        let report = HarnessReport {
+            verdict: Verdict::Fail,
+            findings: vec![
+                finding("b.block", true),
+                finding("a.block", true),
+                finding("c.warn", false),
+                finding("d.warn", false),
+                finding("e.warn", false),
+            ],
+            blocking_count: 2,
+            warning_count: 3,
+            ..HarnessReport::fixture()
+        };
+
+        let rendered = to_markdown(&report, Some(3), &Catalog::default());
+        assert!(rendered.contains("### Blocking\n\n- a.block: body\n- b.block: body\n"));
+        assert!(rendered.contains("### Warnings\n\n- c.warn: body\n\n"));
+        assert!(!rendered.contains("d.warn"));
+        assert!(rendered.contains("- +2 more finding(s) not shown\n"));
+        assert_eq!(report.findings.len(), 5);
+
+        let uncapped = to_markdown(&report, Some(5), &Catalog::default());
+        assert!(uncapped.contains("e.warn"));
+        assert!(!uncapped.contains("more finding(s)"));
+    }
+
+    #[test]
+    fn markdown_headings_follow_the_report_locale() {
+        let report = HarnessReport {
+            findings: vec![finding("tools.disabled", true)],
+            blocking_count: 1,
+            ..HarnessReport::fixture()
+        };
+
+        let rendered = to_markdown(&report, None, &crate::i18n::catalog("es"));
+        assert!(rendered.starts_with("# Informe de Harness\n"));
+        assert!(rendered.contains("## Hallazgos\n\n### Bloqueantes\n"));
+        assert!(rendered.contains("- [Hallazgos](#hallazgos)"));
+        assert!(!rendered.contains("## Findings"));
+    }
+
+    #[test]
+    fn anchor_slug_matches_github_heading_ids() {
+        assert_eq!(anchor_slug("Category Scores"), "category-scores");
+        assert_eq!(anchor_slug("Findings"), "findings");
+        assert_eq!(anchor_slug("Changes: Since v1.0!"), "changes-since-v10");
+    }
+
+    #[test]
+    fn markdown_table_of_contents_links_match_section_headers() {
+        let report = HarnessReport {
+            findings: vec![
+                finding("tools.disabled", false),
+                finding("tools.overlap", false),
+            ],
+            scan_stats: Some(ScanStats {
+                file_count: 1,
+                top_directories: vec![],
+            }),
+            baseline_changes: Some(BaselineChanges {
+                new: vec!["tools.disabled".to_string()],
+                persisting: vec!["tools.overlap".to_string()],
+                resolved: vec!["context.missing_agents".to_string()],
+            }),
+            ..HarnessReport::fixture()
+        };
+
+        let rendered = to_markdown(&report, None, &Catalog::default());
+        assert!(rendered.contains(
+            "## Changes Since Baseline\n\n- new: `tools.disabled`\n\
+             - persisting: `tools.overlap`\n- resolved: `context.missing_agents`\n"
+        ));
+        assert!(rendered.contains("- tools.disabled [new]: body\n"));
+        assert!(rendered.contains("- tools.overlap [persisting]: body\n"));
+        let headers = rendered
+            .lines()
+            .filter_map(|line| line.strip_prefix("## "))
+            .filter(|header| *header != "Contents")
+            .collect::<Vec<_>>();
+        let links = rendered
+            .lines()
+            .filter_map(|line| line.strip_prefix("- ["))
+            .filter_map(|line| line.split_once("](#"))
+            .map(|(title, anchor)| (title, anchor.trim_end_matches(')')))
+            .collect::<Vec<_>>();
+
+        assert_eq!(
+            headers,
+            vec![
+                "Category Scores",
+                "Scan Stats",
+                "Changes Since Baseline",
+                "Findings",
+                "Recommendations"
+            ]
+        );
+        assert_eq!(links.len(), headers.len());
+        for ((title, anchor), header) in links.iter().zip(headers.iter()) {
+            assert_eq!(title, header);
+            assert_eq!(*anchor, anchor_slug(header));
+        }
+    }
+
+    fn finding(id: &str, blocking: bool) -> Finding {
+        Finding {
+            id: id.to_string(),
+            title: id.to_string(),
+            body: "body".to_string(),
+            blocking,
+            file: None,
+        }
+    }
+
+    #[test]
+    fn markdown_findings_are_grouped_by_severity_and_sorted_by_id() {
+        let report = HarnessReport {
+            findings: vec![
+                finding("warn.b", false),
+                finding("block.z", true),
+                finding("warn.a", false),
+                finding("block.a", true),
+            ],
+            ..HarnessReport::fixture()
+        };
+
+        let rendered = to_markdown(&report, None, &Catalog::default());
+        let position = |needle: &str| {
+            rendered
+                .find(needle)
+                .unwrap_or_else(|| panic!("{needle} should render"))
+        };
+        assert!(position("### Blocking") < position("- block.a:"));
+        assert!(position("- block.a:") < position("- block.z:"));
+        assert!(position("- block.z:") < position("### Warnings"));
+        assert!(position("### Warnings") < position("- warn.a:"));
+        assert!(position("- warn.a:") < position("- warn.b:"));
+    }
+
+    #[test]
+    fn markdown_omits_empty_severity_groups() {
+        let report = HarnessReport {
+            findings: vec![finding("warn.a", false)],
+            ..HarnessReport::fixture()
+        };
+
+        let rendered = to_markdown(&report, None, &Catalog::default());
+        assert!(rendered.contains("### Warnings"));
+        assert!(!rendered.contains("### Blocking"));
+    }
+
+    #[test]
+    fn markdown_report_renders_scan_stats_when_present() {
+        let report = HarnessReport {
+            scan_stats: Some(ScanStats {
+                file_count: 12,
+                top_directories: vec![DirectoryFileCount {
+                    path: "src".to_string(),
+                    file_count: 9,
+                }],
+            }),
+            ..HarnessReport::fixture()
+        };
+
+        let rendered = to_markdown(&report, None, &Catalog::default());
+        assert!(rendered.contains("## Scan Stats"));
+        assert!(rendered.contains("- files scanned: 12"));
+        assert!(rendered.contains("- src: 9 file(s)"));
+    }
+
+    #[test]
+    fn markdown_report_renders_score_breakdown_when_present() {
+        let report = HarnessReport {
+            category_scores: ScoreCard::new(0.55, 0.85, 0.3, 0.4, 0.5),
+            score_breakdown: Some(BTreeMap::from([
+                (
+                    "tools".to_string(),
+                    ScoreBreakdown::new(
+                        1.0,
+                        vec![ScoreContribution::new("duplicate_entries", -0.15)],
+                    ),
+                ),
+                (
+                    "context".to_string(),
+                    ScoreBreakdown::new(
+                        0.0,
+                        vec![
+                            ScoreContribution::new("agents_md+header", 0.35),
+                            ScoreContribution::new("context_index", 0.20),
+                            ScoreContribution::new("architecture_doc", 0.0),
+                        ],
+                    ),
+                ),
+            ])),
+            ..HarnessReport::fixture()
+        };
+
+        let rendered = to_markdown(&report, None, &Catalog::default());
+        assert!(rendered.contains("- [Score Breakdown](#score-breakdown)"));
+        assert!(rendered.contains(
+            "### context: 0.550\n\n- agents_md+header: +0.35\n- context_index: +0.20\n\
+             - architecture_doc: +0.00\n"
+        ));
+        assert!(rendered.contains("### tools: 0.850\n\n- base: 1.00\n- duplicate_entries: -0.15\n"));
+        assert!(rendered.find("### context").unwrap() < rendered.find("### tools").unwrap());
     }
 }
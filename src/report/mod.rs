@@ -1,21 +1,119 @@
+pub mod compare;
+pub mod count;
+pub mod github;
 pub mod json;
 pub mod md;
+pub mod ndjson;
 pub mod sarif;
+pub mod summary;
 
 use crate::error::HarnessError;
+use crate::i18n::Catalog;
 use crate::types::report::HarnessReport;
+use crate::types::scoring::{score_percent, Score};
+use std::io::Write;
 
 #[derive(Debug, Clone, Copy)]
 pub enum OutputFormat {
     Json,
     Md,
     Sarif,
+    Summary,
+    GithubActions,
+    Count,
+    Ndjson,
 }
 
-pub fn render(report: &HarnessReport, format: OutputFormat) -> Result<String, HarnessError> {
+pub fn render(
+    report: &HarnessReport,
+    format: OutputFormat,
+    max_findings: Option<usize>,
+    catalog: &Catalog,
+) -> Result<String, HarnessError> {
     match format {
         OutputFormat::Json => json::to_json(report).map_err(HarnessError::Json),
-        OutputFormat::Md => Ok(md::to_markdown(report)),
+        OutputFormat::Md => Ok(md::to_markdown(report, max_findings, catalog)),
         OutputFormat::Sarif => sarif::to_sarif(report).map_err(HarnessError::Json),
+        OutputFormat::Summary => Ok(summary::to_summary(report)),
+        OutputFormat::GithubActions => Ok(github::to_github_actions(report, max_findings)),
+        OutputFormat::Count => Ok(count::to_count(report)),
+        OutputFormat::Ndjson => ndjson::to_ndjson(report).map_err(HarnessError::Json),
+    }
+}
+
+pub fn render_to(
+    out: &mut dyn Write,
+    report: &HarnessReport,
+    format: OutputFormat,
+    max_findings: Option<usize>,
+    catalog: &Catalog,
+) -> Result<(), HarnessError> {
+    match format {
+        OutputFormat::Json => json::write_json(out, report).map_err(HarnessError::Json),
+        OutputFormat::Md => {
+            md::write_markdown(out, report, max_findings, catalog).map_err(HarnessError::Io)
+        }
+        _ => out
+            .write_all(render(report, format, max_findings, catalog)?.as_bytes())
+            .map_err(HarnessError::Io),
+    }
+}
+
+fn hidden_findings(report: &HarnessReport, max_findings: Option<usize>) -> Option<usize> {
+    max_findings
+        .map(|max| report.findings.len().saturating_sub(max))
+        .filter(|hidden| *hidden > 0)
+}
+
+fn format_score(score: Score, percent: bool, decimals: usize) -> String {
+    if percent {
+        score_percent(score).to_string()
+    } else {
+        format!("{score:.decimals$}")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::report::Finding;
+
+    #[test]
+    fn streamed_output_matches_string_rendering() {
+        let findings = (0..3)
+            .map(|index| Finding {
+                id: format!("a.f{index}"),
+                title: format!("F{index}"),
+                body: "body".to_string(),
+                blocking: index == 0,
+                file: Some("AGENTS.md".to_string()),
+            })
+            .collect::<Vec<_>>();
+        let report = HarnessReport {
+            findings,
+            ..HarnessReport::fixture()
+        };
+
+        for format in [
+            OutputFormat::Json,
+            OutputFormat::Md,
+            OutputFormat::Sarif,
+            OutputFormat::Summary,
+            OutputFormat::GithubActions,
+            OutputFormat::Count,
+            OutputFormat::Ndjson,
+        ] {
+            let mut streamed = Vec::new();
+            let catalog = Catalog::default();
+            render_to(&mut streamed, &report, format, Some(2), &catalog)
+                .expect("report should stream");
+            let rendered =
+                render(&report, format, Some(2), &catalog).expect("report should render");
+            assert_eq!(
+                String::from_utf8(streamed).expect("utf-8"),
+                rendered,
+                "{format:?}"
+            );
+        }
     }
 }
@@ -0,0 +1,117 @@
+use crate::types::report::{Finding, HarnessReport};
+use serde::Serialize;
+use serde_json::{json, Map, Value};
+
+pub fn to_ndjson(report: &HarnessReport) -> Result<String, serde_json::Error> {
+    let mut lines = Vec::new();
+    for finding in &report.findings {
+        let baseline_state = report
+            .baseline_changes
+            .as_ref()
+            .and_then(|changes| changes.state_of(&finding.id));
+        lines.push(tagged(
+            "finding",
+            &FindingRecord {
+                finding,
+                baseline_state,
+            },
+        )?);
+    }
+    for recommendation in &report.recommendations {
+        lines.push(tagged("recommendation", recommendation)?);
+    }
+    lines.push(tagged(
+        "summary",
+        &json!({
+            "verdict": report.verdict,
+            "grade": report.grade,
+            "overall_score": report.overall_score,
+            "overall_score_percent": report.overall_score_percent,
+            "category_scores": report.category_scores,
+            "blocking_count": report.blocking_count,
+            "warning_count": report.warning_count,
+            "context_token_estimate": report.context_token_estimate,
+        }),
+    )?);
+    Ok(lines.join("\n"))
+}
+
+#[derive(Serialize)]
+struct FindingRecord<'a> {
+    #[serde(flatten)]
+    finding: &'a Finding,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    baseline_state: Option<&'static str>,
+}
+
+fn tagged<T: Serialize>(kind: &str, value: &T) -> Result<String, serde_json::Error> {
+    let mut record = Map::new();
+    record.insert("type".to_string(), Value::from(kind));
+    if let Value::Object(fields) = serde_json::to_value(value)? {
+        record.extend(fields);
+    }
+    serde_json::to_string(&Value::Object(record))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::report::{
+        BaselineChanges, Effort, Finding, Impact, Recommendation, Risk, Verdict,
+    };
+
+    #[test]
+    fn every_line_is_a_typed_json_record() {
+        let findings = vec![Finding {
+            id: "tools.disabled".to_string(),
+            title: "Disabled tools are configured".to_string(),
+            body: "rm is disabled\nsecond line".to_string(),
+            blocking: true,
+            file: Some("harness.toml".to_string()),
+        }];
+        let mut report = HarnessReport {
+            verdict: Verdict::from_findings(&findings),
+            blocking_count: 1,
+            findings,
+            recommendations: vec![Recommendation::new(
+                "rec.repo.scale",
+                "Document Repository Scale",
+                "summary",
+                Impact::Low,
+                Effort::Xs,
+                Risk::Safe,
+                0.6,
+            )],
+            context_token_estimate: 12,
+            ..HarnessReport::fixture()
+        };
+
+        let rendered = to_ndjson(&report).expect("ndjson should serialize");
+        let records = rendered
+            .lines()
+            .map(|line| serde_json::from_str::<Value>(line).expect("line should be JSON"))
+            .collect::<Vec<_>>();
+
+        let types = records
+            .iter()
+            .map(|record| record["type"].as_str().expect("record should carry a type"))
+            .collect::<Vec<_>>();
+        assert_eq!(types, vec!["finding", "recommendation", "summary"]);
+        assert_eq!(records[0]["id"], "tools.disabled");
+        assert_eq!(records[0]["blocking"], true);
+        assert_eq!(records[1]["id"], "rec.repo.scale");
+        assert_eq!(records[2]["verdict"], "fail");
+        assert_eq!(records[2]["blocking_count"], 1);
+        assert_eq!(records[2]["context_token_estimate"], 12);
+        assert!(records[0].get("baseline_state").is_none());
+
+        report.baseline_changes = Some(BaselineChanges {
+            new: vec!["tools.disabled".to_string()],
+            ..BaselineChanges::default()
+        });
+        let rendered = to_ndjson(&report).expect("ndjson should serialize");
+        let first: Value =
+            serde_json::from_str(rendered.lines().next().expect("finding line")).expect("json");
+        assert_eq!(first["baseline_state"], "new");
+    }
+}
@@ -1,18 +1,49 @@
 use crate::types::report::HarnessReport;
+use crate::util::path::repo_relative_uri;
 use serde_json::json;
 
 pub fn to_sarif(report: &HarnessReport) -> Result<String, serde_json::Error> {
-    let results: Vec<_> = report
+    let mut results: Vec<_> = report
         .findings
         .iter()
         .map(|finding| {
-            json!({
+            let mut result = json!({
                 "ruleId": finding.id,
                 "level": if finding.blocking { "error" } else { "warning" },
                 "message": { "text": finding.body },
-            })
+            });
+            if let Some(file) = &finding.file {
+                result["locations"] = json!([{
+                    "physicalLocation": {
+                        "artifactLocation": {
+                            "uri": repo_relative_uri(file),
+                            "uriBaseId": "%SRCROOT%"
+                        }
+                    }
+                }]);
+            }
+            if let Some(changes) = &report.baseline_changes {
+                let state = if changes.new.contains(&finding.id) {
+                    "new"
+                } else {
+                    "unchanged"
+                };
+                result["baselineState"] = json!(state);
+            }
+            result
         })
         .collect();
+    if let Some(changes) = &report.baseline_changes {
+        results.extend(changes.resolved.iter().map(|id| {
+            json!({
+                "ruleId": id,
+                "kind": "pass",
+                "level": "none",
+                "baselineState": "absent",
+                "message": { "text": "Resolved since the baseline report." },
+            })
+        }));
+    }
 
     let sarif = json!({
         "version": "2.1.0",
@@ -22,9 +53,132 @@ pub fn to_sarif(report: &HarnessReport) -> Result<String, serde_json::Error> {
                     "name": "harness"
                 }
             },
-            "results": results
+            "results": results,
+            "properties": {
+                "verdict": report.verdict.as_str()
+            }
         }]
     });
 
     serde_json::to_string_pretty(&sarif)
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::report::{BaselineChanges, Finding, Verdict};
+
+    use serde_json::Value;
+
+    const SARIF_SCHEMA: &str = include_str!("../../tests/fixtures/sarif-2.1.0-subset.schema.json");
+
+    fn finding(id: &str, blocking: bool) -> Finding {
+        Finding {
+            id: id.to_string(),
+            title: "title".to_string(),
+            body: "body".to_string(),
+            blocking,
+            file: None,
+        }
+    }
+
+    fn sample_report() -> HarnessReport {
+        HarnessReport {
+            verdict: Verdict::Fail,
+            findings: vec![
+                finding("tools.destructive_exposed", true),
+                Finding {
+                    file: Some("docs\\context\\INDEX.md".to_string()),
+                    ..finding("context.missing_index", false)
+                },
+            ],
+            ..HarnessReport::fixture()
+        }
+    }
+
+    fn schema_violations(document: &Value) -> Vec<String> {
+        let schema: Value = serde_json::from_str(SARIF_SCHEMA).expect("schema should parse");
+        let validator = jsonschema::validator_for(&schema).expect("schema should compile");
+        validator
+            .iter_errors(document)
+            .map(|error| format!("{}: {}", error.instance_path(), error))
+            .collect()
+    }
+
+    #[test]
+    fn sarif_run_carries_verdict_property() {
+        let rendered = to_sarif(&sample_report()).expect("sarif should serialize");
+        let parsed: Value = serde_json::from_str(&rendered).expect("sarif should be valid json");
+        assert_eq!(parsed["runs"][0]["properties"]["verdict"], "fail");
+        assert_eq!(parsed["runs"][0]["results"][0]["level"], "error");
+    }
+
+    #[test]
+    fn sarif_locations_use_normalized_repo_relative_uris() {
+        let rendered = to_sarif(&sample_report()).expect("sarif should serialize");
+        let parsed: Value = serde_json::from_str(&rendered).expect("sarif should be valid json");
+        let results = &parsed["runs"][0]["results"];
+        assert!(results[0].get("locations").is_none());
+        let artifact = &results[1]["locations"][0]["physicalLocation"]["artifactLocation"];
+        assert_eq!(artifact["uri"], "docs/context/INDEX.md");
+        assert_eq!(artifact["uriBaseId"], "%SRCROOT%");
+    }
+
+    #[test]
+    fn sarif_output_conforms_to_schema() {
+        let rendered = to_sarif(&sample_report()).expect("sarif should serialize");
+        let parsed: Value = serde_json::from_str(&rendered).expect("sarif should be valid json");
+        let violations = schema_violations(&parsed);
+        assert!(
+            violations.is_empty(),
+            "sarif output violates the 2.1.0 schema:\n{}",
+            violations.join("\n")
+        );
+    }
+
+    #[test]
+    fn sarif_results_carry_baseline_state_when_compared() {
+        let report = HarnessReport {
+            baseline_changes: Some(BaselineChanges {
+                new: vec!["tools.destructive_exposed".to_string()],
+                persisting: vec!["context.missing_index".to_string()],
+                resolved: vec!["context.missing_agents".to_string()],
+            }),
+            ..sample_report()
+        };
+        let rendered = to_sarif(&report).expect("sarif should serialize");
+        let parsed: Value = serde_json::from_str(&rendered).expect("sarif should be valid json");
+        let results = parsed["runs"][0]["results"]
+            .as_array()
+            .expect("results should be an array");
+
+        assert_eq!(results.len(), 3);
+        assert_eq!(results[0]["baselineState"], "new");
+        assert_eq!(results[1]["baselineState"], "unchanged");
+        assert_eq!(results[2]["ruleId"], "context.missing_agents");
+        assert_eq!(results[2]["baselineState"], "absent");
+        assert_eq!(results[2]["level"], "none");
+        assert!(schema_violations(&parsed).is_empty());
+    }
+
+    #[test]
+    fn sarif_omits_baseline_state_without_comparison() {
+        let rendered = to_sarif(&sample_report()).expect("sarif should serialize");
+        let parsed: Value = serde_json::from_str(&rendered).expect("sarif should be valid json");
+        assert!(parsed["runs"][0]["results"][0]
+            .get("baselineState")
+            .is_none());
+    }
+
+    #[test]
+    fn sarif_schema_check_reports_violation_path() {
+        let rendered = to_sarif(&sample_report()).expect("sarif should serialize");
+        let mut parsed: Value =
+            serde_json::from_str(&rendered).expect("sarif should be valid json");
+        parsed["runs"][0]["results"][0]["level"] = Value::from("fatal");
+        let violations = schema_violations(&parsed);
+        assert!(violations
+            .iter()
+            .any(|violation| violation.starts_with("/runs/0/results/0/level")));
+    }
+}
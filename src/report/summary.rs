@@ -0,0 +1,40 @@
+use crate::report::format_score;
+use crate::types::report::HarnessReport;
+
+pub fn to_summary(report: &HarnessReport) -> String {
+    let percent = report.overall_score_percent.is_some();
+    let scores = &report.category_scores;
+    format!(
+        "harness: {} (ctx {} tools {} cont {} ver {} qual {}) {} blocking",
+        format_score(report.overall_score, percent, 2),
+        format_score(scores.context, percent, 2),
+        format_score(scores.tools, percent, 2),
+        format_score(scores.continuity, percent, 2),
+        format_score(scores.verification, percent, 2),
+        format_score(scores.repository_quality, percent, 2),
+        report.blocking_count
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::scoring::ScoreCard;
+
+    #[test]
+    fn summary_is_a_single_line_with_scores_and_blocking_count() {
+        let report = HarnessReport {
+            overall_score: 0.73,
+            category_scores: ScoreCard::new(0.8, 0.9, 0.5, 0.7, 0.6),
+            blocking_count: 1,
+            ..HarnessReport::fixture()
+        };
+
+        let rendered = to_summary(&report);
+        assert_eq!(
+            rendered,
+            "harness: 0.73 (ctx 0.80 tools 0.90 cont 0.50 ver 0.70 qual 0.60) 1 blocking"
+        );
+        assert!(!rendered.contains('\n'));
+    }
+}
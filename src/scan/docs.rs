@@ -1,6 +1,25 @@
-use super::filesystem::{file_exists, read_to_string_if_exists};
+use super::filesystem::FileIndex;
 use super::git_meta::doc_age_days;
-use std::path::Path;
+use super::secrets::SecretMatch;
+use crate::util::tokens::estimate_tokens;
+use std::path::{Path, PathBuf};
+
+pub const CONTEXT_DOC_FILES: [&str; 4] = [
+    "AGENTS.md",
+    "docs/context/INDEX.md",
+    "ARCHITECTURE.md",
+    "docs/ARCHITECTURE.md",
+];
+
+pub const DOC_FILES: [&str; 5] = [
+    "AGENTS.md",
+    "docs/context/INDEX.md",
+    "ARCHITECTURE.md",
+    "docs/ARCHITECTURE.md",
+    "README.md",
+];
+
+pub const ARCHITECTURE_DOC_FILES: [&str; 2] = ["ARCHITECTURE.md", "docs/ARCHITECTURE.md"];
 
 #[derive(Debug, Clone, Default)]
 pub struct DocSignals {
@@ -10,49 +29,81 @@ pub struct DocSignals {
     pub has_architecture_doc: bool,
     pub readme_links_architecture: bool,
     pub docs_age_days: Option<i64>,
+    pub context_token_estimate: usize,
+    pub possible_secrets: Vec<SecretMatch>,
+}
+
+pub fn watched_paths(root: &Path) -> impl Iterator<Item = PathBuf> + '_ {
+    DOC_FILES.iter().map(move |file| root.join(file))
 }
 
-pub fn detect_docs(root: &Path) -> DocSignals {
+pub fn detect_docs(files: &FileIndex, git_metadata: bool) -> DocSignals {
+    let root = files.root();
     let agents_path = root.join("AGENTS.md");
     let context_index_path = root.join("docs/context/INDEX.md");
-    let architecture_path = root.join("ARCHITECTURE.md");
-    let docs_architecture_path = root.join("docs/ARCHITECTURE.md");
     let readme_path = root.join("README.md");
 
-    let agents_content = read_to_string_if_exists(&agents_path).unwrap_or_default();
-    let readme_content = read_to_string_if_exists(&readme_path).unwrap_or_default();
-
-    let has_architecture_doc =
-        file_exists(&architecture_path) || file_exists(&docs_architecture_path);
-    let docs_age_days = doc_age_days(
-        root,
-        &[
-            "AGENTS.md",
-            "docs/context/INDEX.md",
-            "ARCHITECTURE.md",
-            "docs/ARCHITECTURE.md",
-            "README.md",
-        ],
-    );
+    let agents_content = files.read_to_string(&agents_path).unwrap_or_default();
+    let readme_content = files.read_to_string(&readme_path).unwrap_or_default();
+
+    let has_architecture_doc = ARCHITECTURE_DOC_FILES
+        .iter()
+        .any(|file| files.exists(&root.join(file)));
+    let docs_age_days = if git_metadata {
+        doc_age_days(root, &DOC_FILES)
+    } else {
+        None
+    };
 
     DocSignals {
-        has_agents_md: file_exists(&agents_path),
+        has_agents_md: files.exists(&agents_path),
         agents_has_section_header: agents_content
             .lines()
             .any(|line| line.trim_start().starts_with('#')),
-        has_context_index: file_exists(&context_index_path),
+        has_context_index: files.exists(&context_index_path),
         has_architecture_doc,
         readme_links_architecture: readme_content.to_lowercase().contains("architecture"),
         docs_age_days,
+        context_token_estimate: context_token_estimate(files),
+        possible_secrets: Vec::new(),
     }
 }
 
+fn context_token_estimate(files: &FileIndex) -> usize {
+    indexed_context_file_tokens(files)
+        .iter()
+        .map(|(_, tokens)| tokens)
+        .sum()
+}
+
+pub fn context_file_tokens(root: &Path) -> Vec<(&'static str, usize)> {
+    indexed_context_file_tokens(&FileIndex::new(root, []))
+}
+
+fn indexed_context_file_tokens(files: &FileIndex) -> Vec<(&'static str, usize)> {
+    CONTEXT_DOC_FILES
+        .iter()
+        .filter_map(|file| {
+            files
+                .read_to_string(&files.root().join(file))
+                .map(|content| (*file, estimate_tokens(&content)))
+        })
+        .collect()
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::scan::filesystem::walk_entries;
     use std::fs;
     use tempfile::TempDir;
 
+    fn indexed(root: &Path) -> FileIndex {
+        let mut files = FileIndex::new(root, watched_paths(root));
+        walk_entries(root, 100, |entry| files.record(entry)).expect("walk should succeed");
+        files
+    }
+
     #[test]
     fn detect_docs_picks_up_core_files() {
         let dir = TempDir::new().expect("temp dir should be created");
@@ -66,11 +117,22 @@ mod tests {
         fs::write(dir.path().join("ARCHITECTURE.md"), "# Architecture").expect("arch should write");
         fs::write(dir.path().join("docs/context/INDEX.md"), "index").expect("index should write");
 
-        let signals = detect_docs(dir.path());
+        let signals = detect_docs(&indexed(dir.path()), true);
         assert!(signals.has_agents_md);
         assert!(signals.agents_has_section_header);
         assert!(signals.has_context_index);
         assert!(signals.has_architecture_doc);
         assert!(signals.readme_links_architecture);
+        assert_eq!(signals.context_token_estimate, 3 + 2 + 4);
+    }
+
+    #[test]
+    fn detect_docs_skips_doc_age_without_git_metadata() {
+        let dir = TempDir::new().expect("temp dir should be created");
+        fs::write(dir.path().join("AGENTS.md"), "# Agents").expect("agents file should write");
+
+        let signals = detect_docs(&indexed(dir.path()), false);
+        assert!(signals.has_agents_md);
+        assert_eq!(signals.docs_age_days, None);
     }
 }
@@ -1,13 +1,108 @@
-use std::path::{Path, PathBuf};
-use walkdir::WalkDir;
+use crate::error::{HarnessError, Result};
+use std::collections::BTreeSet;
+use std::path::{Component, Path, PathBuf};
+use walkdir::{DirEntry, WalkDir};
 
-pub fn list_files(root: &Path) -> Vec<PathBuf> {
-    WalkDir::new(root)
+pub const DEFAULT_MAX_FILES: usize = 200_000;
+
+#[allow(dead_code)]
+pub fn list_files(root: &Path, max_files: usize) -> Result<Vec<PathBuf>> {
+    let mut files = Vec::new();
+    walk_entries(root, max_files, |entry| {
+        if entry.file_type().is_file() {
+            files.push(entry.path().to_path_buf());
+        }
+    })?;
+    Ok(files)
+}
+
+pub fn walk_entries(
+    root: &Path,
+    max_files: usize,
+    mut visit: impl FnMut(&DirEntry),
+) -> Result<usize> {
+    let mut count = 0;
+    for entry in WalkDir::new(root)
         .into_iter()
         .filter_map(|entry| entry.ok())
-        .filter(|entry| entry.file_type().is_file())
-        .map(|entry| entry.path().to_path_buf())
-        .collect()
+    {
+        if entry.file_type().is_file() {
+            if count >= max_files {
+                return Err(HarnessError::ScanLimitExceeded(format!(
+                    "more than {max_files} files under {}; point harness at a repository root or raise [scan] max_files / --max-files",
+                    root.display()
+                )));
+            }
+            count += 1;
+        }
+        visit(&entry);
+    }
+    Ok(count)
+}
+
+#[derive(Debug, Clone)]
+pub struct FileIndex {
+    root: PathBuf,
+    watched: BTreeSet<PathBuf>,
+    present: BTreeSet<PathBuf>,
+}
+
+impl FileIndex {
+    pub fn new(root: &Path, watched: impl IntoIterator<Item = PathBuf>) -> Self {
+        Self {
+            root: root.to_path_buf(),
+            watched: watched
+                .into_iter()
+                .filter_map(|path| index_key(root, &path))
+                .collect(),
+            present: BTreeSet::new(),
+        }
+    }
+
+    pub fn root(&self) -> &Path {
+        &self.root
+    }
+
+    pub fn record(&mut self, entry: &DirEntry) {
+        let Some(key) = index_key(&self.root, entry.path()) else {
+            return;
+        };
+        if !self.watched.contains(&key) {
+            return;
+        }
+        if entry.path_is_symlink() && !entry.path().exists() {
+            return;
+        }
+        self.present.insert(key);
+    }
+
+    pub fn exists(&self, path: &Path) -> bool {
+        match index_key(&self.root, path) {
+            Some(key) if self.watched.contains(&key) => self.present.contains(&key),
+            _ => file_exists(path),
+        }
+    }
+
+    pub fn read_to_string(&self, path: &Path) -> Option<String> {
+        if self.exists(path) {
+            read_to_string_if_exists(path)
+        } else {
+            None
+        }
+    }
+}
+
+fn index_key(root: &Path, path: &Path) -> Option<PathBuf> {
+    let relative = path.strip_prefix(root).ok()?;
+    let mut key = PathBuf::new();
+    for component in relative.components() {
+        match component {
+            Component::Normal(name) => key.push(name),
+            Component::CurDir => {}
+            _ => return None,
+        }
+    }
+    (!key.as_os_str().is_empty()).then_some(key)
 }
 
 pub fn read_to_string_if_exists(path: &Path) -> Option<String> {
@@ -17,3 +112,105 @@ pub fn read_to_string_if_exists(path: &Path) -> Option<String> {
 pub fn file_exists(path: &Path) -> bool {
     path.exists()
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::fs;
+    use tempfile::TempDir;
+
+    #[test]
+    fn list_files_returns_all_files_within_limit() {
+        let dir = TempDir::new().expect("temp dir should be created");
+        fs::create_dir_all(dir.path().join("src")).expect("src dir should be created");
+        fs::write(dir.path().join("a.txt"), "a").expect("file should write");
+        fs::write(dir.path().join("src/b.rs"), "b").expect("file should write");
+
+        let mut files = list_files(dir.path(), 2).expect("listing should succeed");
+        files.sort();
+        assert_eq!(
+            files,
+            vec![dir.path().join("a.txt"), dir.path().join("src/b.rs")]
+        );
+    }
+
+    #[test]
+    fn walk_entries_accepts_a_tree_at_the_limit() {
+        let dir = TempDir::new().expect("temp dir should be created");
+        fs::write(dir.path().join("a.txt"), "a").expect("file should write");
+        fs::write(dir.path().join("b.txt"), "b").expect("file should write");
+
+        let count = walk_entries(dir.path(), 2, |_| {}).expect("walk should succeed");
+        assert_eq!(count, 2);
+    }
+
+    #[test]
+    fn walk_entries_streams_each_file_and_returns_the_count() {
+        let dir = TempDir::new().expect("temp dir should be created");
+        fs::create_dir_all(dir.path().join("src")).expect("src dir should be created");
+        fs::write(dir.path().join("a.txt"), "a").expect("file should write");
+        fs::write(dir.path().join("src/b.rs"), "b").expect("file should write");
+
+        let mut names = Vec::new();
+        let count = walk_entries(dir.path(), 10, |entry| {
+            if entry.file_type().is_file() {
+                names.push(entry.file_name().to_string_lossy().to_string());
+            }
+        })
+        .expect("walk should succeed");
+        names.sort();
+        assert_eq!(count, 2);
+        assert_eq!(names, vec!["a.txt", "b.rs"]);
+    }
+
+    #[test]
+    fn file_index_answers_watched_paths_from_the_walk() {
+        let dir = TempDir::new().expect("temp dir should be created");
+        fs::write(dir.path().join("AGENTS.md"), "# Agents").expect("file should write");
+        let outside = TempDir::new().expect("temp dir should be created");
+        fs::write(outside.path().join("progress.md"), "summary").expect("file should write");
+
+        let mut index = FileIndex::new(
+            dir.path(),
+            [dir.path().join("AGENTS.md"), dir.path().join("./README.md")],
+        );
+        walk_entries(dir.path(), 10, |entry| index.record(entry)).expect("walk should succeed");
+
+        assert!(index.exists(&dir.path().join("AGENTS.md")));
+        assert!(!index.exists(&dir.path().join("README.md")));
+        assert_eq!(index.read_to_string(&dir.path().join("README.md")), None);
+        assert!(index.exists(&outside.path().join("progress.md")));
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn file_index_follows_symlinks_like_file_exists() {
+        let dir = TempDir::new().expect("temp dir should be created");
+        fs::write(dir.path().join("CLAUDE.md"), "# Agents").expect("file should write");
+        std::os::unix::fs::symlink("CLAUDE.md", dir.path().join("AGENTS.md"))
+            .expect("symlink should create");
+        std::os::unix::fs::symlink("missing.md", dir.path().join("README.md"))
+            .expect("symlink should create");
+
+        let mut index = FileIndex::new(
+            dir.path(),
+            [dir.path().join("AGENTS.md"), dir.path().join("README.md")],
+        );
+        walk_entries(dir.path(), 10, |entry| index.record(entry)).expect("walk should succeed");
+
+        assert!(index.exists(&dir.path().join("AGENTS.md")));
+        assert!(!index.exists(&dir.path().join("README.md")));
+    }
+
+    #[test]
+    fn walk_entries_aborts_when_limit_is_exceeded() {
+        let dir = TempDir::new().expect("temp dir should be created");
+        for name in ["a.txt", "b.txt", "c.txt"] {
+            fs::write(dir.path().join(name), name).expect("file should write");
+        }
+
+        let err = walk_entries(dir.path(), 2, |_| {}).expect_err("walk should fail");
+        assert!(matches!(err, HarnessError::ScanLimitExceeded(_)));
+        assert!(err.to_string().contains("more than 2 files"));
+    }
+}
@@ -1,12 +1,22 @@
 pub mod docs;
 pub mod filesystem;
 pub mod git_meta;
+pub mod remote;
+pub mod remote_template;
+pub mod secrets;
 pub mod tools;
 
-use crate::types::config::HarnessConfig;
+use crate::continuity;
+use crate::error::Result;
+use crate::types::config::{HarnessConfig, DEFAULT_MAX_FILE_LINES, DEFAULT_MIN_PROMPT_CHARS};
+use crate::types::report::{DirectoryFileCount, ScanStats};
+use crate::util::parallel::map_ordered;
+use crate::util::path::repo_relative_uri;
 use docs::DocSignals;
-use filesystem::{file_exists, list_files, read_to_string_if_exists};
-use std::path::{Path, PathBuf};
+use filesystem::{walk_entries, FileIndex, DEFAULT_MAX_FILES};
+use std::collections::BTreeMap;
+use std::io::{BufRead, BufReader};
+use std::path::{Component, Path, PathBuf};
 use tools::ToolSignals;
 
 #[derive(Debug, Clone, Default)]
@@ -16,6 +26,8 @@ pub struct ContinuitySignals {
     pub has_progress_file: bool,
     pub has_feature_state_file: bool,
     pub has_progress_summary: bool,
+    pub feature_state_file: String,
+    pub feature_state_issue: Option<String>,
 }
 
 #[derive(Debug, Clone, Default)]
@@ -23,96 +35,389 @@ pub struct QualitySignals {
     pub has_ci_workflow: bool,
     pub has_tests: bool,
     pub has_lint_config: bool,
+    pub large_files: Vec<LargeFile>,
+    pub harness_artifacts_unignored: bool,
 }
 
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LargeFile {
+    pub path: String,
+    pub lines: usize,
+    /// `lines` is the counting cap; the file is at least that long.
+    pub capped: bool,
+}
+
+const MAX_LINE_COUNTED_FILES: usize = 5_000;
+const LINE_COUNT_CAP_FACTOR: usize = 10;
+const LINT_CONFIG_FILES: [&str; 3] = ["rustfmt.toml", ".clippy.toml", "clippy.toml"];
+const CI_WORKFLOW_DIR: &str = ".github/workflows";
+
 #[derive(Debug, Clone)]
 pub struct RepoModel {
     #[allow(dead_code)]
     pub root: PathBuf,
     pub file_count: usize,
+    pub directory_file_counts: BTreeMap<String, usize>,
     pub docs: DocSignals,
     pub tools: ToolSignals,
     pub continuity: ContinuitySignals,
     pub quality: QualitySignals,
 }
 
-pub fn discover(root: &Path, config: Option<&HarnessConfig>) -> RepoModel {
-    let files = list_files(root);
-    let docs = docs::detect_docs(root);
+impl RepoModel {
+    pub fn scan_stats(&self, limit: usize) -> ScanStats {
+        let mut top_directories = self
+            .directory_file_counts
+            .iter()
+            .map(|(path, file_count)| DirectoryFileCount {
+                path: path.clone(),
+                file_count: *file_count,
+            })
+            .collect::<Vec<_>>();
+        top_directories.sort_by(|a, b| {
+            b.file_count
+                .cmp(&a.file_count)
+                .then_with(|| a.path.cmp(&b.path))
+        });
+        top_directories.truncate(limit);
+
+        ScanStats {
+            file_count: self.file_count,
+            top_directories,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct ScanOptions {
+    pub max_files: usize,
+    pub max_file_lines: usize,
+    pub git_metadata: bool,
+    pub threads: usize,
+    pub secret_check: bool,
+}
+
+impl Default for ScanOptions {
+    fn default() -> Self {
+        Self {
+            max_files: DEFAULT_MAX_FILES,
+            max_file_lines: DEFAULT_MAX_FILE_LINES,
+            git_metadata: true,
+            threads: crate::util::parallel::default_threads(),
+            secret_check: false,
+        }
+    }
+}
+
+impl ScanOptions {
+    pub fn resolve(config: Option<&HarnessConfig>, max_files_override: Option<usize>) -> Self {
+        let defaults = Self::default();
+        Self {
+            max_files: max_files_override
+                .or_else(|| config.and_then(HarnessConfig::scan_max_files))
+                .unwrap_or(defaults.max_files),
+            max_file_lines: config.map_or(defaults.max_file_lines, HarnessConfig::max_file_lines),
+            secret_check: config.is_some_and(HarnessConfig::secret_check),
+            ..defaults
+        }
+    }
+
+    pub fn with_git_metadata(self, git_metadata: bool) -> Self {
+        Self {
+            git_metadata,
+            ..self
+        }
+    }
+
+    pub fn with_threads(self, threads: usize) -> Self {
+        Self { threads, ..self }
+    }
+}
+
+pub fn discover(
+    root: &Path,
+    config: Option<&HarnessConfig>,
+    options: &ScanOptions,
+) -> Result<RepoModel> {
+    let continuity_paths = ContinuityPaths::resolve(root, config);
+    let watched = docs::watched_paths(root)
+        .chain(continuity_paths.all().map(Path::to_path_buf))
+        .chain(LINT_CONFIG_FILES.iter().map(|file| root.join(file)))
+        .chain(std::iter::once(root.join(CI_WORKFLOW_DIR)));
+    let mut files = FileIndex::new(root, watched);
+    let mut tally = FileTally::default();
+    let file_count = walk_entries(root, options.max_files, |entry| {
+        files.record(entry);
+        if entry.file_type().is_file() {
+            tally.visit(root, entry.path());
+        }
+    })?;
+    let mut docs = docs::detect_docs(&files, options.git_metadata);
+    if options.secret_check {
+        docs.possible_secrets = secrets::detect_secrets(&files);
+    }
     let tools = tools::detect_tools(config);
-    let continuity = detect_continuity(root, config);
-    let quality = detect_quality(root, &files);
+    let continuity = detect_continuity(&files, &continuity_paths, config);
+    let quality = detect_quality(&files, &tally, options);
 
-    RepoModel {
+    Ok(RepoModel {
         root: root.to_path_buf(),
-        file_count: files.len(),
+        file_count,
+        directory_file_counts: tally.directory_file_counts,
         docs,
         tools,
         continuity,
         quality,
+    })
+}
+
+#[derive(Debug, Default)]
+struct FileTally {
+    directory_file_counts: BTreeMap<String, usize>,
+    has_workflow_file: bool,
+    has_tests: bool,
+    line_count_candidates: Vec<PathBuf>,
+}
+
+impl FileTally {
+    fn visit(&mut self, root: &Path, path: &Path) {
+        let relative = path.strip_prefix(root).unwrap_or(path);
+        *self
+            .directory_file_counts
+            .entry(top_directory(relative))
+            .or_insert(0) += 1;
+
+        let display = path.to_string_lossy();
+        self.has_workflow_file |= display.contains(".github/workflows/");
+        if !self.has_tests {
+            let name = path
+                .file_name()
+                .and_then(|file| file.to_str())
+                .unwrap_or_default();
+            self.has_tests = name.ends_with("_test.rs")
+                || name.ends_with("_spec.rs")
+                || display.contains("/tests/");
+        }
+
+        if self.line_count_candidates.len() < MAX_LINE_COUNTED_FILES && !is_hidden_path(relative) {
+            self.line_count_candidates.push(path.to_path_buf());
+        }
     }
 }
 
-fn detect_continuity(root: &Path, config: Option<&HarnessConfig>) -> ContinuitySignals {
-    let initializer = config
-        .and_then(|cfg| cfg.continuity.as_ref())
-        .and_then(|continuity| continuity.initializer.as_ref())
-        .map(|path| root.join(path))
-        .unwrap_or_else(|| root.join(".harness/initializer.prompt.md"));
+fn top_directory(relative: &Path) -> String {
+    let mut components = relative.components();
+    let first = components.next();
+    match (first, components.next()) {
+        (Some(Component::Normal(name)), Some(_)) => name.to_string_lossy().to_string(),
+        _ => ".".to_string(),
+    }
+}
 
-    let coding_prompt = config
-        .and_then(|cfg| cfg.continuity.as_ref())
-        .and_then(|continuity| continuity.coding_prompt.as_ref())
-        .map(|path| root.join(path))
-        .unwrap_or_else(|| root.join(".harness/coding.prompt.md"));
+struct ContinuityPaths {
+    initializer: PathBuf,
+    coding_prompt: PathBuf,
+    progress_file: PathBuf,
+    feature_state: PathBuf,
+}
 
-    let progress_file = config
-        .and_then(|cfg| cfg.continuity.as_ref())
-        .and_then(|continuity| continuity.progress_file.as_ref())
-        .map(|path| root.join(path))
-        .unwrap_or_else(|| root.join(".harness/progress.md"));
+impl ContinuityPaths {
+    fn resolve(root: &Path, config: Option<&HarnessConfig>) -> Self {
+        let initializer = config
+            .and_then(|cfg| cfg.continuity.as_ref())
+            .and_then(|continuity| continuity.initializer.as_ref())
+            .map(|path| root.join(path))
+            .unwrap_or_else(|| root.join(".harness/initializer.prompt.md"));
 
-    let feature_state = config
-        .and_then(|cfg| cfg.continuity.as_ref())
-        .and_then(|continuity| continuity.feature_state_file.as_ref())
-        .map(|path| root.join(path))
-        .unwrap_or_else(|| root.join(".harness/feature_list.json"));
+        let coding_prompt = config
+            .and_then(|cfg| cfg.continuity.as_ref())
+            .and_then(|continuity| continuity.coding_prompt.as_ref())
+            .map(|path| root.join(path))
+            .unwrap_or_else(|| root.join(".harness/coding.prompt.md"));
+
+        let progress_file = continuity::progress_file(root, config);
 
-    let progress_content = read_to_string_if_exists(&progress_file).unwrap_or_default();
+        let feature_state = config
+            .and_then(|cfg| cfg.continuity.as_ref())
+            .and_then(|continuity| continuity.feature_state_file.as_ref())
+            .map(|path| root.join(path))
+            .unwrap_or_else(|| root.join(".harness/feature_list.json"));
+
+        Self {
+            initializer,
+            coding_prompt,
+            progress_file,
+            feature_state,
+        }
+    }
+
+    fn all(&self) -> impl Iterator<Item = &Path> {
+        [
+            &self.initializer,
+            &self.coding_prompt,
+            &self.progress_file,
+            &self.feature_state,
+        ]
+        .into_iter()
+        .map(PathBuf::as_path)
+    }
+}
+
+fn detect_continuity(
+    files: &FileIndex,
+    paths: &ContinuityPaths,
+    config: Option<&HarnessConfig>,
+) -> ContinuitySignals {
+    let progress_content = files
+        .read_to_string(&paths.progress_file)
+        .unwrap_or_default();
+    let min_prompt_chars = config.map_or(DEFAULT_MIN_PROMPT_CHARS, HarnessConfig::min_prompt_chars);
+
+    let expected_schema_version = config
+        .and_then(|cfg| cfg.continuity.as_ref())
+        .and_then(|continuity| continuity.state_schema_version);
+    let feature_state_issue = files
+        .read_to_string(&paths.feature_state)
+        .and_then(|content| feature_state_issue(&content, expected_schema_version));
 
     ContinuitySignals {
-        has_initializer_prompt: file_exists(&initializer),
-        has_coding_prompt: file_exists(&coding_prompt),
-        has_progress_file: file_exists(&progress_file),
-        has_feature_state_file: file_exists(&feature_state),
+        has_initializer_prompt: has_substantive_content(
+            files,
+            &paths.initializer,
+            min_prompt_chars,
+        ),
+        has_coding_prompt: has_substantive_content(files, &paths.coding_prompt, min_prompt_chars),
+        has_progress_file: files.exists(&paths.progress_file),
+        has_feature_state_file: files.exists(&paths.feature_state),
         has_progress_summary: progress_content.to_lowercase().contains("summary"),
+        feature_state_file: repo_relative_uri(
+            &paths
+                .feature_state
+                .strip_prefix(files.root())
+                .unwrap_or(&paths.feature_state)
+                .display()
+                .to_string(),
+        ),
+        feature_state_issue,
     }
 }
 
-fn detect_quality(root: &Path, files: &[PathBuf]) -> QualitySignals {
-    let has_ci_workflow = file_exists(&root.join(".github/workflows"))
-        && files
-            .iter()
-            .any(|path| path.to_string_lossy().contains(".github/workflows/"));
-
-    let has_tests = files.iter().any(|path| {
-        let name = path
-            .file_name()
-            .and_then(|file| file.to_str())
-            .unwrap_or_default();
-        name.ends_with("_test.rs")
-            || name.ends_with("_spec.rs")
-            || path.to_string_lossy().contains("/tests/")
-    });
-
-    let has_lint_config = file_exists(&root.join("rustfmt.toml"))
-        || file_exists(&root.join(".clippy.toml"))
-        || file_exists(&root.join("clippy.toml"));
+fn feature_state_issue(content: &str, expected_schema_version: Option<u32>) -> Option<String> {
+    let value: serde_json::Value = match serde_json::from_str(content) {
+        Ok(value) => value,
+        Err(err) => return Some(format!("not valid JSON: {err}")),
+    };
+    if !value
+        .get("features")
+        .is_some_and(serde_json::Value::is_array)
+    {
+        return Some("expected a top-level object with a \"features\" array".to_string());
+    }
+    let expected = expected_schema_version?;
+    match value
+        .get("schema_version")
+        .and_then(serde_json::Value::as_u64)
+    {
+        Some(found) if found == u64::from(expected) => None,
+        Some(found) => Some(format!(
+            "schema_version {found} does not match continuity.state_schema_version {expected}"
+        )),
+        None => Some(format!(
+            "missing schema_version; continuity.state_schema_version expects {expected}"
+        )),
+    }
+}
+
+fn has_substantive_content(files: &FileIndex, path: &Path, min_chars: usize) -> bool {
+    files
+        .read_to_string(path)
+        .is_some_and(|content| content.chars().filter(|c| !c.is_whitespace()).count() >= min_chars)
+}
+
+fn detect_quality(files: &FileIndex, tally: &FileTally, options: &ScanOptions) -> QualitySignals {
+    let root = files.root();
+    let has_ci_workflow = files.exists(&root.join(CI_WORKFLOW_DIR)) && tally.has_workflow_file;
+
+    let has_lint_config = LINT_CONFIG_FILES
+        .iter()
+        .any(|file| files.exists(&root.join(file)));
 
     QualitySignals {
         has_ci_workflow,
-        has_tests,
+        has_tests: tally.has_tests,
         has_lint_config,
+        large_files: detect_large_files(
+            root,
+            &tally.line_count_candidates,
+            options.max_file_lines,
+            options.threads,
+        ),
+        harness_artifacts_unignored: filesystem::read_to_string_if_exists(&root.join(".gitignore"))
+            .is_some_and(|content| !ignores_harness_artifacts(&content)),
+    }
+}
+
+pub fn ignores_harness_artifacts(gitignore: &str) -> bool {
+    gitignore.lines().map(str::trim).any(|line| {
+        matches!(
+            line.trim_start_matches('/'),
+            ".harness" | ".harness/" | ".harness/*" | ".harness/**"
+        )
+    })
+}
+
+fn is_hidden_path(relative: &Path) -> bool {
+    relative.components().any(|component| {
+        matches!(component, Component::Normal(name) if name.to_string_lossy().starts_with('.'))
+    })
+}
+
+fn detect_large_files(
+    root: &Path,
+    files: &[PathBuf],
+    max_file_lines: usize,
+    threads: usize,
+) -> Vec<LargeFile> {
+    let cap = max_file_lines.saturating_mul(LINE_COUNT_CAP_FACTOR);
+    let mut large_files = map_ordered(files, threads, |path| {
+        let lines = count_lines_capped(path, cap)?;
+        (lines > max_file_lines).then(|| LargeFile {
+            path: crate::util::path::repo_relative_uri(
+                &path.strip_prefix(root).unwrap_or(path).to_string_lossy(),
+            ),
+            lines: lines.min(cap),
+            capped: lines > cap,
+        })
+    })
+    .into_iter()
+    .flatten()
+    .collect::<Vec<_>>();
+    large_files.sort_by(|a, b| b.lines.cmp(&a.lines).then_with(|| a.path.cmp(&b.path)));
+    large_files
+}
+
+// Streams the file and stops once the count passes `cap`, so a huge file costs at most
+// `cap` lines of reading. Binary (NUL in the first chunk) and unreadable files yield `None`.
+fn count_lines_capped(path: &Path, cap: usize) -> Option<usize> {
+    let file = std::fs::File::open(path).ok()?;
+    let mut reader = BufReader::new(file);
+    let mut lines = 0;
+    let mut first_chunk = true;
+    loop {
+        let chunk = reader.fill_buf().ok()?;
+        if chunk.is_empty() {
+            return Some(lines);
+        }
+        if first_chunk && chunk.contains(&0) {
+            return None;
+        }
+        first_chunk = false;
+        lines += chunk.iter().filter(|byte| **byte == b'\n').count();
+        if lines > cap {
+            return Some(lines);
+        }
+        let consumed = chunk.len();
+        reader.consume(consumed);
     }
 }
 
@@ -131,10 +436,14 @@ mod tests {
         fs::create_dir_all(dir.path().join("tests")).expect("tests dir should be created");
         fs::write(
             dir.path().join(".harness/initializer.prompt.md"),
-            "initializer",
+            "Set up the environment and record the feature list before coding.",
         )
         .expect("initializer should write");
-        fs::write(dir.path().join(".harness/coding.prompt.md"), "coding").expect("coding write");
+        fs::write(
+            dir.path().join(".harness/coding.prompt.md"),
+            "Pick one failing feature, implement it, and update progress.",
+        )
+        .expect("coding write");
         fs::write(
             dir.path().join(".harness/progress.md"),
             "summary: checkpoint",
@@ -150,10 +459,223 @@ mod tests {
         fs::write(dir.path().join("rustfmt.toml"), "edition = \"2021\"")
             .expect("lint config should write");
 
-        let model = discover(dir.path(), None);
+        let model =
+            discover(dir.path(), None, &ScanOptions::default()).expect("discover should succeed");
         assert!(model.continuity.has_initializer_prompt);
         assert!(model.quality.has_ci_workflow);
         assert!(model.quality.has_tests);
         assert!(model.quality.has_lint_config);
     }
+
+    #[test]
+    fn discover_ignores_placeholder_prompt_files() {
+        let dir = TempDir::new().expect("temp dir should be created");
+        fs::create_dir_all(dir.path().join(".harness")).expect("harness dir should be created");
+        fs::write(
+            dir.path().join(".harness/initializer.prompt.md"),
+            "  \n\t\n",
+        )
+        .expect("initializer should write");
+        fs::write(dir.path().join(".harness/coding.prompt.md"), "TODO").expect("coding write");
+
+        let model =
+            discover(dir.path(), None, &ScanOptions::default()).expect("discover should succeed");
+        assert!(!model.continuity.has_initializer_prompt);
+        assert!(!model.continuity.has_coding_prompt);
+
+        let config: HarnessConfig = toml::from_str(
+            r#"
+[project]
+name = "sample"
+
+[continuity]
+min_prompt_chars = 4
+"#,
+        )
+        .expect("config should parse");
+        let model = discover(dir.path(), Some(&config), &ScanOptions::default())
+            .expect("discover should succeed");
+        assert!(!model.continuity.has_initializer_prompt);
+        assert!(model.continuity.has_coding_prompt);
+    }
+
+    #[test]
+    fn count_lines_capped_stops_past_cap_and_skips_binary_files() {
+        let dir = TempDir::new().expect("temp dir should be created");
+        let long = dir.path().join("long.txt");
+        fs::write(&long, "line\n".repeat(100_000)).expect("long file should write");
+        let binary = dir.path().join("blob.bin");
+        fs::write(&binary, [b"\0".as_slice(), &[b'\n'; 50]].concat()).expect("blob should write");
+        let exact = dir.path().join("exact.txt");
+        fs::write(&exact, "a\nb\n").expect("exact file should write");
+
+        let counted = count_lines_capped(&long, 10).expect("text file should count");
+        assert!(counted > 10 && counted < 100_000);
+        assert_eq!(count_lines_capped(&binary, 10), None);
+        assert_eq!(count_lines_capped(&exact, 2), Some(2));
+        assert_eq!(count_lines_capped(&dir.path().join("missing.txt"), 0), None);
+    }
+
+    #[test]
+    fn discover_records_large_files_and_skips_hidden_paths() {
+        let dir = TempDir::new().expect("temp dir should be created");
+        fs::create_dir_all(dir.path().join("src")).expect("src dir should be created");
+        fs::create_dir_all(dir.path().join(".git")).expect("git dir should be created");
+        fs::write(dir.path().join("src/big.rs"), "x\n".repeat(12)).expect("big file should write");
+        fs::write(dir.path().join("huge.md"), "y\n".repeat(20)).expect("huge file should write");
+        fs::write(dir.path().join("small.rs"), "z\n".repeat(10)).expect("small should write");
+        fs::write(dir.path().join(".git/packed"), "p\n".repeat(50)).expect("git file should write");
+
+        let options = ScanOptions {
+            max_file_lines: 10,
+            ..ScanOptions::default()
+        };
+        let model = discover(dir.path(), None, &options).expect("discover should succeed");
+        assert_eq!(
+            model.quality.large_files,
+            vec![
+                LargeFile {
+                    path: "huge.md".to_string(),
+                    lines: 20,
+                    capped: false,
+                },
+                LargeFile {
+                    path: "src/big.rs".to_string(),
+                    lines: 12,
+                    capped: false,
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn large_files_are_ranked_by_capped_line_count() {
+        let dir = TempDir::new().expect("temp dir should be created");
+        fs::write(dir.path().join("a.rs"), "a\n".repeat(12)).expect("a should write");
+        fs::write(dir.path().join("m.rs"), "m\n".repeat(500)).expect("m should write");
+        fs::write(dir.path().join("z.rs"), "z\n".repeat(30)).expect("z should write");
+
+        let options = ScanOptions {
+            max_file_lines: 10,
+            ..ScanOptions::default()
+        };
+        let model = discover(dir.path(), None, &options).expect("discover should succeed");
+        let ranked = model
+            .quality
+            .large_files
+            .iter()
+            .map(|file| (file.path.as_str(), file.lines, file.capped))
+            .collect::<Vec<_>>();
+        assert_eq!(
+            ranked,
+            vec![
+                ("m.rs", 100, true),
+                ("z.rs", 30, false),
+                ("a.rs", 12, false)
+            ]
+        );
+    }
+
+    #[test]
+    fn discover_flags_gitignore_without_harness_entry() {
+        let dir = TempDir::new().expect("temp dir should be created");
+        let model =
+            discover(dir.path(), None, &ScanOptions::default()).expect("discover should succeed");
+        assert!(!model.quality.harness_artifacts_unignored);
+
+        fs::write(dir.path().join(".gitignore"), "target/\n").expect("gitignore should write");
+        let model =
+            discover(dir.path(), None, &ScanOptions::default()).expect("discover should succeed");
+        assert!(model.quality.harness_artifacts_unignored);
+
+        fs::write(dir.path().join(".gitignore"), "target/\n/.harness/\n")
+            .expect("gitignore should write");
+        let model =
+            discover(dir.path(), None, &ScanOptions::default()).expect("discover should succeed");
+        assert!(!model.quality.harness_artifacts_unignored);
+    }
+
+    #[test]
+    fn gitignore_entries_for_harness_dir_are_recognized() {
+        for entry in [
+            ".harness",
+            ".harness/",
+            "/.harness",
+            "/.harness/*",
+            "  .harness/**  ",
+        ] {
+            assert!(
+                ignores_harness_artifacts(entry),
+                "{entry} should ignore artifacts"
+            );
+        }
+        for entry in ["# .harness/", "!.harness/", ".harness/plans/", "harness/"] {
+            assert!(
+                !ignores_harness_artifacts(entry),
+                "{entry} should not count"
+            );
+        }
+    }
+
+    #[test]
+    fn feature_state_issue_checks_shape_and_schema_version() {
+        assert_eq!(feature_state_issue(r#"{"features": []}"#, None), None);
+        assert!(feature_state_issue("{not json", None)
+            .is_some_and(|issue| issue.starts_with("not valid JSON")));
+        assert!(feature_state_issue(r#"["a"]"#, None)
+            .is_some_and(|issue| issue.contains("\"features\" array")));
+        assert_eq!(
+            feature_state_issue(r#"{"schema_version": 2, "features": []}"#, Some(2)),
+            None
+        );
+        assert!(
+            feature_state_issue(r#"{"schema_version": 1, "features": []}"#, Some(2))
+                .is_some_and(|issue| issue.contains("does not match"))
+        );
+        assert!(feature_state_issue(r#"{"features": []}"#, Some(2))
+            .is_some_and(|issue| issue.starts_with("missing schema_version")));
+    }
+
+    #[test]
+    fn scan_stats_rank_top_directories_by_file_count() {
+        let dir = TempDir::new().expect("temp dir should be created");
+        fs::create_dir_all(dir.path().join("src/nested")).expect("src dir should be created");
+        fs::create_dir_all(dir.path().join("docs")).expect("docs dir should be created");
+        fs::write(dir.path().join("README.md"), "readme").expect("readme should write");
+        fs::write(dir.path().join("src/main.rs"), "fn main() {}").expect("main should write");
+        fs::write(dir.path().join("src/nested/lib.rs"), "").expect("lib should write");
+        fs::write(dir.path().join("docs/guide.md"), "guide").expect("guide should write");
+
+        let model =
+            discover(dir.path(), None, &ScanOptions::default()).expect("discover should succeed");
+        let stats = model.scan_stats(2);
+        assert_eq!(stats.file_count, 4);
+        let ranked = stats
+            .top_directories
+            .iter()
+            .map(|entry| (entry.path.as_str(), entry.file_count))
+            .collect::<Vec<_>>();
+        assert_eq!(ranked, vec![("src", 2), (".", 1)]);
+    }
+
+    #[test]
+    fn scan_options_prefer_override_over_config() {
+        let config: HarnessConfig = toml::from_str(
+            r#"
+[project]
+name = "sample"
+
+[scan]
+max_files = 50
+"#,
+        )
+        .expect("config should parse");
+
+        assert_eq!(
+            ScanOptions::resolve(None, None).max_files,
+            DEFAULT_MAX_FILES
+        );
+        assert_eq!(ScanOptions::resolve(Some(&config), None).max_files, 50);
+        assert_eq!(ScanOptions::resolve(Some(&config), Some(5)).max_files, 5);
+    }
 }
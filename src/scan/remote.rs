@@ -0,0 +1,101 @@
+use crate::error::{HarnessError, Result};
+use crate::util::git::{spawn_error, GIT_PROGRAM};
+use std::path::{Path, PathBuf};
+use std::process::Command;
+use tempfile::TempDir;
+
+pub struct RemoteCheckout {
+    path: PathBuf,
+    dir: Option<TempDir>,
+}
+
+impl RemoteCheckout {
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    pub fn is_kept(&self) -> bool {
+        self.dir.is_none()
+    }
+}
+
+pub fn is_remote_url(path: &Path) -> bool {
+    let Some(raw) = path.to_str() else {
+        return false;
+    };
+    if ["https://", "http://", "ssh://", "git://", "file://"]
+        .iter()
+        .any(|scheme| raw.starts_with(scheme))
+    {
+        return true;
+    }
+
+    // scp-style `user@host:org/repo.git`
+    match (raw.find('@'), raw.find(':'), raw.find('/')) {
+        (Some(at), Some(colon), slash) => at < colon && slash.is_none_or(|slash| colon < slash),
+        _ => false,
+    }
+}
+
+pub fn shallow_clone(url: &str, keep: bool) -> Result<RemoteCheckout> {
+    let dir = tempfile::Builder::new()
+        .prefix("harness-clone-")
+        .tempdir()
+        .map_err(HarnessError::Io)?;
+
+    let output = Command::new(GIT_PROGRAM)
+        .args(["clone", "--depth", "1", "--quiet", "--", url])
+        .arg(dir.path())
+        .output()
+        .map_err(|err| spawn_error(GIT_PROGRAM, err))?;
+    if !output.status.success() {
+        return Err(HarnessError::GitCloneFailed(format!(
+            "{url}: {}",
+            String::from_utf8_lossy(&output.stderr).trim()
+        )));
+    }
+
+    if keep {
+        Ok(RemoteCheckout {
+            path: dir.keep(),
+            dir: None,
+        })
+    } else {
+        Ok(RemoteCheckout {
+            path: dir.path().to_path_buf(),
+            dir: Some(dir),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn is_remote_url_detects_url_shaped_paths() {
+        assert!(is_remote_url(Path::new("https://github.com/org/repo.git")));
+        assert!(is_remote_url(Path::new(
+            "ssh://git@github.com/org/repo.git"
+        )));
+        assert!(is_remote_url(Path::new("git@github.com:org/repo.git")));
+        assert!(is_remote_url(Path::new("file:///srv/git/repo.git")));
+    }
+
+    #[test]
+    fn is_remote_url_rejects_local_paths() {
+        assert!(!is_remote_url(Path::new(".")));
+        assert!(!is_remote_url(Path::new("/tmp/repo")));
+        assert!(!is_remote_url(Path::new("repos/team@2024:archive")));
+    }
+
+    #[test]
+    fn shallow_clone_reports_failure_for_missing_remote() {
+        let missing = tempfile::TempDir::new().expect("temp dir should be created");
+        let url = format!("file://{}/absent.git", missing.path().display());
+        let err = shallow_clone(&url, false)
+            .err()
+            .expect("clone of missing remote should fail");
+        assert!(err.to_string().contains("git clone failed"));
+    }
+}
@@ -0,0 +1,149 @@
+use crate::config;
+use crate::error::{HarnessError, Result};
+use std::io;
+use std::path::Path;
+use std::process::Command;
+
+const CURL_PROGRAM: &str = "curl";
+const FETCH_TIMEOUT_SECS: &str = "30";
+
+pub fn fetch_config_template(source: &str, offline: bool) -> Result<String> {
+    let content = if is_http_url(source) {
+        if offline {
+            return Err(fetch_failed(
+                source,
+                "--offline forbids downloads; pass a local path instead".to_string(),
+            ));
+        }
+        download(CURL_PROGRAM, source)?
+    } else {
+        read_local_template(source)?
+    };
+    config::parse_config(&content, source).map_err(|err| {
+        HarnessError::ConfigParse(format!(
+            "template {source} is not a valid harness.toml: {err}"
+        ))
+    })?;
+    Ok(content)
+}
+
+fn is_http_url(source: &str) -> bool {
+    source.starts_with("https://") || source.starts_with("http://")
+}
+
+fn fetch_failed(url: &str, reason: String) -> HarnessError {
+    HarnessError::TemplateFetchFailed {
+        url: url.to_string(),
+        reason,
+    }
+}
+
+fn read_local_template(source: &str) -> Result<String> {
+    let path = Path::new(source);
+    if !path.is_file() {
+        return Err(HarnessError::ConfigNotFound(source.to_string()));
+    }
+    std::fs::read_to_string(path).map_err(HarnessError::Io)
+}
+
+fn download(program: &str, url: &str) -> Result<String> {
+    let output = Command::new(program)
+        .args([
+            "--fail",
+            "--silent",
+            "--show-error",
+            "--location",
+            "--max-time",
+            FETCH_TIMEOUT_SECS,
+            "--",
+            url,
+        ])
+        .output()
+        .map_err(|err| match err.kind() {
+            io::ErrorKind::NotFound => fetch_failed(
+                url,
+                format!("`{program}` was not found; install curl or pass a local path"),
+            ),
+            _ => HarnessError::Io(err),
+        })?;
+    if !output.status.success() {
+        return Err(fetch_failed(
+            url,
+            String::from_utf8_lossy(&output.stderr).trim().to_string(),
+        ));
+    }
+    String::from_utf8(output.stdout)
+        .map_err(|_| fetch_failed(url, "response body is not valid UTF-8".to_string()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::TempDir;
+
+    #[test]
+    fn local_template_is_returned_verbatim_when_valid() {
+        let dir = TempDir::new().expect("temp dir should be created");
+        let path = dir.path().join("org.toml");
+        let template = "# org template\n[project]\nname = \"org\"\nprofile = \"agent\"\n";
+        std::fs::write(&path, template).expect("template should write");
+
+        let source = path.to_str().expect("utf-8 path");
+        assert_eq!(
+            read_local_template(source).expect("template should read"),
+            template
+        );
+        for offline in [false, true] {
+            let fetched = fetch_config_template(source, offline).expect("template should load");
+            assert_eq!(fetched, template);
+        }
+    }
+
+    #[test]
+    fn invalid_or_missing_local_templates_are_rejected() {
+        let dir = TempDir::new().expect("temp dir should be created");
+        let path = dir.path().join("broken.toml");
+        std::fs::write(&path, "[verfication]\n").expect("template should write");
+
+        let err = fetch_config_template(path.to_str().expect("utf-8 path"), false)
+            .expect_err("invalid template should fail");
+        assert!(err.to_string().contains("is not a valid harness.toml"));
+
+        let missing = dir.path().join("missing.toml");
+        let err = read_local_template(missing.to_str().expect("utf-8 path"))
+            .expect_err("missing template should fail");
+        assert!(matches!(err, HarnessError::ConfigNotFound(_)));
+    }
+
+    #[test]
+    fn url_templates_are_refused_offline() {
+        let err = fetch_config_template("https://example.com/harness.toml", true)
+            .expect_err("offline fetch should fail");
+        assert!(matches!(err, HarnessError::TemplateFetchFailed { .. }));
+        assert!(err.to_string().contains("--offline forbids downloads"));
+    }
+
+    #[test]
+    fn url_download_reports_missing_fetch_program() {
+        let err = download(
+            "/nonexistent/harness-curl",
+            "https://example.com/harness.toml",
+        )
+        .expect_err("missing curl should fail");
+        assert!(matches!(
+            &err,
+            HarnessError::TemplateFetchFailed { url, .. } if url == "https://example.com/harness.toml"
+        ));
+        assert!(err
+            .to_string()
+            .contains("install curl or pass a local path"));
+    }
+
+    #[test]
+    fn only_http_sources_are_downloaded() {
+        assert!(is_http_url("https://example.com/harness.toml"));
+        assert!(is_http_url("http://example.com/harness.toml"));
+        assert!(!is_http_url("./templates/harness.toml"));
+        assert!(!is_http_url("file:///srv/harness.toml"));
+    }
+}
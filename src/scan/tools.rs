@@ -4,9 +4,9 @@ use std::collections::HashSet;
 #[derive(Debug, Clone, Default)]
 pub struct ToolSignals {
     pub tool_names: Vec<String>,
-    pub risky_overlap_clusters: usize,
+    pub risky_overlap_clusters: Vec<Vec<String>>,
     pub unrestricted_destructive: usize,
-    pub has_ambiguous_duplicates: bool,
+    pub duplicate_tools: Vec<String>,
 }
 
 pub fn detect_tools(config: Option<&HarnessConfig>) -> ToolSignals {
@@ -20,15 +20,25 @@ pub fn detect_tools(config: Option<&HarnessConfig>) -> ToolSignals {
     }
 
     normalize_tool_list(&mut tool_names);
-    let risky_overlap_clusters = count_overlap_clusters(&tool_names);
-    let unrestricted_destructive = count_unrestricted_destructive(&tool_names);
-    let has_ambiguous_duplicates = has_duplicates(&tool_names);
+    let risky_overlap_clusters = detect_overlap_clusters(&tool_names);
+    let custom_destructive = config
+        .and_then(|config| config.tools.as_ref())
+        .and_then(|tools| tools.baseline.as_ref())
+        .map(|baseline| baseline.destructive.as_slice())
+        .unwrap_or_default();
+    let allowed_destructive = config
+        .and_then(|config| config.tools.as_ref())
+        .and_then(|tools| tools.allow_destructive.as_deref())
+        .unwrap_or_default();
+    let unrestricted_destructive =
+        count_unrestricted_destructive(&tool_names, custom_destructive, allowed_destructive);
+    let duplicate_tools = find_duplicates(&tool_names);
 
     ToolSignals {
         tool_names,
         risky_overlap_clusters,
         unrestricted_destructive,
-        has_ambiguous_duplicates,
+        duplicate_tools,
     }
 }
 
@@ -65,44 +75,51 @@ fn normalize_tool_list(tools: &mut Vec<String>) {
     tools.sort();
 }
 
-fn has_duplicates(tools: &[String]) -> bool {
+fn find_duplicates(tools: &[String]) -> Vec<String> {
     let mut unique = HashSet::new();
+    let mut duplicates = Vec::new();
     for tool in tools {
-        if !unique.insert(tool) {
-            return true;
+        if !unique.insert(tool) && !duplicates.contains(tool) {
+            duplicates.push(tool.clone());
         }
     }
-    false
+    duplicates
 }
 
-fn count_overlap_clusters(tools: &[String]) -> usize {
+fn detect_overlap_clusters(tools: &[String]) -> Vec<Vec<String>> {
     let grep_cluster = ["grep", "rg", "ag", "ack"];
     let find_cluster = ["find", "fd"];
-    let mut count = 0;
-    if grep_cluster
-        .iter()
-        .filter(|tool| tools.contains(&tool.to_string()))
-        .count()
-        > 1
-    {
-        count += 1;
-    }
-    if find_cluster
-        .iter()
-        .filter(|tool| tools.contains(&tool.to_string()))
-        .count()
-        > 1
-    {
-        count += 1;
-    }
-    count
+    [&grep_cluster[..], &find_cluster[..]]
+        .into_iter()
+        .map(|cluster| {
+            cluster
+                .iter()
+                .filter(|tool| tools.contains(&tool.to_string()))
+                .map(|tool| tool.to_string())
+                .collect::<Vec<_>>()
+        })
+        .filter(|present| present.len() > 1)
+        .collect()
 }
 
-fn count_unrestricted_destructive(tools: &[String]) -> usize {
+fn count_unrestricted_destructive(
+    tools: &[String],
+    custom: &[String],
+    allowed: &[String],
+) -> usize {
     let dangerous = ["sudo", "mkfs", "fdisk", "rm", "shutdown"];
+    let normalize = |entries: &[String]| {
+        entries
+            .iter()
+            .map(|entry| entry.trim().to_lowercase())
+            .collect::<HashSet<_>>()
+    };
+    let custom = normalize(custom);
+    let allowed = normalize(allowed);
     tools
         .iter()
-        .filter(|tool| dangerous.contains(&tool.as_str()))
+        .filter(|tool| dangerous.contains(&tool.as_str()) || custom.contains(tool.as_str()))
+        .filter(|tool| !allowed.contains(tool.as_str()))
         .count()
 }
 
@@ -116,4 +133,94 @@ mod tests {
         assert!(signals.tool_names.contains(&"bash".to_string()));
         assert!(!signals.tool_names.is_empty());
     }
+
+    #[test]
+    fn detect_tools_reports_duplicate_entries_once_per_tool() {
+        let config: HarnessConfig = toml::from_str(
+            r#"
+[project]
+name = "sample"
+
+[tools.baseline]
+read = ["rg", "cat", "RG"]
+write = ["rg"]
+"#,
+        )
+        .expect("config should parse");
+
+        let signals = detect_tools(Some(&config));
+        assert_eq!(signals.duplicate_tools, vec!["rg".to_string()]);
+        assert!(detect_tools(None).duplicate_tools.is_empty());
+    }
+
+    #[test]
+    fn detect_tools_counts_configured_destructive_entries() {
+        let config: HarnessConfig = toml::from_str(
+            r#"
+[project]
+name = "sample"
+
+[tools.baseline]
+read = ["cat", "dd"]
+write = ["kubectl delete", "rm"]
+destructive = ["DD", "kubectl delete"]
+"#,
+        )
+        .expect("config should parse");
+
+        let signals = detect_tools(Some(&config));
+        assert_eq!(signals.unrestricted_destructive, 3);
+    }
+
+    #[test]
+    fn detect_tools_ignores_custom_destructive_entries_that_are_not_enabled() {
+        let config: HarnessConfig = toml::from_str(
+            r#"
+[project]
+name = "sample"
+
+[tools.baseline]
+read = ["cat", "rg"]
+destructive = ["dd", "git push --force"]
+"#,
+        )
+        .expect("config should parse");
+
+        assert_eq!(detect_tools(Some(&config)).unrestricted_destructive, 0);
+    }
+
+    #[test]
+    fn detect_tools_skips_explicitly_allowed_destructive_tools() {
+        let config: HarnessConfig = toml::from_str(
+            r#"
+[project]
+name = "sample"
+
+[tools]
+allow_destructive = ["RM"]
+
+[tools.baseline]
+read = ["cat"]
+write = ["rm", "sudo"]
+"#,
+        )
+        .expect("config should parse");
+
+        assert_eq!(detect_tools(Some(&config)).unrestricted_destructive, 1);
+    }
+
+    #[test]
+    fn detect_overlap_clusters_names_tools_in_each_cluster() {
+        let tools = ["ag", "bash", "fd", "find", "git", "rg"]
+            .map(String::from)
+            .to_vec();
+        assert_eq!(
+            detect_overlap_clusters(&tools),
+            vec![
+                vec!["rg".to_string(), "ag".to_string()],
+                vec!["find".to_string(), "fd".to_string()],
+            ]
+        );
+        assert!(detect_overlap_clusters(&["rg".to_string(), "find".to_string()]).is_empty());
+    }
 }
@@ -1,9 +1,14 @@
+use crate::analyze::registry;
 use crate::error::HarnessError;
+use crate::i18n;
+use crate::types::report::GradeCutoffs;
+use crate::util::template::placeholders;
 use serde::Deserialize;
-use std::collections::{HashMap, HashSet};
+use std::collections::{BTreeMap, HashMap, HashSet};
 
 #[allow(dead_code)]
 #[derive(Debug, Clone, Deserialize)]
+#[serde(deny_unknown_fields)]
 pub struct HarnessConfig {
     pub project: ProjectConfig,
     pub context: Option<ContextConfig>,
@@ -13,6 +18,10 @@ pub struct HarnessConfig {
     pub metrics: Option<MetricsConfig>,
     pub optimization: Option<OptimizationConfig>,
     pub workflow: Option<WorkflowConfig>,
+    pub scan: Option<ScanConfig>,
+    pub report: Option<ReportConfig>,
+    pub findings: Option<FindingsConfig>,
+    pub recommendations: Option<RecommendationsConfig>,
 }
 
 #[allow(dead_code)]
@@ -41,6 +50,8 @@ pub struct ContextConfig {
     pub context_index: Option<String>,
     #[serde(default)]
     pub doc_map_required: bool,
+    pub fresh_days: Option<u32>,
+    pub max_context_tokens: Option<usize>,
 }
 
 #[derive(Debug, Clone, Deserialize)]
@@ -49,6 +60,9 @@ pub struct ToolsConfig {
     pub specialized: Option<ToolSpecialized>,
     pub deprecated: Option<ToolDeprecated>,
     pub aliases: Option<HashMap<String, String>>,
+    pub max_tools: Option<usize>,
+    pub tool_count_penalty: Option<f32>,
+    pub allow_destructive: Option<Vec<String>>,
 }
 
 #[derive(Debug, Clone, Deserialize)]
@@ -59,6 +73,8 @@ pub struct ToolBaseline {
     pub write: Vec<String>,
     #[serde(default)]
     pub forbidden: Vec<String>,
+    #[serde(default)]
+    pub destructive: Vec<String>,
 }
 
 #[derive(Debug, Clone, Deserialize)]
@@ -98,6 +114,7 @@ pub enum LogSampling {
 #[allow(dead_code)]
 #[derive(Debug, Clone, Deserialize)]
 pub struct ContinuityConfig {
+    pub enabled: Option<bool>,
     pub initializer: Option<String>,
     pub coding_prompt: Option<String>,
     pub progress_file: Option<String>,
@@ -107,6 +124,9 @@ pub struct ContinuityConfig {
     pub batch_interval_secs: Option<u32>,
     pub max_log_size_kb: Option<u32>,
     pub retained_logs: Option<u32>,
+    pub max_evidence_items: Option<u32>,
+    pub weights: Option<HashMap<String, f32>>,
+    pub min_prompt_chars: Option<usize>,
 }
 
 #[derive(Debug, Clone, Deserialize)]
@@ -114,6 +134,8 @@ pub struct MetricsConfig {
     pub weights: Option<HashMap<String, f32>>,
     pub max_risk_tolerance: Option<f32>,
     pub max_penalty_per_bucket: Option<f32>,
+    pub thresholds: Option<HashMap<String, f32>>,
+    pub thresholds_blocking: Option<bool>,
 }
 
 #[derive(Debug, Clone, Deserialize)]
@@ -123,6 +145,10 @@ pub struct OptimizationConfig {
     pub min_uplift_rel: Option<f32>,
     pub trace_staleness_days: Option<u32>,
     pub task_overlap_threshold: Option<f32>,
+    pub significance_alpha: Option<f32>,
+    pub dedup_traces: Option<bool>,
+    pub field_map: Option<BTreeMap<String, String>>,
+    pub success_outcomes: Option<Vec<String>>,
 }
 
 #[derive(Debug, Clone, Copy, PartialEq)]
@@ -132,6 +158,7 @@ pub struct OptimizationThresholds {
     pub min_uplift_rel: f32,
     pub trace_staleness_days: u32,
     pub task_overlap_threshold: f32,
+    pub significance_alpha: Option<f32>,
 }
 
 impl Default for OptimizationThresholds {
@@ -142,6 +169,7 @@ impl Default for OptimizationThresholds {
             min_uplift_rel: 0.10,
             trace_staleness_days: 90,
             task_overlap_threshold: 0.50,
+            significance_alpha: None,
         }
     }
 }
@@ -155,6 +183,80 @@ pub struct WorkflowConfig {
     pub replan_on_loop: bool,
 }
 
+#[derive(Debug, Clone, Deserialize)]
+pub struct ScanConfig {
+    pub max_files: Option<usize>,
+    pub max_file_lines: Option<usize>,
+    pub secret_check: Option<bool>,
+}
+
+#[derive(Debug, Clone, Default, Deserialize)]
+pub struct FindingsConfig {
+    #[serde(default)]
+    pub blocking: Vec<String>,
+    #[serde(default)]
+    pub non_blocking: Vec<String>,
+}
+
+#[derive(Debug, Clone, Default, Deserialize)]
+pub struct RecommendationsConfig {
+    #[serde(default)]
+    pub exclude: Vec<String>,
+    pub stale_decay: Option<f32>,
+    #[serde(flatten)]
+    pub templates: BTreeMap<String, RecommendationTemplate>,
+}
+
+#[derive(Debug, Clone, Default, Deserialize)]
+pub struct RecommendationTemplate {
+    pub title: Option<String>,
+    pub summary: Option<String>,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct ReportConfig {
+    pub score_scale: Option<ScoreScale>,
+    pub grade_cutoffs: Option<GradeCutoffs>,
+    pub locale: Option<String>,
+    pub max_findings: Option<usize>,
+}
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum ScoreScale {
+    #[default]
+    Ratio,
+    Percent,
+}
+
+pub const DEFAULT_FRESH_DAYS: u32 = 90;
+pub const DEFAULT_MIN_PROMPT_CHARS: usize = 20;
+pub const DEFAULT_MAX_FILE_LINES: usize = 1000;
+pub const DEFAULT_MAX_TOOLS: usize = 12;
+pub const DEFAULT_TOOL_COUNT_PENALTY: f32 = 0.10;
+pub const DEFAULT_SUCCESS_OUTCOMES: [&str; 1] = ["success"];
+pub const RECOMMENDATION_PLACEHOLDERS: [&str; 4] =
+    ["file_count", "missing_file", "tool_count", "context_tokens"];
+
+pub fn default_success_outcomes() -> Vec<String> {
+    DEFAULT_SUCCESS_OUTCOMES.map(String::from).to_vec()
+}
+
+const CONTINUITY_WEIGHT_KEYS: [&str; 4] = [
+    "prompts",
+    "progress_file",
+    "feature_state_file",
+    "progress_summary",
+];
+
+const CATEGORY_KEYS: [&str; 5] = [
+    "context",
+    "tools",
+    "continuity",
+    "verification",
+    "repository_quality",
+];
+
 impl HarnessConfig {
     pub fn default_weights() -> [f32; 5] {
         [0.30, 0.25, 0.20, 0.15, 0.10]
@@ -176,6 +278,30 @@ impl HarnessConfig {
         }
     }
 
+    pub fn default_continuity_weights() -> [f32; 4] {
+        [0.40, 0.25, 0.20, 0.15]
+    }
+
+    pub fn continuity_weights(&self) -> [f32; 4] {
+        let defaults = Self::default_continuity_weights();
+        match self
+            .continuity
+            .as_ref()
+            .and_then(|continuity| continuity.weights.as_ref())
+        {
+            Some(weights) => {
+                let mut resolved = defaults;
+                for (slot, key) in resolved.iter_mut().zip(CONTINUITY_WEIGHT_KEYS) {
+                    if let Some(weight) = weights.get(key) {
+                        *slot = *weight;
+                    }
+                }
+                resolved
+            }
+            None => defaults,
+        }
+    }
+
     #[allow(dead_code)]
     pub fn max_penalty_per_bucket(&self) -> f32 {
         self.metrics
@@ -184,24 +310,172 @@ impl HarnessConfig {
             .unwrap_or(0.40)
     }
 
+    pub fn fresh_days(&self) -> u32 {
+        self.context
+            .as_ref()
+            .and_then(|context| context.fresh_days)
+            .unwrap_or(DEFAULT_FRESH_DAYS)
+    }
+
+    pub fn max_context_tokens(&self) -> Option<usize> {
+        self.context
+            .as_ref()
+            .and_then(|context| context.max_context_tokens)
+    }
+
+    pub fn max_tools(&self) -> usize {
+        self.tools
+            .as_ref()
+            .and_then(|tools| tools.max_tools)
+            .unwrap_or(DEFAULT_MAX_TOOLS)
+    }
+
+    pub fn tool_count_penalty(&self) -> f32 {
+        self.tools
+            .as_ref()
+            .and_then(|tools| tools.tool_count_penalty)
+            .unwrap_or(DEFAULT_TOOL_COUNT_PENALTY)
+    }
+
+    pub fn min_prompt_chars(&self) -> usize {
+        self.continuity
+            .as_ref()
+            .and_then(|continuity| continuity.min_prompt_chars)
+            .unwrap_or(DEFAULT_MIN_PROMPT_CHARS)
+    }
+
+    pub fn category_thresholds(&self) -> HashMap<String, f32> {
+        self.metrics
+            .as_ref()
+            .and_then(|metrics| metrics.thresholds.clone())
+            .unwrap_or_default()
+    }
+
+    pub fn thresholds_blocking(&self) -> bool {
+        self.metrics
+            .as_ref()
+            .and_then(|metrics| metrics.thresholds_blocking)
+            .unwrap_or(false)
+    }
+
+    pub fn score_scale(&self) -> ScoreScale {
+        self.report
+            .as_ref()
+            .and_then(|report| report.score_scale)
+            .unwrap_or_default()
+    }
+
+    pub fn grade_cutoffs(&self) -> GradeCutoffs {
+        self.report
+            .as_ref()
+            .and_then(|report| report.grade_cutoffs)
+            .unwrap_or_default()
+    }
+
+    pub fn max_findings(&self) -> Option<usize> {
+        self.report.as_ref().and_then(|report| report.max_findings)
+    }
+
+    pub fn locale(&self) -> &str {
+        self.report
+            .as_ref()
+            .and_then(|report| report.locale.as_deref())
+            .unwrap_or(i18n::DEFAULT_LOCALE)
+    }
+
+    pub fn blocking_override(&self, finding_id: &str) -> Option<bool> {
+        let findings = self.findings.as_ref()?;
+        if findings.blocking.iter().any(|id| id == finding_id) {
+            Some(true)
+        } else if findings.non_blocking.iter().any(|id| id == finding_id) {
+            Some(false)
+        } else {
+            None
+        }
+    }
+
+    pub fn recommendation_template(&self, id: &str) -> Option<&RecommendationTemplate> {
+        self.recommendations.as_ref()?.templates.get(id)
+    }
+
+    pub fn excluded_recommendations(&self) -> &[String] {
+        self.recommendations
+            .as_ref()
+            .map_or(&[], |recommendations| recommendations.exclude.as_slice())
+    }
+
+    pub fn is_recommendation_excluded(&self, id: &str) -> bool {
+        self.excluded_recommendations()
+            .iter()
+            .any(|excluded| excluded == id)
+    }
+
+    pub fn stale_decay(&self) -> Option<f32> {
+        self.recommendations.as_ref()?.stale_decay
+    }
+
+    pub fn scan_max_files(&self) -> Option<usize> {
+        self.scan.as_ref().and_then(|scan| scan.max_files)
+    }
+
+    pub fn secret_check(&self) -> bool {
+        self.scan
+            .as_ref()
+            .and_then(|scan| scan.secret_check)
+            .unwrap_or(false)
+    }
+
+    pub fn max_file_lines(&self) -> usize {
+        self.scan
+            .as_ref()
+            .and_then(|scan| scan.max_file_lines)
+            .unwrap_or(DEFAULT_MAX_FILE_LINES)
+    }
+
     pub fn optimization_thresholds(&self) -> OptimizationThresholds {
         let defaults = OptimizationThresholds::default();
         match &self.optimization {
             Some(optimization) => OptimizationThresholds {
                 min_traces: optimization.min_traces.unwrap_or(defaults.min_traces),
-                min_uplift_abs: optimization.min_uplift_abs.unwrap_or(defaults.min_uplift_abs),
-                min_uplift_rel: optimization.min_uplift_rel.unwrap_or(defaults.min_uplift_rel),
+                min_uplift_abs: optimization
+                    .min_uplift_abs
+                    .unwrap_or(defaults.min_uplift_abs),
+                min_uplift_rel: optimization
+                    .min_uplift_rel
+                    .unwrap_or(defaults.min_uplift_rel),
                 trace_staleness_days: optimization
                     .trace_staleness_days
                     .unwrap_or(defaults.trace_staleness_days),
                 task_overlap_threshold: optimization
                     .task_overlap_threshold
                     .unwrap_or(defaults.task_overlap_threshold),
+                significance_alpha: optimization.significance_alpha,
             },
             None => defaults,
         }
     }
 
+    pub fn dedup_traces(&self) -> bool {
+        self.optimization
+            .as_ref()
+            .and_then(|optimization| optimization.dedup_traces)
+            .unwrap_or(false)
+    }
+
+    pub fn trace_field_map(&self) -> BTreeMap<String, String> {
+        self.optimization
+            .as_ref()
+            .and_then(|optimization| optimization.field_map.clone())
+            .unwrap_or_default()
+    }
+
+    pub fn success_outcomes(&self) -> Vec<String> {
+        self.optimization
+            .as_ref()
+            .and_then(|optimization| optimization.success_outcomes.clone())
+            .unwrap_or_else(default_success_outcomes)
+    }
+
     pub fn validate(&self) -> Result<(), HarnessError> {
         if !matches!(self.project.profile.as_str(), "general" | "agent") {
             return Err(HarnessError::ConfigParse(format!(
@@ -226,16 +500,9 @@ impl HarnessConfig {
 
         if let Some(metrics) = &self.metrics {
             if let Some(weights) = &metrics.weights {
-                const ALLOWED_WEIGHT_KEYS: [&str; 5] = [
-                    "context",
-                    "tools",
-                    "continuity",
-                    "verification",
-                    "repository_quality",
-                ];
                 let unknown = weights
                     .keys()
-                    .filter(|key| !ALLOWED_WEIGHT_KEYS.contains(&key.as_str()))
+                    .filter(|key| !CATEGORY_KEYS.contains(&key.as_str()))
                     .cloned()
                     .collect::<Vec<_>>();
                 if !unknown.is_empty() {
@@ -246,6 +513,29 @@ impl HarnessConfig {
                 }
             }
 
+            if let Some(thresholds) = &metrics.thresholds {
+                let mut unknown = thresholds
+                    .keys()
+                    .filter(|key| !CATEGORY_KEYS.contains(&key.as_str()))
+                    .cloned()
+                    .collect::<Vec<_>>();
+                if !unknown.is_empty() {
+                    unknown.sort();
+                    return Err(HarnessError::ConfigParse(format!(
+                        "metrics.thresholds contains unknown key(s): {}",
+                        unknown.join(", ")
+                    )));
+                }
+                if thresholds
+                    .values()
+                    .any(|value| !(0.0..=1.0).contains(value))
+                {
+                    return Err(HarnessError::ConfigParse(
+                        "metrics.thresholds values must be between 0.0 and 1.0".to_string(),
+                    ));
+                }
+            }
+
             if let Some(max_risk_tolerance) = metrics.max_risk_tolerance {
                 if !(0.0..=1.0).contains(&max_risk_tolerance) {
                     return Err(HarnessError::ConfigParse(
@@ -262,6 +552,115 @@ impl HarnessConfig {
             }
         }
 
+        if let Some(findings) = &self.findings {
+            let conflicting = findings
+                .blocking
+                .iter()
+                .filter(|id| findings.non_blocking.contains(id))
+                .cloned()
+                .collect::<Vec<_>>();
+            if !conflicting.is_empty() {
+                return Err(HarnessError::ConfigParse(format!(
+                    "findings.blocking and findings.non_blocking both contain: {}",
+                    conflicting.join(", ")
+                )));
+            }
+        }
+
+        if !i18n::LOCALES.contains(&self.locale()) {
+            return Err(HarnessError::ConfigParse(format!(
+                "report.locale must be one of: {} (found {})",
+                i18n::LOCALES.join(", "),
+                self.locale()
+            )));
+        }
+
+        if let Some(stale_decay) = self.stale_decay() {
+            if !(0.0..=1.0).contains(&stale_decay) {
+                return Err(HarnessError::ConfigParse(
+                    "recommendations.stale_decay must be between 0.0 and 1.0".to_string(),
+                ));
+            }
+        }
+
+        for (id, template) in self
+            .recommendations
+            .iter()
+            .flat_map(|recommendations| &recommendations.templates)
+        {
+            if !registry::is_known_recommendation(id) {
+                return Err(HarnessError::ConfigParse(format!(
+                    "recommendations.\"{id}\" overrides an unknown recommendation id \
+                     (expected one of: {})",
+                    registry::RECOMMENDATIONS
+                        .iter()
+                        .map(|spec| spec.id)
+                        .collect::<Vec<_>>()
+                        .join(", ")
+                )));
+            }
+            for (field, text) in [("title", &template.title), ("summary", &template.summary)] {
+                let Some(text) = text else {
+                    continue;
+                };
+                if let Some(unknown) = placeholders(text)
+                    .into_iter()
+                    .find(|key| !RECOMMENDATION_PLACEHOLDERS.contains(key))
+                {
+                    return Err(HarnessError::ConfigParse(format!(
+                        "recommendations.\"{id}\".{field} references unknown placeholder \
+                         {{{unknown}}} (expected one of: {})",
+                        RECOMMENDATION_PLACEHOLDERS.join(", ")
+                    )));
+                }
+            }
+        }
+
+        if let Some(weights) = self
+            .continuity
+            .as_ref()
+            .and_then(|continuity| continuity.weights.as_ref())
+        {
+            let mut unknown = weights
+                .keys()
+                .filter(|key| !CONTINUITY_WEIGHT_KEYS.contains(&key.as_str()))
+                .cloned()
+                .collect::<Vec<_>>();
+            if !unknown.is_empty() {
+                unknown.sort();
+                return Err(HarnessError::ConfigParse(format!(
+                    "continuity.weights contains unknown key(s): {}",
+                    unknown.join(", ")
+                )));
+            }
+            let resolved = self.continuity_weights();
+            if resolved.iter().any(|weight| !(0.0..=1.0).contains(weight)) {
+                return Err(HarnessError::ConfigParse(
+                    "continuity.weights values must be between 0.0 and 1.0".to_string(),
+                ));
+            }
+            let weight_sum: f32 = resolved.iter().sum();
+            if (weight_sum - 1.0).abs() > 0.001 {
+                return Err(HarnessError::ConfigParse(format!(
+                    "continuity.weights must sum to 1.0 (found {:.3})",
+                    weight_sum
+                )));
+            }
+        }
+
+        let cutoffs = self.grade_cutoffs();
+        let ordered = [cutoffs.a, cutoffs.b, cutoffs.c, cutoffs.d];
+        if ordered.iter().any(|cutoff| !(0.0..=1.0).contains(cutoff)) {
+            return Err(HarnessError::ConfigParse(
+                "report.grade_cutoffs values must be between 0.0 and 1.0".to_string(),
+            ));
+        }
+        if ordered.windows(2).any(|pair| pair[0] < pair[1]) {
+            return Err(HarnessError::ConfigParse(
+                "report.grade_cutoffs must be descending from A to D".to_string(),
+            ));
+        }
+
         if let Some(verification) = &self.verification {
             if verification.pre_completion_required && verification.required.is_empty() {
                 return Err(HarnessError::ConfigParse(
@@ -279,6 +678,49 @@ impl HarnessConfig {
             validate_tool_deprecation_lifecycle(deprecated)?;
         }
 
+        if !(0.0..=1.0).contains(&self.tool_count_penalty()) {
+            return Err(HarnessError::ConfigParse(
+                "tools.tool_count_penalty must be between 0.0 and 1.0".to_string(),
+            ));
+        }
+
+        if self.max_context_tokens() == Some(0) {
+            return Err(HarnessError::ConfigParse(
+                "context.max_context_tokens must be greater than 0".to_string(),
+            ));
+        }
+
+        if self.max_findings() == Some(0) {
+            return Err(HarnessError::ConfigParse(
+                "report.max_findings must be greater than 0".to_string(),
+            ));
+        }
+
+        if self
+            .continuity
+            .as_ref()
+            .and_then(|continuity| continuity.max_evidence_items)
+            == Some(0)
+        {
+            return Err(HarnessError::ConfigParse(
+                "continuity.max_evidence_items must be greater than 0".to_string(),
+            ));
+        }
+
+        if let Some(max_files) = self.scan_max_files() {
+            if max_files == 0 {
+                return Err(HarnessError::ConfigParse(
+                    "scan.max_files must be greater than 0".to_string(),
+                ));
+            }
+        }
+
+        if self.max_file_lines() == 0 {
+            return Err(HarnessError::ConfigParse(
+                "scan.max_file_lines must be greater than 0".to_string(),
+            ));
+        }
+
         if let Some(optimization) = &self.optimization {
             if let Some(min_traces) = optimization.min_traces {
                 if min_traces == 0 {
@@ -316,6 +758,26 @@ impl HarnessConfig {
                     ));
                 }
             }
+            if let Some(significance_alpha) = optimization.significance_alpha {
+                if !(significance_alpha > 0.0 && significance_alpha < 1.0) {
+                    return Err(HarnessError::ConfigParse(
+                        "optimization.significance_alpha must be between 0.0 and 1.0 (exclusive)"
+                            .to_string(),
+                    ));
+                }
+            }
+            if let Some(success_outcomes) = &optimization.success_outcomes {
+                if success_outcomes.is_empty()
+                    || success_outcomes
+                        .iter()
+                        .any(|outcome| outcome.trim().is_empty())
+                {
+                    return Err(HarnessError::ConfigParse(
+                        "optimization.success_outcomes must list at least one non-empty outcome"
+                            .to_string(),
+                    ));
+                }
+            }
         }
 
         Ok(())
@@ -448,6 +910,238 @@ repository_quality = 0.1
         assert!(cfg.validate().is_err());
     }
 
+    #[test]
+    fn validate_rejects_out_of_range_category_threshold() {
+        let toml_str = r#"
+[project]
+name = "test"
+
+[metrics.thresholds]
+continuity = 1.5
+"#;
+        let cfg: HarnessConfig = toml::from_str(toml_str).expect("config should parse");
+        let err = cfg
+            .validate()
+            .expect_err("threshold above 1.0 should be rejected");
+        assert!(err.to_string().contains("metrics.thresholds"));
+    }
+
+    #[test]
+    fn grade_cutoffs_default_missing_grades_and_reject_misordering() {
+        let cfg: HarnessConfig = toml::from_str(
+            r#"
+[project]
+name = "test"
+
+[report.grade_cutoffs]
+A = 0.95
+"#,
+        )
+        .expect("config should parse");
+        let cutoffs = cfg.grade_cutoffs();
+        assert!((cutoffs.a - 0.95).abs() < 0.001);
+        assert!((cutoffs.b - 0.8).abs() < 0.001);
+        assert!(cfg.validate().is_ok());
+
+        let cfg: HarnessConfig = toml::from_str(
+            r#"
+[project]
+name = "test"
+
+[report.grade_cutoffs]
+B = 0.95
+"#,
+        )
+        .expect("config should parse");
+        assert!(cfg.validate().is_err());
+    }
+
+    #[test]
+    fn validate_rejects_finding_in_both_override_lists() {
+        let cfg: HarnessConfig = toml::from_str(
+            r#"
+[project]
+name = "test"
+
+[findings]
+blocking = ["context.missing_index"]
+non_blocking = ["context.missing_index", "tools.deprecated"]
+"#,
+        )
+        .expect("config should parse");
+        let err = cfg
+            .validate()
+            .expect_err("conflicting overrides should be rejected");
+        assert!(err.to_string().contains("context.missing_index"));
+        assert!(!err.to_string().contains("tools.deprecated"));
+    }
+
+    #[test]
+    fn validate_accepts_known_locales_only() {
+        let parse = |locale: &str| -> HarnessConfig {
+            toml::from_str(&format!(
+                "[project]\nname = \"test\"\n\n[report]\nlocale = \"{locale}\"\n"
+            ))
+            .expect("config should parse")
+        };
+        assert_eq!(parse("es").locale(), "es");
+        assert!(parse("es").validate().is_ok());
+        let err = parse("fr")
+            .validate()
+            .expect_err("unknown locale should be rejected");
+        assert!(err
+            .to_string()
+            .contains("report.locale must be one of: en, es"));
+    }
+
+    #[test]
+    fn validate_rejects_unknown_recommendation_placeholders() {
+        let cfg: HarnessConfig = toml::from_str(
+            r#"
+[project]
+name = "test"
+
+[recommendations."rec.context.index"]
+title = "Index {file_count} files"
+summary = "Add {missing_file} for {team}"
+"#,
+        )
+        .expect("config should parse");
+        let err = cfg
+            .validate()
+            .expect_err("unknown placeholder should be rejected");
+        assert!(err
+            .to_string()
+            .contains("recommendations.\"rec.context.index\".summary"));
+        assert!(err.to_string().contains("{team}"));
+        assert_eq!(
+            cfg.recommendation_template("rec.context.index")
+                .and_then(|template| template.title.as_deref()),
+            Some("Index {file_count} files")
+        );
+    }
+
+    #[test]
+    fn validate_rejects_overrides_for_unknown_recommendation_ids() {
+        let cfg: HarnessConfig = toml::from_str(
+            r#"
+[project]
+name = "test"
+
+[recommendations."rec.context.indx"]
+title = "Index the repo"
+"#,
+        )
+        .expect("config should parse");
+        let err = cfg
+            .validate()
+            .expect_err("unknown recommendation id should be rejected");
+        assert!(err
+            .to_string()
+            .contains("recommendations.\"rec.context.indx\" overrides an unknown"));
+        assert!(err.to_string().contains("rec.context.index"));
+    }
+
+    #[test]
+    fn misspelled_top_level_section_is_rejected() {
+        let err = toml::from_str::<HarnessConfig>(
+            r#"
+[project]
+name = "test"
+
+[verfication]
+required = ["cargo test"]
+"#,
+        )
+        .expect_err("unknown section should be rejected");
+        let message = err.to_string();
+        assert!(message.contains("unknown field `verfication`"), "{message}");
+        assert!(message.contains("`verification`"), "{message}");
+    }
+
+    #[test]
+    fn optional_sections_may_all_be_omitted() {
+        let cfg: HarnessConfig = toml::from_str("[project]\nname = \"test\"\n")
+            .expect("project-only config should parse");
+        assert!(cfg.verification.is_none());
+        assert!(cfg.recommendations.is_none());
+    }
+
+    #[test]
+    fn recommendations_exclude_parses_alongside_templates() {
+        let cfg: HarnessConfig = toml::from_str(
+            r#"
+[project]
+name = "test"
+
+[recommendations]
+exclude = ["rec.repo.scale"]
+
+[recommendations."rec.context.index"]
+summary = "Map {file_count} files."
+"#,
+        )
+        .expect("config should parse");
+        assert!(cfg.validate().is_ok());
+        assert!(cfg.is_recommendation_excluded("rec.repo.scale"));
+        assert!(!cfg.is_recommendation_excluded("rec.context.index"));
+        assert!(cfg.recommendation_template("rec.context.index").is_some());
+        assert!(cfg.recommendation_template("exclude").is_none());
+        assert_eq!(cfg.stale_decay(), None);
+    }
+
+    #[test]
+    fn validate_rejects_out_of_range_stale_decay() {
+        let parse = |decay: &str| -> HarnessConfig {
+            toml::from_str(&format!(
+                "[project]\nname = \"test\"\n\n[recommendations]\nstale_decay = {decay}\n"
+            ))
+            .expect("config should parse")
+        };
+        assert_eq!(parse("0.25").stale_decay(), Some(0.25));
+        assert!(parse("0.25").validate().is_ok());
+        let err = parse("1.5")
+            .validate()
+            .expect_err("decay above 1.0 should fail");
+        assert!(err.to_string().contains("recommendations.stale_decay"));
+    }
+
+    #[test]
+    fn continuity_weights_accept_custom_distribution_and_reject_bad_sum() {
+        let cfg: HarnessConfig = toml::from_str(
+            r#"
+[project]
+name = "test"
+
+[continuity.weights]
+prompts = 0.5
+progress_file = 0.3
+feature_state_file = 0.0
+progress_summary = 0.2
+"#,
+        )
+        .expect("config should parse");
+        assert!(cfg.validate().is_ok());
+        assert_eq!(cfg.continuity_weights(), [0.5, 0.3, 0.0, 0.2]);
+
+        let cfg: HarnessConfig = toml::from_str(
+            r#"
+[project]
+name = "test"
+
+[continuity.weights]
+prompts = 0.6
+"#,
+        )
+        .expect("config should parse");
+        let err = cfg
+            .validate()
+            .expect_err("weights summing to 1.2 should be rejected");
+        assert!(err
+            .to_string()
+            .contains("continuity.weights must sum to 1.0"));
+    }
+
     #[test]
     fn validate_rejects_pre_completion_without_required() {
         let toml_str = r#"
@@ -496,6 +1190,7 @@ name = "test"
                 min_uplift_rel: 0.10,
                 trace_staleness_days: 90,
                 task_overlap_threshold: 0.50,
+                significance_alpha: None,
             }
         );
     }
@@ -523,10 +1218,109 @@ task_overlap_threshold = 0.75
                 min_uplift_rel: 0.12,
                 trace_staleness_days: 30,
                 task_overlap_threshold: 0.75,
+                significance_alpha: None,
             }
         );
     }
 
+    #[test]
+    fn dedup_traces_defaults_off_and_parses_override() {
+        let cfg: HarnessConfig =
+            toml::from_str("[project]\nname = \"test\"\n").expect("config should parse");
+        assert!(!cfg.dedup_traces());
+
+        let cfg: HarnessConfig =
+            toml::from_str("[project]\nname = \"test\"\n\n[optimization]\ndedup_traces = true\n")
+                .expect("config should parse");
+        assert!(cfg.dedup_traces());
+    }
+
+    #[test]
+    fn trace_field_map_parses_optimization_table() {
+        let cfg: HarnessConfig = toml::from_str(
+            "[project]\nname = \"test\"\n\n[optimization.field_map]\nrev = \"revision\"\n",
+        )
+        .expect("config should parse");
+        assert_eq!(
+            cfg.trace_field_map().get("rev").map(String::as_str),
+            Some("revision")
+        );
+    }
+
+    #[test]
+    fn success_outcomes_default_to_success_and_parse_override() {
+        let cfg: HarnessConfig =
+            toml::from_str("[project]\nname = \"test\"\n").expect("config should parse");
+        assert_eq!(cfg.success_outcomes(), vec!["success".to_string()]);
+
+        let cfg: HarnessConfig = toml::from_str(
+            "[project]\nname = \"test\"\n\n[optimization]\nsuccess_outcomes = [\"passed\", \"done\"]\n",
+        )
+        .expect("config should parse");
+        assert_eq!(
+            cfg.success_outcomes(),
+            vec!["passed".to_string(), "done".to_string()]
+        );
+    }
+
+    #[test]
+    fn max_file_lines_defaults_and_rejects_zero() {
+        let cfg: HarnessConfig =
+            toml::from_str("[project]\nname = \"test\"\n").expect("config should parse");
+        assert_eq!(cfg.max_file_lines(), DEFAULT_MAX_FILE_LINES);
+
+        let cfg: HarnessConfig =
+            toml::from_str("[project]\nname = \"test\"\n\n[scan]\nmax_file_lines = 0\n")
+                .expect("config should parse");
+        let err = cfg.validate().expect_err("validation should fail");
+        assert!(err.to_string().contains("scan.max_file_lines"));
+    }
+
+    #[test]
+    fn validate_rejects_zero_max_context_tokens() {
+        let cfg: HarnessConfig =
+            toml::from_str("[project]\nname = \"test\"\n\n[context]\nmax_context_tokens = 0\n")
+                .expect("config should parse");
+        let err = cfg.validate().expect_err("validation should fail");
+        assert!(err.to_string().contains("context.max_context_tokens"));
+    }
+
+    #[test]
+    fn validate_rejects_zero_max_findings() {
+        let cfg: HarnessConfig =
+            toml::from_str("[project]\nname = \"test\"\n\n[report]\nmax_findings = 0\n")
+                .expect("config should parse");
+        let err = cfg.validate().expect_err("validation should fail");
+        assert!(err.to_string().contains("report.max_findings"));
+    }
+
+    #[test]
+    fn validate_rejects_zero_max_evidence_items() {
+        let cfg: HarnessConfig =
+            toml::from_str("[project]\nname = \"test\"\n\n[continuity]\nmax_evidence_items = 0\n")
+                .expect("config should parse");
+        let err = cfg.validate().expect_err("validation should fail");
+        assert!(err.to_string().contains("continuity.max_evidence_items"));
+    }
+
+    #[test]
+    fn validate_rejects_out_of_range_tool_count_penalty() {
+        let cfg: HarnessConfig =
+            toml::from_str("[project]\nname = \"test\"\n\n[tools]\ntool_count_penalty = 1.5\n")
+                .expect("config should parse");
+        let err = cfg.validate().expect_err("validation should fail");
+        assert!(err.to_string().contains("tools.tool_count_penalty"));
+    }
+
+    #[test]
+    fn validate_rejects_empty_success_outcomes() {
+        let cfg: HarnessConfig =
+            toml::from_str("[project]\nname = \"test\"\n\n[optimization]\nsuccess_outcomes = []\n")
+                .expect("config should parse");
+        let err = cfg.validate().expect_err("validation should fail");
+        assert!(err.to_string().contains("optimization.success_outcomes"));
+    }
+
     #[test]
     fn validate_rejects_invalid_optimization_thresholds() {
         let toml_str = r#"
@@ -538,10 +1332,39 @@ min_traces = 0
 "#;
         let cfg: HarnessConfig = toml::from_str(toml_str).expect("config should parse");
         let err = cfg.validate().expect_err("validation should fail");
-        assert!(
-            err.to_string()
-                .contains("optimization.min_traces must be greater than 0")
-        );
+        assert!(err
+            .to_string()
+            .contains("optimization.min_traces must be greater than 0"));
+    }
+
+    #[test]
+    fn validate_rejects_out_of_range_significance_alpha() {
+        let toml_str = r#"
+[project]
+name = "test"
+
+[optimization]
+significance_alpha = 1.0
+"#;
+        let cfg: HarnessConfig = toml::from_str(toml_str).expect("config should parse");
+        let err = cfg.validate().expect_err("validation should fail");
+        assert!(err.to_string().contains("optimization.significance_alpha"));
+    }
+
+    #[test]
+    fn validate_rejects_zero_scan_max_files() {
+        let toml_str = r#"
+[project]
+name = "test"
+
+[scan]
+max_files = 0
+"#;
+        let cfg: HarnessConfig = toml::from_str(toml_str).expect("config should parse");
+        let err = cfg.validate().expect_err("validation should fail");
+        assert!(err
+            .to_string()
+            .contains("scan.max_files must be greater than 0"));
     }
 
     #[test]
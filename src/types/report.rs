@@ -1,8 +1,9 @@
-use crate::types::scoring::ScoreCard;
-use serde::Serialize;
+use crate::types::scoring::{ScoreBreakdown, ScoreCard};
+use serde::{Deserialize, Serialize};
 use std::cmp::Ordering;
+use std::collections::BTreeMap;
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
 #[serde(rename_all = "lowercase")]
 pub enum Impact {
     Low,
@@ -11,6 +12,10 @@ pub enum Impact {
 }
 
 impl Impact {
+    pub fn at_least(self, min: Self) -> bool {
+        self.priority() >= min.priority()
+    }
+
     fn priority(self) -> u8 {
         match self {
             Self::High => 3,
@@ -20,7 +25,7 @@ impl Impact {
     }
 }
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
 #[serde(rename_all = "lowercase")]
 pub enum Effort {
     Xs,
@@ -39,9 +44,18 @@ impl Effort {
             Self::L => 4,
         }
     }
+
+    pub fn estimated_hours(self) -> (u32, u32) {
+        match self {
+            Self::Xs => (0, 1),
+            Self::S => (1, 4),
+            Self::M => (4, 16),
+            Self::L => (16, 40),
+        }
+    }
 }
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
 #[serde(rename_all = "lowercase")]
 pub enum Risk {
     Safe,
@@ -50,7 +64,94 @@ pub enum Risk {
     High,
 }
 
-#[derive(Debug, Clone, Serialize)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Verdict {
+    Pass,
+    Warn,
+    Fail,
+}
+
+impl Verdict {
+    pub fn from_findings(findings: &[Finding]) -> Self {
+        if findings.iter().any(|finding| finding.blocking) {
+            Self::Fail
+        } else if !findings.is_empty() {
+            Self::Warn
+        } else {
+            Self::Pass
+        }
+    }
+
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Self::Pass => "pass",
+            Self::Warn => "warn",
+            Self::Fail => "fail",
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum Grade {
+    A,
+    B,
+    C,
+    D,
+    F,
+}
+
+#[derive(Debug, Clone, Copy, Deserialize)]
+#[serde(default)]
+pub struct GradeCutoffs {
+    #[serde(rename = "A")]
+    pub a: f32,
+    #[serde(rename = "B")]
+    pub b: f32,
+    #[serde(rename = "C")]
+    pub c: f32,
+    #[serde(rename = "D")]
+    pub d: f32,
+}
+
+impl Default for GradeCutoffs {
+    fn default() -> Self {
+        Self {
+            a: 0.9,
+            b: 0.8,
+            c: 0.7,
+            d: 0.6,
+        }
+    }
+}
+
+impl Grade {
+    pub fn from_score(score: f32, cutoffs: &GradeCutoffs) -> Self {
+        if score >= cutoffs.a {
+            Self::A
+        } else if score >= cutoffs.b {
+            Self::B
+        } else if score >= cutoffs.c {
+            Self::C
+        } else if score >= cutoffs.d {
+            Self::D
+        } else {
+            Self::F
+        }
+    }
+
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Self::A => "A",
+            Self::B => "B",
+            Self::C => "C",
+            Self::D => "D",
+            Self::F => "F",
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Finding {
     pub id: String,
     pub title: String,
@@ -59,7 +160,7 @@ pub struct Finding {
     pub file: Option<String>,
 }
 
-#[derive(Debug, Clone, Serialize)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Recommendation {
     pub id: String,
     pub title: String,
@@ -92,12 +193,57 @@ impl Recommendation {
     }
 }
 
-#[derive(Debug, Clone, Serialize)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DirectoryFileCount {
+    pub path: String,
+    pub file_count: usize,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ScanStats {
+    pub file_count: usize,
+    pub top_directories: Vec<DirectoryFileCount>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct HarnessReport {
+    pub verdict: Verdict,
+    pub grade: Grade,
     pub overall_score: f32,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub overall_score_percent: Option<u8>,
     pub category_scores: ScoreCard,
+    pub blocking_count: usize,
+    pub warning_count: usize,
+    #[serde(default)]
+    pub context_token_estimate: usize,
     pub findings: Vec<Finding>,
     pub recommendations: Vec<Recommendation>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub scan_stats: Option<ScanStats>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub score_breakdown: Option<BTreeMap<String, ScoreBreakdown>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub baseline_changes: Option<BaselineChanges>,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct BaselineChanges {
+    pub new: Vec<String>,
+    pub persisting: Vec<String>,
+    pub resolved: Vec<String>,
+}
+
+impl BaselineChanges {
+    pub fn state_of(&self, id: &str) -> Option<&'static str> {
+        if self.new.iter().any(|candidate| candidate == id) {
+            Some("new")
+        } else if self.persisting.iter().any(|candidate| candidate == id) {
+            Some("persisting")
+        } else {
+            None
+        }
+    }
 }
 
 impl HarnessReport {
@@ -107,11 +253,42 @@ impl HarnessReport {
                 .priority()
                 .cmp(&a.impact.priority())
                 .then_with(|| a.effort.rank().cmp(&b.effort.rank()))
+                .then_with(|| b.confidence.total_cmp(&a.confidence))
+                .then_with(|| alphabetical_cmp(&a.id, &b.id))
+        });
+    }
+
+    pub fn sort_findings(&mut self) {
+        self.findings.sort_by(|a, b| {
+            b.blocking
+                .cmp(&a.blocking)
                 .then_with(|| alphabetical_cmp(&a.id, &b.id))
         });
     }
 }
 
+#[cfg(test)]
+impl HarnessReport {
+    /// Empty passing report for tests; override only the fields under test.
+    pub(crate) fn fixture() -> Self {
+        Self {
+            verdict: Verdict::Pass,
+            grade: Grade::F,
+            overall_score: 0.0,
+            overall_score_percent: None,
+            category_scores: ScoreCard::new(0.0, 0.0, 0.0, 0.0, 0.0),
+            blocking_count: 0,
+            warning_count: 0,
+            context_token_estimate: 0,
+            findings: Vec::new(),
+            recommendations: Vec::new(),
+            scan_stats: None,
+            score_breakdown: None,
+            baseline_changes: None,
+        }
+    }
+}
+
 fn alphabetical_cmp(a: &str, b: &str) -> Ordering {
     a.cmp(b)
 }
@@ -119,7 +296,6 @@ fn alphabetical_cmp(a: &str, b: &str) -> Ordering {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::types::scoring::ScoreCard;
 
     #[test]
     fn recommendation_confidence_is_clamped() {
@@ -136,12 +312,77 @@ mod tests {
         assert!((recommendation.confidence - 1.0).abs() < 0.001);
     }
 
+    fn finding(id: &str, blocking: bool) -> Finding {
+        Finding {
+            id: id.to_string(),
+            title: id.to_string(),
+            body: "body".to_string(),
+            blocking,
+            file: None,
+        }
+    }
+
+    #[test]
+    fn verdict_reflects_most_severe_finding() {
+        assert_eq!(Verdict::from_findings(&[]), Verdict::Pass);
+        assert_eq!(
+            Verdict::from_findings(&[finding("a", false)]),
+            Verdict::Warn
+        );
+        assert_eq!(
+            Verdict::from_findings(&[finding("a", false), finding("b", true)]),
+            Verdict::Fail
+        );
+    }
+
+    #[test]
+    fn grade_cutoffs_are_inclusive_lower_bounds() {
+        let cutoffs = GradeCutoffs::default();
+        assert_eq!(Grade::from_score(1.0, &cutoffs), Grade::A);
+        assert_eq!(Grade::from_score(0.9, &cutoffs), Grade::A);
+        assert_eq!(Grade::from_score(0.899, &cutoffs), Grade::B);
+        assert_eq!(Grade::from_score(0.8, &cutoffs), Grade::B);
+        assert_eq!(Grade::from_score(0.7, &cutoffs), Grade::C);
+        assert_eq!(Grade::from_score(0.6, &cutoffs), Grade::D);
+        assert_eq!(Grade::from_score(0.599, &cutoffs), Grade::F);
+        assert_eq!(Grade::from_score(0.0, &cutoffs), Grade::F);
+    }
+
+    #[test]
+    fn grade_uses_custom_cutoffs() {
+        let cutoffs = GradeCutoffs {
+            a: 0.95,
+            b: 0.85,
+            c: 0.5,
+            d: 0.25,
+        };
+        assert_eq!(Grade::from_score(0.9, &cutoffs), Grade::B);
+        assert_eq!(Grade::from_score(0.5, &cutoffs), Grade::C);
+        assert_eq!(Grade::from_score(0.3, &cutoffs), Grade::D);
+    }
+
+    #[test]
+    fn impact_at_least_compares_priority() {
+        assert!(Impact::High.at_least(Impact::Medium));
+        assert!(Impact::Medium.at_least(Impact::Medium));
+        assert!(!Impact::Low.at_least(Impact::Medium));
+    }
+
     #[test]
-    fn recommendation_sorting_uses_impact_effort_and_id() {
+    fn effort_estimated_hours_are_ordered_ranges() {
+        assert_eq!(Effort::Xs.estimated_hours(), (0, 1));
+        assert_eq!(Effort::S.estimated_hours(), (1, 4));
+        assert_eq!(Effort::M.estimated_hours(), (4, 16));
+        assert_eq!(Effort::L.estimated_hours(), (16, 40));
+        for effort in [Effort::Xs, Effort::S, Effort::M, Effort::L] {
+            let (low, high) = effort.estimated_hours();
+            assert!(low < high);
+        }
+    }
+
+    #[test]
+    fn recommendation_sorting_uses_impact_effort_confidence_and_id() {
         let mut report = HarnessReport {
-            overall_score: 0.0,
-            category_scores: ScoreCard::new(0.0, 0.0, 0.0, 0.0, 0.0),
-            findings: vec![],
             recommendations: vec![
                 Recommendation::new(
                     "b",
@@ -171,6 +412,7 @@ mod tests {
                     0.7,
                 ),
             ],
+            ..HarnessReport::fixture()
         };
 
         report.sort_recommendations();
@@ -182,4 +424,89 @@ mod tests {
             .collect();
         assert_eq!(ids, vec!["a".to_string(), "b".to_string(), "c".to_string()]);
     }
+
+    #[test]
+    fn recommendation_sorting_breaks_impact_effort_ties_by_confidence() {
+        let mut report = HarnessReport {
+            recommendations: vec![
+                Recommendation::new(
+                    "a",
+                    "Less certain",
+                    "x",
+                    Impact::High,
+                    Effort::S,
+                    Risk::Safe,
+                    0.55,
+                ),
+                Recommendation::new(
+                    "b",
+                    "More certain",
+                    "x",
+                    Impact::High,
+                    Effort::S,
+                    Risk::Safe,
+                    0.95,
+                ),
+                Recommendation::new(
+                    "c",
+                    "Equally certain",
+                    "x",
+                    Impact::High,
+                    Effort::S,
+                    Risk::Safe,
+                    0.55,
+                ),
+            ],
+            ..HarnessReport::fixture()
+        };
+
+        report.sort_recommendations();
+
+        let ids: Vec<&str> = report
+            .recommendations
+            .iter()
+            .map(|recommendation| recommendation.id.as_str())
+            .collect();
+        assert_eq!(ids, vec!["b", "a", "c"]);
+    }
+
+    #[test]
+    fn finding_sorting_puts_blocking_first_then_orders_by_id() {
+        let finding = |id: &str, blocking: bool| Finding {
+            id: id.to_string(),
+            title: id.to_string(),
+            body: id.to_string(),
+            blocking,
+            file: None,
+        };
+        let mut report = HarnessReport {
+            verdict: Verdict::Fail,
+            findings: vec![
+                finding("verification.missing_config", false),
+                finding("tools.disabled", true),
+                finding("context.missing_agents", false),
+                finding("tools.deprecated", true),
+            ],
+            blocking_count: 2,
+            warning_count: 2,
+            ..HarnessReport::fixture()
+        };
+
+        report.sort_findings();
+
+        let ids: Vec<&str> = report
+            .findings
+            .iter()
+            .map(|finding| finding.id.as_str())
+            .collect();
+        assert_eq!(
+            ids,
+            vec![
+                "tools.deprecated",
+                "tools.disabled",
+                "context.missing_agents",
+                "verification.missing_config",
+            ]
+        );
+    }
 }
@@ -1,7 +1,11 @@
-use serde::Serialize;
+use serde::{Deserialize, Serialize};
 
 pub type Score = f32;
 
+pub fn score_percent(score: Score) -> u8 {
+    (score.clamp(0.0, 1.0) * 100.0).round() as u8
+}
+
 #[allow(dead_code)]
 #[derive(Debug, Clone, Serialize)]
 pub struct CategoryScoreBuilder {
@@ -34,7 +38,47 @@ impl CategoryScoreBuilder {
     }
 }
 
-#[derive(Debug, Clone, Serialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ScoreBreakdown {
+    pub base: Score,
+    pub contributions: Vec<ScoreContribution>,
+    pub total: Score,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ScoreContribution {
+    pub signal: String,
+    pub delta: Score,
+}
+
+impl ScoreBreakdown {
+    pub fn new(base: Score, contributions: Vec<ScoreContribution>) -> Self {
+        let total = contributions
+            .iter()
+            .fold(base, |score, contribution| score + contribution.delta)
+            .clamp(0.0, 1.0);
+        Self {
+            base,
+            contributions,
+            total,
+        }
+    }
+}
+
+impl ScoreContribution {
+    pub fn new(signal: impl Into<String>, delta: Score) -> Self {
+        Self {
+            signal: signal.into(),
+            delta,
+        }
+    }
+}
+
+pub fn signal(label: impl Into<String>, applies: bool, delta: Score) -> ScoreContribution {
+    ScoreContribution::new(label, if applies { delta } else { 0.0 })
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct ScoreCard {
     pub context: Score,
     pub tools: Score,
@@ -73,6 +117,16 @@ impl ScoreCard {
         }
     }
 
+    pub fn categories(&self) -> [(&'static str, Score); 5] {
+        [
+            ("context", self.context),
+            ("tools", self.tools),
+            ("continuity", self.continuity),
+            ("verification", self.verification),
+            ("repository_quality", self.repository_quality),
+        ]
+    }
+
     pub fn weighted_overall(&self, weights: &[Score; 5]) -> Score {
         let clamped = self.clamped();
         [
@@ -122,6 +176,13 @@ mod tests {
         assert!((clamped.overall - 1.0).abs() < 0.001);
     }
 
+    #[test]
+    fn score_percent_rounds_and_clamps() {
+        assert_eq!(score_percent(0.876), 88);
+        assert_eq!(score_percent(1.4), 100);
+        assert_eq!(score_percent(-0.1), 0);
+    }
+
     #[test]
     fn bucket_penalty_limit_enforced() {
         let mut builder = CategoryScoreBuilder::new(1.0);
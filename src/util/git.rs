@@ -0,0 +1,27 @@
+use crate::error::HarnessError;
+use std::io;
+
+pub const GIT_PROGRAM: &str = "git";
+
+pub fn spawn_error(program: &str, err: io::Error) -> HarnessError {
+    if err.kind() == io::ErrorKind::NotFound {
+        HarnessError::GitUnavailable(format!(
+            "`{program}` was not found; install git and make sure it is on PATH"
+        ))
+    } else {
+        HarnessError::Io(err)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_spawn_error_maps_not_found_to_git_unavailable() {
+        let err = spawn_error("git", io::Error::from(io::ErrorKind::NotFound));
+        assert!(matches!(err, HarnessError::GitUnavailable(_)));
+        let err = spawn_error("git", io::Error::from(io::ErrorKind::PermissionDenied));
+        assert!(matches!(err, HarnessError::Io(_)));
+    }
+}
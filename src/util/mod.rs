@@ -0,0 +1,6 @@
+pub mod git;
+pub mod hash;
+pub mod parallel;
+pub mod path;
+pub mod template;
+pub mod tokens;
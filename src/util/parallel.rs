@@ -0,0 +1,45 @@
+pub fn default_threads() -> usize {
+    std::thread::available_parallelism().map_or(1, std::num::NonZeroUsize::get)
+}
+
+pub fn map_ordered<T, R>(items: &[T], threads: usize, map: impl Fn(&T) -> R + Sync) -> Vec<R>
+where
+    T: Sync,
+    R: Send,
+{
+    let threads = threads.clamp(1, items.len().max(1));
+    if threads == 1 {
+        return items.iter().map(map).collect();
+    }
+    let chunk_size = items.len().div_ceil(threads);
+    let map = &map;
+    std::thread::scope(|scope| {
+        let workers = items
+            .chunks(chunk_size)
+            .map(|chunk| scope.spawn(move || chunk.iter().map(map).collect::<Vec<_>>()))
+            .collect::<Vec<_>>();
+        workers
+            .into_iter()
+            .flat_map(|worker| {
+                worker
+                    .join()
+                    .unwrap_or_else(|payload| std::panic::resume_unwind(payload))
+            })
+            .collect()
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn map_ordered_preserves_input_order_for_any_thread_count() {
+        let items = (0..37).collect::<Vec<u32>>();
+        let expected = items.iter().map(|item| item * 2).collect::<Vec<_>>();
+        for threads in [0, 1, 2, 3, 8, 64] {
+            assert_eq!(map_ordered(&items, threads, |item| item * 2), expected);
+        }
+        assert!(map_ordered(&[] as &[u32], 4, |item| *item).is_empty());
+    }
+}
@@ -0,0 +1,23 @@
+pub fn repo_relative_uri(path: &str) -> String {
+    let mut normalized = path.replace('\\', "/");
+    while let Some(rest) = normalized.strip_prefix("./") {
+        normalized = rest.to_string();
+    }
+    normalized.trim_start_matches('/').to_string()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_repo_relative_uri_uses_forward_slashes() {
+        assert_eq!(
+            repo_relative_uri("docs\\context\\INDEX.md"),
+            "docs/context/INDEX.md"
+        );
+        assert_eq!(repo_relative_uri(".\\AGENTS.md"), "AGENTS.md");
+        assert_eq!(repo_relative_uri("/harness.toml"), "harness.toml");
+        assert_eq!(repo_relative_uri("harness.toml"), "harness.toml");
+    }
+}
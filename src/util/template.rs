@@ -0,0 +1,67 @@
+use std::collections::BTreeMap;
+
+pub fn placeholders(template: &str) -> Vec<&str> {
+    let mut found = Vec::new();
+    let mut rest = template;
+    while let Some(start) = rest.find('{') {
+        let after = &rest[start + 1..];
+        let Some(end) = after.find('}') else {
+            break;
+        };
+        let key = &after[..end];
+        if is_placeholder_key(key) {
+            found.push(key);
+            rest = &after[end + 1..];
+        } else {
+            rest = after;
+        }
+    }
+    found
+}
+
+pub fn interpolate(template: &str, values: &BTreeMap<&str, String>) -> String {
+    let mut output = String::with_capacity(template.len());
+    let mut rest = template;
+    while let Some(start) = rest.find('{') {
+        output.push_str(&rest[..start]);
+        let after = &rest[start + 1..];
+        match after.find('}').map(|end| (&after[..end], end)) {
+            Some((key, end)) if is_placeholder_key(key) && values.contains_key(key) => {
+                output.push_str(&values[key]);
+                rest = &after[end + 1..];
+            }
+            _ => {
+                output.push('{');
+                rest = after;
+            }
+        }
+    }
+    output.push_str(rest);
+    output
+}
+
+fn is_placeholder_key(key: &str) -> bool {
+    !key.is_empty() && key.chars().all(|c| c.is_ascii_alphanumeric() || c == '_')
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn placeholders_lists_keys_in_order_and_ignores_other_braces() {
+        assert_eq!(
+            placeholders("{file_count} files, {missing_file}; {not a key} {} {open"),
+            vec!["file_count", "missing_file"]
+        );
+    }
+
+    #[test]
+    fn interpolate_replaces_known_keys_and_keeps_everything_else() {
+        let values = BTreeMap::from([("file_count", "42".to_string())]);
+        assert_eq!(
+            interpolate("{file_count} files {unknown} {x y} {", &values),
+            "42 files {unknown} {x y} {"
+        );
+    }
+}